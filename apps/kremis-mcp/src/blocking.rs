@@ -0,0 +1,528 @@
+//! # Blocking Kremis HTTP Client
+//!
+//! Synchronous counterpart of [`KremisClient`](crate::client::KremisClient)
+//! built on `reqwest::blocking`, for callers without an async runtime.
+//! Enabled by the `blocking` cargo feature.
+//!
+//! Requests, responses, and errors use the same types as the async client.
+//! Retries (`with_retry`), the postcard query protocol, and `ingest_bulk`
+//! are async-only.
+
+use crate::client::{
+    AttributeCount, ClientError, EntityEdge, EntityWeight, GraphSummary, HonestResponse,
+    IngestResponse, ReadyResponse, SYNC_BATCH_SIZE, ServerCapabilities, Signal, StageResponse,
+    SyncReport, TraversalStrategy, attribute_schema_sorted, diff_edges, require_endpoint,
+};
+use serde_json::Value;
+
+/// Map error statuses to [`ClientError`], passing other responses through.
+fn check_status(
+    resp: reqwest::blocking::Response,
+) -> Result<reqwest::blocking::Response, ClientError> {
+    let status = resp.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(ClientError::Unauthorized);
+    }
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err(ClientError::RateLimited);
+    }
+    if !status.is_success() {
+        let body = resp.text().unwrap_or_default();
+        return Err(ClientError::Status {
+            code: status.as_u16(),
+            body,
+        });
+    }
+    Ok(resp)
+}
+
+/// Decode a JSON value into a typed response.
+fn from_value<T: serde::de::DeserializeOwned>(value: Value) -> Result<T, ClientError> {
+    serde_json::from_value(value).map_err(|e| ClientError::ParseError(e.to_string()))
+}
+
+/// Blocking HTTP client for the Kremis REST API.
+#[derive(Clone)]
+pub struct BlockingKremisClient {
+    http: reqwest::blocking::Client,
+    base_url: String,
+    api_key: Option<String>,
+    /// Discovered capabilities; `None` assumes every endpoint exists.
+    capabilities: Option<ServerCapabilities>,
+    /// Per-request timeout; also bounds server-side query deadlines.
+    timeout: Option<std::time::Duration>,
+}
+
+#[allow(dead_code)]
+impl BlockingKremisClient {
+    /// Create a new client pointing at the given Kremis server URL.
+    pub fn new(base_url: String, api_key: Option<String>) -> Self {
+        Self {
+            http: reqwest::blocking::Client::new(),
+            base_url,
+            api_key,
+            capabilities: None,
+            timeout: None,
+        }
+    }
+
+    /// Apply a per-request timeout, as [`KremisClient::with_timeout`](crate::client::KremisClient::with_timeout).
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.http = reqwest::blocking::Client::builder()
+            .timeout(timeout)
+            .build()
+            .unwrap_or_default();
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Connect to a server, discovering its version via `/health`.
+    pub fn connect(base_url: String, api_key: Option<String>) -> Result<Self, ClientError> {
+        let mut client = Self::new(base_url, api_key);
+        let health = client.health()?;
+        let version = health["version"]
+            .as_str()
+            .ok_or_else(|| ClientError::ParseError("health response has no version".into()))?;
+        client.capabilities = Some(ServerCapabilities::from_version(version));
+        Ok(client)
+    }
+
+    /// Capabilities discovered by `connect`, if any.
+    pub fn capabilities(&self) -> Option<&ServerCapabilities> {
+        self.capabilities.as_ref()
+    }
+
+    /// Fail fast if the connected server does not provide `endpoint`.
+    fn require(&self, endpoint: &'static str) -> Result<(), ClientError> {
+        require_endpoint(self.capabilities.as_ref(), endpoint)
+    }
+
+    /// Build a request with optional Bearer auth.
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::blocking::RequestBuilder {
+        let url = format!("{}{}", self.base_url, path);
+        let mut req = self.http.request(method, &url);
+        if let Some(ref key) = self.api_key {
+            req = req.bearer_auth(key);
+        }
+        req
+    }
+
+    /// Send a request and handle connection errors.
+    fn send(
+        &self,
+        req: reqwest::blocking::RequestBuilder,
+    ) -> Result<reqwest::blocking::Response, ClientError> {
+        req.send()
+            .map_err(|e| ClientError::ConnectionFailed(format!("{}: {e}", self.base_url)))
+    }
+
+    /// Send a request, check its status, and parse the JSON body.
+    fn call(&self, req: reqwest::blocking::RequestBuilder) -> Result<Value, ClientError> {
+        check_status(self.send(req)?)?
+            .json::<Value>()
+            .map_err(|e| ClientError::ParseError(e.to_string()))
+    }
+
+    /// GET /health
+    pub fn health(&self) -> Result<Value, ClientError> {
+        self.call(self.request(reqwest::Method::GET, "/health"))
+    }
+
+    /// GET /ready → readiness; a server still loading answers `503` with progress.
+    pub fn ready(&self) -> Result<ReadyResponse, ClientError> {
+        self.require("/ready")?;
+        let resp = self.send(self.request(reqwest::Method::GET, "/ready"))?;
+        if resp.status() == reqwest::StatusCode::SERVICE_UNAVAILABLE {
+            return resp
+                .json::<ReadyResponse>()
+                .map_err(|e| ClientError::ParseError(e.to_string()));
+        }
+        check_status(resp)?
+            .json::<ReadyResponse>()
+            .map_err(|e| ClientError::ParseError(e.to_string()))
+    }
+
+    /// GET /status → graph statistics.
+    pub fn status(&self) -> Result<Value, ClientError> {
+        self.call(self.request(reqwest::Method::GET, "/status"))
+    }
+
+    /// GET /stage → developmental stage.
+    pub fn stage(&self) -> Result<StageResponse, ClientError> {
+        from_value(self.call(self.request(reqwest::Method::GET, "/stage"))?)
+    }
+
+    /// GET /summary → status, stage, and the `top_k` hub entities in one call.
+    pub fn summary(&self, top_k: usize) -> Result<GraphSummary, ClientError> {
+        self.require("/summary")?;
+        let req = self
+            .request(reqwest::Method::GET, "/summary")
+            .query(&[("top_k", top_k)]);
+        from_value(self.call(req)?)
+    }
+
+    /// GET /schema/attributes → `(attribute, entity count)`, most common first.
+    pub fn attribute_schema(&self) -> Result<Vec<(String, usize)>, ClientError> {
+        self.require("/schema/attributes")?;
+        let value = self.call(self.request(reqwest::Method::GET, "/schema/attributes"))?;
+        let attributes: Vec<AttributeCount> = from_value(value["attributes"].clone())?;
+        Ok(attribute_schema_sorted(attributes))
+    }
+
+    /// POST /signal → ingest a signal.
+    pub fn ingest(
+        &self,
+        entity_id: u64,
+        attribute: &str,
+        value: &str,
+    ) -> Result<Value, ClientError> {
+        let body = serde_json::json!({
+            "entity_id": entity_id,
+            "attribute": attribute,
+            "value": value,
+        });
+        self.call(self.request(reqwest::Method::POST, "/signal").json(&body))
+    }
+
+    /// POST /signals → ingest a whole slice in one request.
+    pub fn ingest_batch(&self, signals: &[Signal]) -> Result<Vec<IngestResponse>, ClientError> {
+        self.require("/signals")?;
+        from_value(
+            self.call(
+                self.request(reqwest::Method::POST, "/signals")
+                    .json(signals),
+            )?,
+        )
+    }
+
+    /// POST /query → execute a graph query (generic JSON body).
+    pub fn query(&self, mut request: Value) -> Result<Value, ClientError> {
+        if let (Some(timeout), Some(body)) = (self.timeout, request.as_object_mut()) {
+            let timeout_ms = u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX);
+            body.entry("deadline_ms")
+                .or_insert_with(|| (timeout_ms - timeout_ms / 4).into());
+        }
+        self.call(self.request(reqwest::Method::POST, "/query").json(&request))
+    }
+
+    /// POST /query → unfiltered traversal with an explicit strategy.
+    pub fn traverse(
+        &self,
+        node_id: u64,
+        depth: usize,
+        strategy: TraversalStrategy,
+    ) -> Result<Value, ClientError> {
+        self.query(serde_json::json!({
+            "type": "traverse",
+            "node_id": node_id,
+            "depth": depth,
+            "strategy": strategy,
+        }))
+    }
+
+    /// POST /query → a BFS traversal fetched `page_size` path entries at a time.
+    pub fn traverse_paged(
+        &self,
+        node_id: u64,
+        depth: usize,
+        page_size: usize,
+        mut on_page: impl FnMut(Value),
+    ) -> Result<usize, ClientError> {
+        let mut offset = 0u64;
+        let mut pages = 0usize;
+        loop {
+            let page = self.query(serde_json::json!({
+                "type": "traverse",
+                "node_id": node_id,
+                "depth": depth,
+                "limit": page_size,
+                "offset": offset,
+            }))?;
+            let next = page["page"]["next_offset"].as_u64();
+            on_page(page);
+            pages = pages.saturating_add(1);
+            match next {
+                Some(next) if next > offset => offset = next,
+                _ => return Ok(pages),
+            }
+        }
+    }
+
+    /// POST /export → export graph in canonical format.
+    pub fn export(&self) -> Result<Value, ClientError> {
+        self.call(self.request(reqwest::Method::POST, "/export"))
+    }
+
+    /// GET /export → the graph as raw canonical bytes.
+    pub fn download_snapshot(&self) -> Result<Vec<u8>, ClientError> {
+        let resp = check_status(self.send(self.request(reqwest::Method::GET, "/export"))?)?;
+        resp.bytes()
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| ClientError::ParseError(e.to_string()))
+    }
+
+    /// POST /import → replace the server graph with canonical bytes.
+    pub fn upload_snapshot(&self, bytes: &[u8]) -> Result<(), ClientError> {
+        self.require("/import")?;
+        let req = self
+            .request(reqwest::Method::POST, "/import")
+            .header(reqwest::header::CONTENT_TYPE, "application/octet-stream")
+            .body(bytes.to_vec());
+        self.call(req).map(|_| ())
+    }
+
+    /// GET /edges → every edge by entity id.
+    pub fn edges(&self) -> Result<Vec<EntityEdge>, ClientError> {
+        self.require("/edges")?;
+        let value = self.call(self.request(reqwest::Method::GET, "/edges"))?;
+        from_value(value["edges"].clone())
+    }
+
+    /// POST /edges → every edge with weight >= `min_weight`, fetching all pages.
+    pub fn edges_above(&self, min_weight: i64) -> Result<Vec<EntityEdge>, ClientError> {
+        self.require("/edges")?;
+        let mut edges = Vec::new();
+        let mut offset = 0u64;
+        loop {
+            let req = self
+                .request(reqwest::Method::POST, "/edges")
+                .json(&serde_json::json!({"min_weight": min_weight, "offset": offset}));
+            let value = self.call(req)?;
+            let page: Vec<EntityEdge> = from_value(value["edges"].clone())?;
+            edges.extend(page);
+            match value["next_offset"].as_u64() {
+                Some(next) if next > offset => offset = next,
+                _ => return Ok(edges),
+            }
+        }
+    }
+
+    /// PUT /edge → set an edge weight by entity id, creating it if missing.
+    pub fn put_edge(&self, edge: &EntityEdge) -> Result<Value, ClientError> {
+        self.require("/edge")?;
+        self.call(self.request(reqwest::Method::PUT, "/edge").json(edge))
+    }
+
+    /// Push a local edge set to the server, sending only the delta.
+    ///
+    /// See [`KremisClient::sync`](crate::client::KremisClient::sync).
+    pub fn sync(&self, local: &[EntityEdge]) -> Result<SyncReport, ClientError> {
+        self.require("/edges/bulk")?;
+        let server = self.edges()?;
+        let (added, changed) = diff_edges(&server, local);
+
+        let pending: Vec<EntityEdge> = added.iter().chain(&changed).copied().collect();
+        for batch in pending.chunks(SYNC_BATCH_SIZE) {
+            self.call(
+                self.request(reqwest::Method::PUT, "/edges/bulk")
+                    .json(batch),
+            )?;
+        }
+
+        Ok(SyncReport {
+            added: added.len(),
+            changed: changed.len(),
+            unchanged: local
+                .len()
+                .saturating_sub(added.len())
+                .saturating_sub(changed.len()),
+        })
+    }
+
+    /// POST /edges/bulk → add `(from_entity, to_entity, weight)` edges directly.
+    pub fn ingest_edges(&self, edges: &[(u64, u64, i64)]) -> Result<usize, ClientError> {
+        self.require("/edges/bulk")?;
+        let body: Vec<EntityEdge> = edges
+            .iter()
+            .map(|&(from_entity, to_entity, weight)| EntityEdge {
+                from_entity,
+                to_entity,
+                weight,
+            })
+            .collect();
+        let value = self.call(
+            self.request(reqwest::Method::POST, "/edges/bulk")
+                .json(&body),
+        )?;
+        value["applied"]
+            .as_u64()
+            .and_then(|n| usize::try_from(n).ok())
+            .ok_or_else(|| ClientError::ParseError("bulk edges response has no count".into()))
+    }
+
+    /// POST /signal/retract → decrement edge weight between two entities.
+    pub fn retract(&self, from_entity: u64, to_entity: u64) -> Result<Value, ClientError> {
+        self.require("/signal/retract")?;
+        let body = serde_json::json!({
+            "from_entity": from_entity,
+            "to_entity": to_entity,
+        });
+        self.call(
+            self.request(reqwest::Method::POST, "/signal/retract")
+                .json(&body),
+        )
+    }
+
+    /// PATCH /edge → add a signed delta to an edge's weight (removed at zero).
+    pub fn adjust_edge(
+        &self,
+        from_entity: u64,
+        to_entity: u64,
+        delta: i64,
+    ) -> Result<Value, ClientError> {
+        self.require("/edge")?;
+        let body = serde_json::json!({
+            "from_entity": from_entity,
+            "to_entity": to_entity,
+            "delta": delta,
+        });
+        self.call(self.request(reqwest::Method::PATCH, "/edge").json(&body))
+    }
+
+    /// POST /lookup/batch → resolve entity ids to node ids, in request order.
+    pub fn lookup_batch(&self, entity_ids: &[u64]) -> Result<Vec<Option<u64>>, ClientError> {
+        self.require("/lookup/batch")?;
+        let body = serde_json::json!({ "entity_ids": entity_ids });
+        let value = self.call(
+            self.request(reqwest::Method::POST, "/lookup/batch")
+                .json(&body),
+        )?;
+        from_value(value["node_ids"].clone())
+    }
+
+    /// GET /explain → facts along the strongest path between two nodes.
+    pub fn explain_connection(&self, a: u64, b: u64) -> Result<HonestResponse, ClientError> {
+        self.require("/explain")?;
+        let req = self
+            .request(reqwest::Method::GET, "/explain")
+            .query(&[("start", a), ("end", b)]);
+        from_value(self.call(req)?)
+    }
+
+    /// GET /entity/{id}/top → the `k` heaviest neighbor entities with their weights.
+    pub fn top_relationships(
+        &self,
+        entity_id: u64,
+        k: usize,
+    ) -> Result<Vec<(u64, i64)>, ClientError> {
+        self.require("/entity/{id}/top")?;
+        let req = self
+            .request(reqwest::Method::GET, &format!("/entity/{entity_id}/top"))
+            .query(&[("k", k)]);
+        let value = self.call(req)?;
+        let neighbors: Vec<EntityWeight> = from_value(value["neighbors"].clone())?;
+        Ok(neighbors
+            .into_iter()
+            .map(|n| (n.entity_id, n.weight))
+            .collect())
+    }
+
+    /// GET /fingerprint → the server graph's canonical checksum.
+    pub fn fingerprint(&self) -> Result<u64, ClientError> {
+        self.require("/fingerprint")?;
+        let value = self.call(self.request(reqwest::Method::GET, "/fingerprint"))?;
+        value["fingerprint"]
+            .as_u64()
+            .ok_or_else(|| ClientError::ParseError("missing fingerprint".to_string()))
+    }
+
+    /// GET /hash → canonical BLAKE3 hash of the graph.
+    pub fn hash(&self) -> Result<Value, ClientError> {
+        self.require("/hash")?;
+        self.call(self.request(reqwest::Method::GET, "/hash"))
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// `reqwest::blocking` clients must be built, used, and dropped off the
+    /// async worker threads.
+    async fn blocking<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> T {
+        tokio::task::spawn_blocking(f).await.expect("blocking task")
+    }
+
+    #[tokio::test]
+    async fn lookup_batch_preserves_order() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/lookup/batch"))
+            .and(body_json(serde_json::json!({ "entity_ids": [10, 20, 30] })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "node_ids": [3, null, 1],
+                "error": null
+            })))
+            .mount(&server)
+            .await;
+
+        let uri = server.uri();
+        let node_ids =
+            blocking(move || BlockingKremisClient::new(uri, None).lookup_batch(&[10, 20, 30]))
+                .await
+                .expect("lookup_batch");
+        assert_eq!(node_ids, vec![Some(3), None, Some(1)]);
+    }
+
+    #[tokio::test]
+    async fn ingest_batch_and_status_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/signals"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "success": true, "node_id": 0, "error": null },
+                { "success": false, "node_id": null, "error": "Invalid signal: empty attribute" }
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/status"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("warming up"))
+            .mount(&server)
+            .await;
+
+        let uri = server.uri();
+        let (results, status) = blocking(move || {
+            let client = BlockingKremisClient::new(uri, None);
+            let signals = [Signal::new(1, "name", "Alice"), Signal::new(2, "", "Bob")];
+            (client.ingest_batch(&signals), client.status())
+        })
+        .await;
+
+        let results = results.expect("ingest_batch");
+        assert!(results[0].success);
+        assert!(!results[1].success);
+        assert!(matches!(
+            status,
+            Err(ClientError::Status { code: 503, ref body }) if body == "warming up"
+        ));
+    }
+
+    #[tokio::test]
+    async fn connect_gates_unsupported_endpoints() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/health"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "version": "0.10.0"
+            })))
+            .mount(&server)
+            .await;
+
+        let uri = server.uri();
+        let result = blocking(move || {
+            let client = BlockingKremisClient::connect(uri, None).expect("connect");
+            client.fingerprint()
+        })
+        .await;
+        assert!(matches!(result, Err(ClientError::Unsupported(_))));
+    }
+}
@@ -2,7 +2,348 @@
 //!
 //! Wrapper around the Kremis REST API for use by the MCP server.
 
+use rmcp::schemars;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::BTreeSet;
+
+/// Traversal order for `traverse` queries (mirrors the server enum).
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TraversalStrategy {
+    /// Breadth-first (server default).
+    #[default]
+    Bfs,
+    /// Depth-first.
+    Dfs,
+}
+
+/// Developmental stage (mirrors the server's `S0`..`S3` codes).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Stage {
+    S0,
+    S1,
+    S2,
+    S3,
+}
+
+impl std::fmt::Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::str::FromStr for Stage {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "S0" => Ok(Stage::S0),
+            "S1" => Ok(Stage::S1),
+            "S2" => Ok(Stage::S2),
+            "S3" => Ok(Stage::S3),
+            _ => Err(ClientError::ParseError(format!("unknown stage: {s}"))),
+        }
+    }
+}
+
+/// Response from `GET /stage`.
+///
+/// `stage` stays a string so newer server stages still deserialize.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Deserialize)]
+pub struct StageResponse {
+    pub stage: String,
+    pub name: String,
+    pub progress_percent: u8,
+    pub stable_edges_needed: usize,
+    pub stable_edges_current: usize,
+}
+
+#[allow(dead_code)]
+impl StageResponse {
+    /// Parse `stage` into a typed [`Stage`] (`None` if unrecognized).
+    pub fn stage_enum(&self) -> Option<Stage> {
+        self.stage.parse().ok()
+    }
+}
+
+/// Graph counts, as in the `status` section of `GET /summary`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct StatusCounts {
+    pub node_count: usize,
+    pub edge_count: usize,
+    pub stable_edges: usize,
+    pub density_millionths: u64,
+    #[serde(default)]
+    pub ingest_rate: u64,
+}
+
+/// An entity and its degree (incoming + outgoing edges).
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct Hub {
+    pub entity_id: u64,
+    pub degree: usize,
+}
+
+/// Response from `GET /summary`: status, stage, and hubs in one call.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Deserialize)]
+pub struct GraphSummary {
+    pub status: StatusCounts,
+    pub stage: StageResponse,
+    /// Highest-degree entities, most connected first.
+    pub hubs: Vec<Hub>,
+}
+
+/// An attribute and how many entities carry it, as listed by `GET /schema/attributes`.
+#[derive(Debug, Deserialize)]
+pub(crate) struct AttributeCount {
+    pub(crate) attribute: String,
+    pub(crate) count: usize,
+}
+
+/// Response from `GET /ready`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct ReadyResponse {
+    pub ready: bool,
+    pub loaded_nodes: usize,
+    pub total_nodes: usize,
+}
+
+/// A signal to ingest (entity, attribute, value), as sent to `/signal(s)`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Signal {
+    pub entity_id: u64,
+    pub attribute: String,
+    pub value: String,
+}
+
+#[allow(dead_code)]
+impl Signal {
+    pub fn new(entity_id: u64, attribute: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            entity_id,
+            attribute: attribute.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// Per-signal result from `POST /signal(s)`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct IngestResponse {
+    pub success: bool,
+    pub node_id: Option<u64>,
+    pub error: Option<String>,
+    #[serde(default)]
+    pub stability_confidence: Option<u8>,
+}
+
+/// Options for [`KremisClient::ingest_bulk`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BulkOptions {
+    /// Signals per `/signals` request (0 is treated as 1).
+    pub batch_size: usize,
+    /// Retries per batch after a connection error, 429, or 5xx.
+    pub max_retries: u32,
+    /// Drop duplicate signals locally, keeping the first occurrence.
+    pub dedup: bool,
+    /// Send an `Idempotency-Key` per batch so retries never double-ingest.
+    pub idempotency: bool,
+}
+
+impl Default for BulkOptions {
+    fn default() -> Self {
+        Self {
+            batch_size: 500,
+            max_retries: 3,
+            dedup: true,
+            idempotency: true,
+        }
+    }
+}
+
+/// Outcome of [`KremisClient::ingest_bulk`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BulkSummary {
+    /// Signals sent to the server (after dedup).
+    pub submitted: usize,
+    /// Signals the server ingested.
+    pub succeeded: usize,
+    /// Signals rejected by the server or in batches that exhausted retries.
+    pub failed: usize,
+    /// Duplicates dropped locally.
+    pub deduplicated: usize,
+    /// Total retry attempts across all batches.
+    pub retries: u32,
+}
+
+/// Distinguishes idempotency keys from concurrent calls in one process.
+static IDEMPOTENCY_CALL_COUNTER: std::sync::atomic::AtomicU64 =
+    std::sync::atomic::AtomicU64::new(0);
+
+/// A process-unique prefix for the `Idempotency-Key`s of one call.
+fn idempotency_call_id() -> String {
+    format!(
+        "{:x}-{:x}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default(),
+        IDEMPOTENCY_CALL_COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    )
+}
+
+/// Retry settings for [`KremisClient::with_retry`].
+///
+/// Connection errors, 5xx, and 429 are retried; other 4xx are returned at
+/// once. Delays grow as `base_delay_ms * 2^attempt`, capped at
+/// `max_delay_ms`, with up to half of each delay randomized. A 429 with a
+/// `Retry-After` header waits that many seconds instead (also capped).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry.
+    pub base_delay_ms: u64,
+    /// Upper bound for any single delay.
+    pub max_delay_ms: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay_ms: 100,
+            max_delay_ms: 5_000,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before retry number `attempt` (0-based).
+    fn delay(&self, attempt: u32, retry_after: Option<std::time::Duration>) -> std::time::Duration {
+        let max = std::time::Duration::from_millis(self.max_delay_ms);
+        if let Some(retry_after) = retry_after {
+            return retry_after.min(max);
+        }
+        let backoff = self
+            .base_delay_ms
+            .saturating_mul(1u64 << attempt.min(16))
+            .min(self.max_delay_ms);
+        let half = backoff / 2;
+        let jitter = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| u64::from(d.subsec_nanos()))
+            .unwrap_or_default()
+            % half.saturating_add(1);
+        std::time::Duration::from_millis(backoff - half + jitter)
+    }
+}
+
+/// Seconds from a `Retry-After` header, if present and numeric.
+fn retry_after(resp: &reqwest::Response) -> Option<std::time::Duration> {
+    resp.headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(std::time::Duration::from_secs)
+}
+
+/// An edge addressed by entity ids, as listed by `GET /edges`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct EntityEdge {
+    pub from_entity: u64,
+    pub to_entity: u64,
+    pub weight: i64,
+}
+
+/// A neighbor entity as listed by `GET /entity/{id}/top`.
+#[derive(Debug, Deserialize)]
+pub(crate) struct EntityWeight {
+    pub(crate) entity_id: u64,
+    pub(crate) weight: i64,
+}
+
+/// Edges per `PUT /edges/bulk` request in `sync` (the server's batch limit).
+pub(crate) const SYNC_BATCH_SIZE: usize = 10_000;
+
+/// Outcome of [`KremisClient::sync`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    /// Local edges missing on the server, now pushed.
+    pub added: usize,
+    /// Local edges whose weight differed on the server, now pushed.
+    pub changed: usize,
+    /// Local edges already identical on the server.
+    pub unchanged: usize,
+}
+
+/// A statement backed by graph edges, mirroring the core `Fact`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fact {
+    pub statement: String,
+    pub evidence_path: Vec<u64>,
+}
+
+/// A derived statement with confidence (0-100), mirroring the core `Inference`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Inference {
+    pub statement: String,
+    pub confidence: u8,
+    pub evidence_path: Vec<u64>,
+}
+
+/// A question the graph cannot answer, mirroring the core `Unknown`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Unknown {
+    pub statement: String,
+}
+
+/// Facts, inferences, and unknowns, mirroring the core `HonestResponse`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HonestResponse {
+    pub facts: Vec<Fact>,
+    pub inferences: Vec<Inference>,
+    pub unknowns: Vec<Unknown>,
+}
+
+/// Split local edges into those the server lacks and those with a different weight.
+///
+/// Server-only edges are ignored: sync only pushes, it never deletes.
+pub(crate) fn diff_edges(
+    server: &[EntityEdge],
+    local: &[EntityEdge],
+) -> (Vec<EntityEdge>, Vec<EntityEdge>) {
+    let remote: std::collections::BTreeMap<(u64, u64), i64> = server
+        .iter()
+        .map(|e| ((e.from_entity, e.to_entity), e.weight))
+        .collect();
+    let mut added = Vec::new();
+    let mut changed = Vec::new();
+    for edge in local {
+        match remote.get(&(edge.from_entity, edge.to_entity)) {
+            None => added.push(*edge),
+            Some(&weight) if weight != edge.weight => changed.push(*edge),
+            Some(_) => {}
+        }
+    }
+    (added, changed)
+}
 
 /// Errors from the HTTP client layer.
 #[derive(Debug)]
@@ -13,10 +354,12 @@ pub enum ClientError {
     Unauthorized,
     /// 429 Too Many Requests.
     RateLimited,
-    /// Server returned a 5xx error.
-    ServerError(u16, String),
+    /// Server returned any other non-2xx status, with the raw response body.
+    Status { code: u16, body: String },
     /// Failed to parse response body.
     ParseError(String),
+    /// The connected server version does not provide this endpoint.
+    Unsupported(String),
 }
 
 impl std::fmt::Display for ClientError {
@@ -25,20 +368,267 @@ impl std::fmt::Display for ClientError {
             Self::ConnectionFailed(url) => write!(f, "Cannot connect to Kremis at {url}"),
             Self::Unauthorized => write!(f, "Unauthorized: invalid or missing API key"),
             Self::RateLimited => write!(f, "Rate limited: too many requests"),
-            Self::ServerError(status, msg) => write!(f, "Server error ({status}): {msg}"),
+            Self::Status { code, body } => write!(f, "HTTP {code}: {body}"),
             Self::ParseError(msg) => write!(f, "Parse error: {msg}"),
+            Self::Unsupported(msg) => write!(f, "Unsupported: {msg}"),
         }
     }
 }
 
 impl std::error::Error for ClientError {}
 
+/// Minimum server version `(major, minor, patch)` for each versioned endpoint.
+///
+/// Endpoints not listed here are available in every supported server.
+const ENDPOINT_MIN_VERSION: &[(&str, (u64, u64, u64))] = &[
+    ("/hash", (0, 7, 0)),
+    ("/metrics", (0, 7, 0)),
+    ("/signal/retract", (0, 8, 0)),
+    ("/lookup/batch", (0, 12, 0)),
+    ("/edge", (0, 12, 0)),
+    ("/edges", (0, 12, 0)),
+    ("/signals", (0, 12, 0)),
+    ("/explain", (0, 12, 0)),
+    ("/ready", (0, 12, 0)),
+    ("/entity/{id}/top", (0, 12, 0)),
+    ("/fingerprint", (0, 12, 0)),
+    ("/import", (0, 12, 0)),
+    ("/summary", (0, 12, 0)),
+    ("/edges/bulk", (0, 12, 0)),
+    ("/schema/attributes", (0, 12, 0)),
+];
+
+/// Parse a `major.minor.patch` version string (pre-release suffixes ignored).
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let core = version.split(['-', '+']).next()?;
+    let mut parts = core.split('.').map(|p| p.parse::<u64>().ok());
+    Some((
+        parts.next()??,
+        parts.next()??,
+        parts.next().flatten().unwrap_or(0),
+    ))
+}
+
+/// Endpoints and version discovered from a server's `/health` response.
+#[derive(Debug, Clone)]
+pub struct ServerCapabilities {
+    /// Version string reported by the server.
+    pub version: String,
+    /// Versioned endpoints this server provides.
+    pub endpoints: BTreeSet<&'static str>,
+}
+
+impl ServerCapabilities {
+    /// Derive capabilities from a reported server version.
+    pub fn from_version(version: &str) -> Self {
+        let parsed = parse_version(version);
+        let endpoints = ENDPOINT_MIN_VERSION
+            .iter()
+            .filter(|(_, min)| parsed.is_some_and(|v| v >= *min))
+            .map(|(endpoint, _)| *endpoint)
+            .collect();
+        Self {
+            version: version.to_string(),
+            endpoints,
+        }
+    }
+}
+
+/// `(attribute, count)` pairs by descending count, then attribute name.
+pub(crate) fn attribute_schema_sorted(attributes: Vec<AttributeCount>) -> Vec<(String, usize)> {
+    let mut schema: Vec<(String, usize)> = attributes
+        .into_iter()
+        .map(|a| (a.attribute, a.count))
+        .collect();
+    schema.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    schema
+}
+
+/// Fail fast if `capabilities` are known and lack a versioned `endpoint`.
+pub(crate) fn require_endpoint(
+    capabilities: Option<&ServerCapabilities>,
+    endpoint: &'static str,
+) -> Result<(), ClientError> {
+    match capabilities {
+        Some(caps)
+            if ENDPOINT_MIN_VERSION.iter().any(|(e, _)| *e == endpoint)
+                && !caps.endpoints.contains(endpoint) =>
+        {
+            Err(ClientError::Unsupported(format!(
+                "endpoint {endpoint} unsupported in server version {}",
+                caps.version
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Map error statuses to [`ClientError`], passing other responses through.
+async fn check_status(resp: reqwest::Response) -> Result<reqwest::Response, ClientError> {
+    let status = resp.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(ClientError::Unauthorized);
+    }
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err(ClientError::RateLimited);
+    }
+    if !status.is_success() {
+        let body = resp.text().await.unwrap_or_default();
+        return Err(ClientError::Status {
+            code: status.as_u16(),
+            body,
+        });
+    }
+    Ok(resp)
+}
+
+// =============================================================================
+// BINARY QUERY PROTOCOL
+// =============================================================================
+
+/// Content type selecting the server's postcard `/query` protocol.
+const POSTCARD_CONTENT_TYPE: &str = "application/x-postcard";
+
+/// Query request, mirroring the server's `BinaryQueryRequest`.
+///
+/// Postcard encodes variants by index and fields by position, so both
+/// orders must match the server exactly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum BinaryQueryRequest {
+    Lookup {
+        entity_id: u64,
+        #[serde(default)]
+        namespace: Option<u16>,
+    },
+    Traverse {
+        node_id: u64,
+        depth: usize,
+        #[serde(default)]
+        strategy: TraversalStrategy,
+    },
+    TraverseFiltered {
+        node_id: u64,
+        depth: usize,
+        min_weight: i64,
+        #[serde(default)]
+        top_k: Option<usize>,
+    },
+    StrongestPath {
+        start: u64,
+        end: u64,
+    },
+    Intersect {
+        nodes: Vec<u64>,
+    },
+    Related {
+        node_id: u64,
+        depth: usize,
+    },
+    Properties {
+        node_id: u64,
+    },
+}
+
+/// Query body, mirroring the server's `BinaryQueryBody`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct BinaryQueryBody {
+    request: BinaryQueryRequest,
+    deadline_ms: Option<u64>,
+}
+
+impl BinaryQueryBody {
+    /// Convert a JSON query (`{"type": "...", ...}`) into its binary form.
+    fn from_json(request: &Value) -> Result<Self, ClientError> {
+        let mut fields = request
+            .as_object()
+            .cloned()
+            .ok_or_else(|| ClientError::ParseError("query must be a JSON object".into()))?;
+        let kind = match fields.remove("type") {
+            Some(Value::String(kind)) => kind,
+            _ => return Err(ClientError::ParseError("query has no type".into())),
+        };
+        let deadline_ms = fields.remove("deadline_ms").and_then(|v| v.as_u64());
+        let mut tagged = serde_json::Map::new();
+        tagged.insert(kind, Value::Object(fields));
+        let request = serde_json::from_value(Value::Object(tagged))
+            .map_err(|e| ClientError::ParseError(e.to_string()))?;
+        Ok(Self {
+            request,
+            deadline_ms,
+        })
+    }
+}
+
+/// An edge in a query response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct EdgeJson {
+    from: u64,
+    to: u64,
+    weight: i64,
+}
+
+/// A node property in a query response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct PropertyJson {
+    attribute: String,
+    value: String,
+}
+
+/// Query response, mirroring the server's `BinaryQueryResponse`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct BinaryQueryResponse {
+    success: bool,
+    found: bool,
+    path: Vec<u64>,
+    edges: Vec<EdgeJson>,
+    properties: Vec<PropertyJson>,
+    grounding: String,
+    error: Option<String>,
+    diagnostic: Option<String>,
+    timed_out: bool,
+}
+
+impl BinaryQueryResponse {
+    /// The JSON the server would have sent, omitting the same empty fields.
+    fn into_json(self) -> Value {
+        let mut value = serde_json::json!({
+            "success": self.success,
+            "found": self.found,
+            "path": self.path,
+            "edges": self.edges,
+            "grounding": self.grounding,
+            "error": self.error,
+        });
+        if let Some(object) = value.as_object_mut() {
+            if !self.properties.is_empty() {
+                object.insert("properties".into(), serde_json::json!(self.properties));
+            }
+            if let Some(diagnostic) = self.diagnostic {
+                object.insert("diagnostic".into(), diagnostic.into());
+            }
+            if self.timed_out {
+                object.insert("timed_out".into(), true.into());
+            }
+        }
+        value
+    }
+}
+
 /// HTTP client that wraps calls to the Kremis REST API.
 #[derive(Clone)]
 pub struct KremisClient {
     http: reqwest::Client,
     base_url: String,
     api_key: Option<String>,
+    /// Discovered capabilities; `None` assumes every endpoint exists.
+    capabilities: Option<ServerCapabilities>,
+    /// Per-request timeout; also bounds server-side query deadlines.
+    timeout: Option<std::time::Duration>,
+    /// Send `/query` bodies as postcard instead of JSON.
+    binary: bool,
+    /// Retry transient failures of the core calls (see `with_retry`).
+    retry: Option<RetryPolicy>,
 }
 
 #[allow(dead_code)]
@@ -49,9 +639,67 @@ impl KremisClient {
             http: reqwest::Client::new(),
             base_url,
             api_key,
+            capabilities: None,
+            timeout: None,
+            binary: false,
+            retry: None,
         }
     }
 
+    /// Apply a per-request timeout.
+    ///
+    /// Queries then carry a `deadline_ms` of three quarters of the timeout,
+    /// so the server returns a partial result (`timed_out: true`) before the
+    /// client gives up on the response.
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.http = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .unwrap_or_default();
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Retry `health`, `status`, `stage`, `ingest`, and `query` on transient
+    /// failures, as described by `policy`.
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Send queries with the postcard binary protocol instead of JSON.
+    ///
+    /// `query` still takes and returns JSON values; only the wire format
+    /// changes.
+    pub fn with_binary_protocol(mut self) -> Self {
+        self.binary = true;
+        self
+    }
+
+    /// Connect to a server, discovering its version via `/health`.
+    ///
+    /// Methods for endpoints the server version predates return
+    /// `ClientError::Unsupported` instead of failing on a 404.
+    pub async fn connect(base_url: String, api_key: Option<String>) -> Result<Self, ClientError> {
+        let mut client = Self::new(base_url, api_key);
+        let health = client.health().await?;
+        let version = health["version"]
+            .as_str()
+            .ok_or_else(|| ClientError::ParseError("health response has no version".into()))?;
+        client.capabilities = Some(ServerCapabilities::from_version(version));
+        Ok(client)
+    }
+
+    /// Capabilities discovered by `connect`, if any.
+    pub fn capabilities(&self) -> Option<&ServerCapabilities> {
+        self.capabilities.as_ref()
+    }
+
+    /// Fail fast if the connected server does not provide `endpoint`.
+    fn require(&self, endpoint: &'static str) -> Result<(), ClientError> {
+        require_endpoint(self.capabilities.as_ref(), endpoint)
+    }
+
     /// Build a request with optional Bearer auth.
     fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
         let url = format!("{}{}", self.base_url, path);
@@ -64,18 +712,9 @@ impl KremisClient {
 
     /// Handle HTTP response: check status codes and parse JSON.
     async fn handle_response(&self, resp: reqwest::Response) -> Result<Value, ClientError> {
-        let status = resp.status();
-        if status == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(ClientError::Unauthorized);
-        }
-        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
-            return Err(ClientError::RateLimited);
-        }
-        if status.is_server_error() {
-            let body = resp.text().await.unwrap_or_default();
-            return Err(ClientError::ServerError(status.as_u16(), body));
-        }
-        resp.json::<Value>()
+        check_status(resp)
+            .await?
+            .json::<Value>()
             .await
             .map_err(|e| ClientError::ParseError(e.to_string()))
     }
@@ -87,21 +726,113 @@ impl KremisClient {
             .map_err(|e| ClientError::ConnectionFailed(format!("{}: {e}", self.base_url)))
     }
 
+    /// Like `send`, retrying per the client's [`RetryPolicy`], if any.
+    ///
+    /// The final response is returned as-is, so callers still map its
+    /// status with `check_status`.
+    async fn send_retrying(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, ClientError> {
+        match self.retry {
+            Some(policy) => self.send_with_policy(req, policy, &mut 0).await,
+            None => self.send(req).await,
+        }
+    }
+
+    /// Like `send`, retrying per `policy` and adding each retry to `retries`.
+    async fn send_with_policy(
+        &self,
+        req: reqwest::RequestBuilder,
+        policy: RetryPolicy,
+        retries: &mut u32,
+    ) -> Result<reqwest::Response, ClientError> {
+        let mut attempt: u32 = 0;
+        loop {
+            let Some(this_try) = req.try_clone() else {
+                return self.send(req).await;
+            };
+            let wait = match self.send(this_try).await {
+                Ok(resp)
+                    if attempt < policy.max_retries
+                        && (resp.status().is_server_error()
+                            || resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS) =>
+                {
+                    retry_after(&resp)
+                }
+                Err(ClientError::ConnectionFailed(_)) if attempt < policy.max_retries => None,
+                result => return result,
+            };
+            tokio::time::sleep(policy.delay(attempt, wait)).await;
+            attempt = attempt.saturating_add(1);
+            *retries = retries.saturating_add(1);
+        }
+    }
+
     /// GET /health
     pub async fn health(&self) -> Result<Value, ClientError> {
         let req = self.request(reqwest::Method::GET, "/health");
-        let resp = self.send(req).await?;
+        let resp = self.send_retrying(req).await?;
         self.handle_response(resp).await
     }
 
+    /// GET /ready → readiness; a server still loading answers `503` with progress.
+    pub async fn ready(&self) -> Result<ReadyResponse, ClientError> {
+        self.require("/ready")?;
+        let req = self.request(reqwest::Method::GET, "/ready");
+        let resp = self.send(req).await?;
+        if resp.status() == reqwest::StatusCode::SERVICE_UNAVAILABLE {
+            return resp
+                .json::<ReadyResponse>()
+                .await
+                .map_err(|e| ClientError::ParseError(e.to_string()));
+        }
+        let value = self.handle_response(resp).await?;
+        serde_json::from_value(value).map_err(|e| ClientError::ParseError(e.to_string()))
+    }
+
     /// GET /status → graph statistics.
     pub async fn status(&self) -> Result<Value, ClientError> {
         let req = self.request(reqwest::Method::GET, "/status");
-        let resp = self.send(req).await?;
+        let resp = self.send_retrying(req).await?;
         self.handle_response(resp).await
     }
 
+    /// GET /stage → developmental stage.
+    pub async fn stage(&self) -> Result<StageResponse, ClientError> {
+        let req = self.request(reqwest::Method::GET, "/stage");
+        let resp = self.send_retrying(req).await?;
+        let value = self.handle_response(resp).await?;
+        serde_json::from_value(value).map_err(|e| ClientError::ParseError(e.to_string()))
+    }
+
+    /// GET /summary → status, stage, and the `top_k` hub entities in one call.
+    pub async fn summary(&self, top_k: usize) -> Result<GraphSummary, ClientError> {
+        self.require("/summary")?;
+        let req = self
+            .request(reqwest::Method::GET, "/summary")
+            .query(&[("top_k", top_k)]);
+        let resp = self.send(req).await?;
+        let value = self.handle_response(resp).await?;
+        serde_json::from_value(value).map_err(|e| ClientError::ParseError(e.to_string()))
+    }
+
+    /// GET /schema/attributes → `(attribute, entity count)`, most common first.
+    pub async fn attribute_schema(&self) -> Result<Vec<(String, usize)>, ClientError> {
+        self.require("/schema/attributes")?;
+        let req = self.request(reqwest::Method::GET, "/schema/attributes");
+        let resp = self.send(req).await?;
+        let value = self.handle_response(resp).await?;
+        let attributes: Vec<AttributeCount> =
+            serde_json::from_value(value["attributes"].clone())
+                .map_err(|e| ClientError::ParseError(e.to_string()))?;
+        Ok(attribute_schema_sorted(attributes))
+    }
+
     /// POST /signal → ingest a signal.
+    ///
+    /// With `with_retry`, the request carries an `Idempotency-Key` so a
+    /// retry after a lost response never ingests the signal twice.
     pub async fn ingest(
         &self,
         entity_id: u64,
@@ -113,18 +844,101 @@ impl KremisClient {
             "attribute": attribute,
             "value": value,
         });
-        let req = self.request(reqwest::Method::POST, "/signal").json(&body);
-        let resp = self.send(req).await?;
+        let mut req = self.request(reqwest::Method::POST, "/signal").json(&body);
+        if self.retry.is_some() {
+            req = req.header(
+                "Idempotency-Key",
+                format!("signal-{}", idempotency_call_id()),
+            );
+        }
+        let resp = self.send_retrying(req).await?;
         self.handle_response(resp).await
     }
 
     /// POST /query → execute a graph query (generic JSON body).
-    pub async fn query(&self, request: Value) -> Result<Value, ClientError> {
+    pub async fn query(&self, mut request: Value) -> Result<Value, ClientError> {
+        if let (Some(timeout), Some(body)) = (self.timeout, request.as_object_mut()) {
+            let timeout_ms = u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX);
+            body.entry("deadline_ms")
+                .or_insert_with(|| (timeout_ms - timeout_ms / 4).into());
+        }
+        // Paging is JSON-only, so paged queries skip the binary protocol.
+        if self.binary && request.get("limit").is_none() && request.get("offset").is_none() {
+            return self.query_binary(&request).await;
+        }
         let req = self.request(reqwest::Method::POST, "/query").json(&request);
-        let resp = self.send(req).await?;
+        let resp = self.send_retrying(req).await?;
         self.handle_response(resp).await
     }
 
+    /// POST /query with postcard bodies; the reply is converted back to JSON.
+    async fn query_binary(&self, request: &Value) -> Result<Value, ClientError> {
+        let body = postcard::to_allocvec(&BinaryQueryBody::from_json(request)?)
+            .map_err(|e| ClientError::ParseError(e.to_string()))?;
+        let req = self
+            .request(reqwest::Method::POST, "/query")
+            .header(reqwest::header::CONTENT_TYPE, POSTCARD_CONTENT_TYPE)
+            .body(body);
+        let resp = check_status(self.send_retrying(req).await?).await?;
+        let bytes = resp
+            .bytes()
+            .await
+            .map_err(|e| ClientError::ParseError(e.to_string()))?;
+        let response: BinaryQueryResponse =
+            postcard::from_bytes(&bytes).map_err(|e| ClientError::ParseError(e.to_string()))?;
+        Ok(response.into_json())
+    }
+
+    /// POST /query → unfiltered traversal with an explicit strategy.
+    pub async fn traverse(
+        &self,
+        node_id: u64,
+        depth: usize,
+        strategy: TraversalStrategy,
+    ) -> Result<Value, ClientError> {
+        let body = serde_json::json!({
+            "type": "traverse",
+            "node_id": node_id,
+            "depth": depth,
+            "strategy": strategy,
+        });
+        self.query(body).await
+    }
+
+    /// POST /query → a BFS traversal fetched `page_size` path entries at a time.
+    ///
+    /// Calls `on_page` with each page's response, in order, so the whole
+    /// result is never held at once. A `page_size` of 0 is sent as 1, since
+    /// the server rejects an empty page. Returns the number of pages.
+    pub async fn traverse_paged(
+        &self,
+        node_id: u64,
+        depth: usize,
+        page_size: usize,
+        mut on_page: impl FnMut(Value),
+    ) -> Result<usize, ClientError> {
+        let mut offset = 0u64;
+        let mut pages = 0usize;
+        loop {
+            let page = self
+                .query(serde_json::json!({
+                    "type": "traverse",
+                    "node_id": node_id,
+                    "depth": depth,
+                    "limit": page_size.max(1),
+                    "offset": offset,
+                }))
+                .await?;
+            let next = page["page"]["next_offset"].as_u64();
+            on_page(page);
+            pages = pages.saturating_add(1);
+            match next {
+                Some(next) if next > offset => offset = next,
+                _ => return Ok(pages),
+            }
+        }
+    }
+
     /// POST /export → export graph in canonical format.
     pub async fn export(&self) -> Result<Value, ClientError> {
         let req = self.request(reqwest::Method::POST, "/export");
@@ -132,8 +946,223 @@ impl KremisClient {
         self.handle_response(resp).await
     }
 
+    /// GET /export → the graph as raw canonical bytes.
+    pub async fn download_snapshot(&self) -> Result<Vec<u8>, ClientError> {
+        let req = self.request(reqwest::Method::GET, "/export");
+        let resp = check_status(self.send(req).await?).await?;
+        resp.bytes()
+            .await
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| ClientError::ParseError(e.to_string()))
+    }
+
+    /// POST /import → replace the server graph with canonical bytes.
+    ///
+    /// The server only accepts this when it has an API key configured.
+    pub async fn upload_snapshot(&self, bytes: &[u8]) -> Result<(), ClientError> {
+        self.require("/import")?;
+        let req = self
+            .request(reqwest::Method::POST, "/import")
+            .header(reqwest::header::CONTENT_TYPE, "application/octet-stream")
+            .body(bytes.to_vec());
+        let resp = self.send(req).await?;
+        self.handle_response(resp).await.map(|_| ())
+    }
+
+    /// POST /signals → ingest a whole slice in one request.
+    ///
+    /// Returns one response per signal, in order, so partial failures stay
+    /// visible. A rejected batch (e.g. too large) is a `Status` error.
+    pub async fn ingest_batch(
+        &self,
+        signals: &[Signal],
+    ) -> Result<Vec<IngestResponse>, ClientError> {
+        self.require("/signals")?;
+        let req = self
+            .request(reqwest::Method::POST, "/signals")
+            .json(signals);
+        let resp = check_status(self.send(req).await?).await?;
+        resp.json::<Vec<IngestResponse>>()
+            .await
+            .map_err(|e| ClientError::ParseError(e.to_string()))
+    }
+
+    /// POST /signals (batched) → ingest many signals with retry and dedup.
+    ///
+    /// Each batch is retried on connection errors, 429, and 5xx with the
+    /// client's [`RetryPolicy`] delays (the default policy without
+    /// `with_retry`), up to `opts.max_retries` times. With `idempotency`, a
+    /// retried batch reuses its key so the server replays the original
+    /// result instead of ingesting twice.
+    pub async fn ingest_bulk(
+        &self,
+        signals: &[Signal],
+        opts: BulkOptions,
+    ) -> Result<BulkSummary, ClientError> {
+        self.require("/signals")?;
+        let mut summary = BulkSummary::default();
+
+        let unique: Vec<&Signal> = if opts.dedup {
+            let mut seen = BTreeSet::new();
+            signals.iter().filter(|s| seen.insert(*s)).collect()
+        } else {
+            signals.iter().collect()
+        };
+        summary.deduplicated = signals.len().saturating_sub(unique.len());
+        summary.submitted = unique.len();
+
+        let call_id = idempotency_call_id();
+        let policy = RetryPolicy {
+            max_retries: opts.max_retries,
+            ..self.retry.unwrap_or_default()
+        };
+
+        for (index, batch) in unique.chunks(opts.batch_size.max(1)).enumerate() {
+            let mut req = self.request(reqwest::Method::POST, "/signals").json(batch);
+            if opts.idempotency {
+                req = req.header("Idempotency-Key", format!("bulk-{call_id}-{index}"));
+            }
+            let result = match self
+                .send_with_policy(req, policy, &mut summary.retries)
+                .await
+            {
+                Ok(resp) => self.handle_response(resp).await,
+                Err(e) => Err(e),
+            };
+
+            match result {
+                Ok(Value::Array(responses)) => {
+                    let ok = responses
+                        .iter()
+                        .filter(|r| r["success"].as_bool().unwrap_or(false))
+                        .count();
+                    summary.succeeded = summary.succeeded.saturating_add(ok);
+                    summary.failed = summary
+                        .failed
+                        .saturating_add(batch.len().saturating_sub(ok));
+                }
+                Ok(other) => {
+                    return Err(ClientError::ParseError(format!(
+                        "expected per-signal responses, got {other}"
+                    )));
+                }
+                // Retries exhausted: count the batch and move on
+                Err(
+                    ClientError::ConnectionFailed(_)
+                    | ClientError::RateLimited
+                    | ClientError::Status { code: 500.., .. },
+                ) => {
+                    summary.failed = summary.failed.saturating_add(batch.len());
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// GET /edges → every edge by entity id.
+    pub async fn edges(&self) -> Result<Vec<EntityEdge>, ClientError> {
+        self.require("/edges")?;
+        let req = self.request(reqwest::Method::GET, "/edges");
+        let resp = self.send(req).await?;
+        let value = self.handle_response(resp).await?;
+        serde_json::from_value(value["edges"].clone())
+            .map_err(|e| ClientError::ParseError(e.to_string()))
+    }
+
+    /// POST /edges → every edge with weight >= `min_weight`, fetching all pages.
+    pub async fn edges_above(&self, min_weight: i64) -> Result<Vec<EntityEdge>, ClientError> {
+        self.require("/edges")?;
+        let mut edges = Vec::new();
+        let mut offset = 0u64;
+        loop {
+            let req = self
+                .request(reqwest::Method::POST, "/edges")
+                .json(&serde_json::json!({"min_weight": min_weight, "offset": offset}));
+            let resp = self.send(req).await?;
+            let value = self.handle_response(resp).await?;
+            let page: Vec<EntityEdge> = serde_json::from_value(value["edges"].clone())
+                .map_err(|e| ClientError::ParseError(e.to_string()))?;
+            edges.extend(page);
+            match value["next_offset"].as_u64() {
+                Some(next) if next > offset => offset = next,
+                _ => return Ok(edges),
+            }
+        }
+    }
+
+    /// PUT /edge → set an edge weight by entity id, creating it if missing.
+    pub async fn put_edge(&self, edge: &EntityEdge) -> Result<Value, ClientError> {
+        self.require("/edge")?;
+        let req = self.request(reqwest::Method::PUT, "/edge").json(edge);
+        let resp = self.send(req).await?;
+        self.handle_response(resp).await
+    }
+
+    /// Push a local edge set to the server, sending only the delta.
+    ///
+    /// Fetches the server's edges, diffs them against `local` by entity pair,
+    /// and sets only added or re-weighted edges through `PUT /edges/bulk`.
+    /// Never deletes server edges. A rejected batch (e.g. a non-positive
+    /// weight) surfaces as [`ClientError::Status`]; earlier batches stay
+    /// applied.
+    ///
+    /// Takes entity-addressed edges rather than a `kremis_core::Graph`:
+    /// this crate only speaks HTTP to the server and does not link the core.
+    pub async fn sync(&self, local: &[EntityEdge]) -> Result<SyncReport, ClientError> {
+        self.require("/edges/bulk")?;
+        let server = self.edges().await?;
+        let (added, changed) = diff_edges(&server, local);
+
+        let pending: Vec<EntityEdge> = added.iter().chain(&changed).copied().collect();
+        for batch in pending.chunks(SYNC_BATCH_SIZE) {
+            let req = self
+                .request(reqwest::Method::PUT, "/edges/bulk")
+                .json(batch);
+            let resp = self.send(req).await?;
+            self.handle_response(resp).await?;
+        }
+
+        Ok(SyncReport {
+            added: added.len(),
+            changed: changed.len(),
+            unchanged: local
+                .len()
+                .saturating_sub(added.len())
+                .saturating_sub(changed.len()),
+        })
+    }
+
+    /// POST /edges/bulk → add `(from_entity, to_entity, weight)` edges directly.
+    ///
+    /// Weights are added onto existing edges and missing entities are
+    /// created. Returns how many edges the server applied (non-positive
+    /// weights are skipped).
+    pub async fn ingest_edges(&self, edges: &[(u64, u64, i64)]) -> Result<usize, ClientError> {
+        self.require("/edges/bulk")?;
+        let body: Vec<EntityEdge> = edges
+            .iter()
+            .map(|&(from_entity, to_entity, weight)| EntityEdge {
+                from_entity,
+                to_entity,
+                weight,
+            })
+            .collect();
+        let req = self
+            .request(reqwest::Method::POST, "/edges/bulk")
+            .json(&body);
+        let resp = self.send(req).await?;
+        let value = self.handle_response(resp).await?;
+        value["applied"]
+            .as_u64()
+            .and_then(|n| usize::try_from(n).ok())
+            .ok_or_else(|| ClientError::ParseError("bulk edges response has no count".into()))
+    }
+
     /// POST /signal/retract → decrement edge weight between two entities.
     pub async fn retract(&self, from_entity: u64, to_entity: u64) -> Result<Value, ClientError> {
+        self.require("/signal/retract")?;
         let body = serde_json::json!({
             "from_entity": from_entity,
             "to_entity": to_entity,
@@ -145,10 +1174,1159 @@ impl KremisClient {
         self.handle_response(resp).await
     }
 
+    /// PATCH /edge → add a signed delta to an edge's weight (removed at zero).
+    pub async fn adjust_edge(
+        &self,
+        from_entity: u64,
+        to_entity: u64,
+        delta: i64,
+    ) -> Result<Value, ClientError> {
+        self.require("/edge")?;
+        let body = serde_json::json!({
+            "from_entity": from_entity,
+            "to_entity": to_entity,
+            "delta": delta,
+        });
+        let req = self.request(reqwest::Method::PATCH, "/edge").json(&body);
+        let resp = self.send(req).await?;
+        self.handle_response(resp).await
+    }
+
+    /// POST /lookup/batch → resolve entity ids to node ids, in request order.
+    pub async fn lookup_batch(&self, entity_ids: &[u64]) -> Result<Vec<Option<u64>>, ClientError> {
+        self.require("/lookup/batch")?;
+        let body = serde_json::json!({ "entity_ids": entity_ids });
+        let req = self
+            .request(reqwest::Method::POST, "/lookup/batch")
+            .json(&body);
+        let resp = self.send(req).await?;
+        let value = self.handle_response(resp).await?;
+        serde_json::from_value(value["node_ids"].clone())
+            .map_err(|e| ClientError::ParseError(e.to_string()))
+    }
+
+    /// GET /explain → facts along the strongest path between two nodes.
+    pub async fn explain_connection(&self, a: u64, b: u64) -> Result<HonestResponse, ClientError> {
+        self.require("/explain")?;
+        let req = self
+            .request(reqwest::Method::GET, "/explain")
+            .query(&[("start", a), ("end", b)]);
+        let resp = self.send(req).await?;
+        let value = self.handle_response(resp).await?;
+        serde_json::from_value(value).map_err(|e| ClientError::ParseError(e.to_string()))
+    }
+
+    /// GET /entity/{id}/top → the `k` heaviest neighbor entities with their weights.
+    pub async fn top_relationships(
+        &self,
+        entity_id: u64,
+        k: usize,
+    ) -> Result<Vec<(u64, i64)>, ClientError> {
+        self.require("/entity/{id}/top")?;
+        let req = self
+            .request(reqwest::Method::GET, &format!("/entity/{entity_id}/top"))
+            .query(&[("k", k)]);
+        let resp = self.send(req).await?;
+        let value = self.handle_response(resp).await?;
+        let neighbors: Vec<EntityWeight> = serde_json::from_value(value["neighbors"].clone())
+            .map_err(|e| ClientError::ParseError(e.to_string()))?;
+        Ok(neighbors
+            .into_iter()
+            .map(|n| (n.entity_id, n.weight))
+            .collect())
+    }
+
+    /// GET /fingerprint → the server graph's canonical checksum.
+    ///
+    /// Equal to `Graph::canonical_hash` of an identical local graph.
+    pub async fn fingerprint(&self) -> Result<u64, ClientError> {
+        self.require("/fingerprint")?;
+        let req = self.request(reqwest::Method::GET, "/fingerprint");
+        let resp = self.send(req).await?;
+        let value = self.handle_response(resp).await?;
+        value["fingerprint"]
+            .as_u64()
+            .ok_or_else(|| ClientError::ParseError("missing fingerprint".to_string()))
+    }
+
     /// GET /hash → canonical BLAKE3 hash of the graph.
     pub async fn hash(&self) -> Result<Value, ClientError> {
+        self.require("/hash")?;
         let req = self.request(reqwest::Method::GET, "/hash");
         let resp = self.send(req).await?;
         self.handle_response(resp).await
     }
 }
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{
+        body_bytes, body_json, body_partial_json, header, method, path, query_param,
+    };
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn lookup_batch_preserves_order() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/lookup/batch"))
+            .and(body_json(serde_json::json!({ "entity_ids": [10, 20, 30] })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "node_ids": [3, null, 1],
+                "error": null
+            })))
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None);
+        let node_ids = client
+            .lookup_batch(&[10, 20, 30])
+            .await
+            .expect("lookup_batch");
+
+        assert_eq!(node_ids, vec![Some(3), None, Some(1)]);
+    }
+
+    #[tokio::test]
+    async fn traverse_sends_strategy() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/query"))
+            .and(body_json(serde_json::json!({
+                "type": "traverse",
+                "node_id": 1,
+                "depth": 2,
+                "strategy": "dfs"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "found": true,
+                "path": [1, 2],
+                "edges": []
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None);
+        let resp = client
+            .traverse(1, 2, TraversalStrategy::Dfs)
+            .await
+            .expect("traverse");
+
+        assert_eq!(resp["path"], serde_json::json!([1, 2]));
+    }
+
+    #[tokio::test]
+    async fn query_sends_deadline_from_timeout() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/query"))
+            .and(body_json(serde_json::json!({
+                "type": "related",
+                "node_id": 1,
+                "depth": 3,
+                "deadline_ms": 1500
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "found": true,
+                "path": [1],
+                "edges": [],
+                "timed_out": true
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            KremisClient::new(server.uri(), None).with_timeout(std::time::Duration::from_secs(2));
+        let resp = client
+            .query(serde_json::json!({"type": "related", "node_id": 1, "depth": 3}))
+            .await
+            .expect("query");
+
+        assert_eq!(resp["timed_out"], serde_json::json!(true));
+    }
+
+    async fn mock_health(version: &str) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/health"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "version": version
+            })))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn connect_old_server_disables_newer_endpoints() {
+        let server = mock_health("0.7.2").await;
+
+        let client = KremisClient::connect(server.uri(), None)
+            .await
+            .expect("connect");
+        assert_eq!(client.capabilities().expect("caps").version, "0.7.2");
+
+        let err = client
+            .lookup_batch(&[1])
+            .await
+            .expect_err("should be gated");
+        assert!(matches!(err, ClientError::Unsupported(_)));
+        let msg = err.to_string();
+        assert!(msg.contains("/lookup/batch"));
+        assert!(msg.contains("0.7.2"));
+        assert!(matches!(
+            client.retract(1, 2).await,
+            Err(ClientError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn baseline_server_lacks_endpoints_added_since() {
+        let caps = ServerCapabilities::from_version("0.11.0");
+        assert!(caps.endpoints.contains("/signal/retract"));
+        for endpoint in ["/signals", "/edges/bulk", "/ready", "/schema/attributes"] {
+            assert!(
+                require_endpoint(Some(&caps), endpoint).is_err(),
+                "{endpoint}"
+            );
+        }
+        let current = ServerCapabilities::from_version(env!("CARGO_PKG_VERSION"));
+        assert!(require_endpoint(Some(&current), "/schema/attributes").is_ok());
+    }
+
+    #[tokio::test]
+    async fn connect_current_server_allows_endpoints() {
+        let server = mock_health("0.12.0").await;
+        Mock::given(method("POST"))
+            .and(path("/lookup/batch"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "node_ids": [null]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::connect(server.uri(), None)
+            .await
+            .expect("connect");
+        assert_eq!(client.lookup_batch(&[1]).await.expect("lookup"), vec![None]);
+    }
+
+    #[test]
+    fn parse_version_handles_suffixes() {
+        assert_eq!(parse_version("0.11.0"), Some((0, 11, 0)));
+        assert_eq!(parse_version("1.2.3-rc1"), Some((1, 2, 3)));
+        assert_eq!(parse_version("2.0"), Some((2, 0, 0)));
+        assert_eq!(parse_version("garbage"), None);
+    }
+
+    #[tokio::test]
+    async fn adjust_edge_sends_signed_delta() {
+        let server = MockServer::start().await;
+        for (delta, weight, removed) in [(3i64, 5i64, false), (-5, 0, true)] {
+            Mock::given(method("PATCH"))
+                .and(path("/edge"))
+                .and(body_json(serde_json::json!({
+                    "from_entity": 1,
+                    "to_entity": 2,
+                    "delta": delta
+                })))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "success": true,
+                    "new_weight": weight,
+                    "removed": removed,
+                    "error": null
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let client = KremisClient::new(server.uri(), None);
+        let up = client.adjust_edge(1, 2, 3).await.expect("positive delta");
+        assert_eq!(up["new_weight"], 5);
+        let down = client.adjust_edge(1, 2, -5).await.expect("negative delta");
+        assert_eq!(down["removed"], true);
+    }
+
+    #[tokio::test]
+    async fn stage_response_exposes_typed_stage() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/stage"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "stage": "S1",
+                "name": "Pattern Crystallization",
+                "progress_percent": 12,
+                "stable_edges_needed": 1000,
+                "stable_edges_current": 120
+            })))
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None);
+        let stage = client.stage().await.expect("stage");
+        assert_eq!(stage.stage_enum(), Some(Stage::S1));
+    }
+
+    #[test]
+    fn stage_roundtrip_and_unknown() {
+        for stage in [Stage::S0, Stage::S1, Stage::S2, Stage::S3] {
+            let json = serde_json::to_string(&stage).expect("serialize");
+            assert_eq!(json, format!("\"{stage}\""));
+            assert_eq!(stage.to_string().parse::<Stage>().expect("parse"), stage);
+        }
+        assert!("S7".parse::<Stage>().is_err());
+
+        let response = StageResponse {
+            stage: "S7".to_string(),
+            name: String::new(),
+            progress_percent: 0,
+            stable_edges_needed: 0,
+            stable_edges_current: 0,
+        };
+        assert_eq!(response.stage_enum(), None);
+    }
+
+    #[tokio::test]
+    async fn ingest_bulk_retries_with_same_key_and_dedups() {
+        let server = MockServer::start().await;
+        // The two unique signals, in first-occurrence order
+        let expected_body = serde_json::json!([
+            {"entity_id": 1, "attribute": "name", "value": "Alice"},
+            {"entity_id": 2, "attribute": "name", "value": "Bob"}
+        ]);
+        Mock::given(method("POST"))
+            .and(path("/signals"))
+            .and(body_json(expected_body.clone()))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/signals"))
+            .and(body_json(expected_body))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"success": true, "node_id": 0, "error": null},
+                {"success": true, "node_id": 1, "error": null}
+            ])))
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None);
+        let signals = vec![
+            Signal::new(1, "name", "Alice"),
+            Signal::new(2, "name", "Bob"),
+            Signal::new(1, "name", "Alice"),
+        ];
+        let summary = client
+            .ingest_bulk(&signals, BulkOptions::default())
+            .await
+            .expect("bulk ingest");
+
+        assert_eq!(
+            summary,
+            BulkSummary {
+                submitted: 2,
+                succeeded: 2,
+                failed: 0,
+                deduplicated: 1,
+                retries: 1,
+            }
+        );
+
+        let requests = server.received_requests().await.expect("recorded");
+        assert_eq!(requests.len(), 2);
+        let keys: Vec<_> = requests
+            .iter()
+            .map(|r| r.headers.get("idempotency-key").cloned())
+            .collect();
+        assert!(keys[0].is_some());
+        assert_eq!(keys[0], keys[1]);
+    }
+
+    #[tokio::test]
+    async fn ingest_bulk_counts_exhausted_batches_as_failed() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/signals"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None);
+        let opts = BulkOptions {
+            batch_size: 1,
+            max_retries: 1,
+            dedup: false,
+            idempotency: false,
+        };
+        let signals = vec![Signal::new(1, "k", "v"), Signal::new(1, "k", "v")];
+        let summary = client.ingest_bulk(&signals, opts).await.expect("summary");
+
+        assert_eq!(summary.submitted, 2);
+        assert_eq!(summary.failed, 2);
+        assert_eq!(summary.retries, 2);
+    }
+
+    #[tokio::test]
+    async fn sync_pushes_only_the_extra_edge() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/edges"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "edges": [{"from_entity": 1, "to_entity": 2, "weight": 5}],
+                "error": null
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/edges/bulk"))
+            .and(body_json(serde_json::json!([{
+                "from_entity": 2,
+                "to_entity": 3,
+                "weight": 1
+            }])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "applied": 1,
+                "error": null
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let local = vec![
+            EntityEdge {
+                from_entity: 1,
+                to_entity: 2,
+                weight: 5,
+            },
+            EntityEdge {
+                from_entity: 2,
+                to_entity: 3,
+                weight: 1,
+            },
+        ];
+        let client = KremisClient::new(server.uri(), None);
+        let report = client.sync(&local).await.expect("sync");
+
+        assert_eq!(
+            report,
+            SyncReport {
+                added: 1,
+                changed: 0,
+                unchanged: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn sync_reports_rejected_batch_as_status() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/edges"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "edges": [],
+                "error": null
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/edges/bulk"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "success": false,
+                "applied": 0,
+                "error": "weight must be positive"
+            })))
+            .mount(&server)
+            .await;
+
+        let local = [EntityEdge {
+            from_entity: 1,
+            to_entity: 2,
+            weight: -3,
+        }];
+        let client = KremisClient::new(server.uri(), None);
+        let err = client.sync(&local).await.expect_err("rejected");
+        assert!(
+            matches!(&err, ClientError::Status { code: 400, body } if body.contains("weight must be positive")),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn diff_edges_detects_weight_changes() {
+        let edge = |from, to, weight| EntityEdge {
+            from_entity: from,
+            to_entity: to,
+            weight,
+        };
+        let server = [edge(1, 2, 5), edge(7, 8, 1)];
+        let local = [edge(1, 2, 6), edge(3, 4, 1)];
+
+        let (added, changed) = diff_edges(&server, &local);
+        assert_eq!(added, vec![edge(3, 4, 1)]);
+        assert_eq!(changed, vec![edge(1, 2, 6)]);
+    }
+
+    #[tokio::test]
+    async fn edges_above_collects_every_page() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/edges"))
+            .and(body_json(
+                serde_json::json!({"min_weight": 10, "offset": 0}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "edges": [{"from_entity": 1, "to_entity": 2, "weight": 10}],
+                "total": 2,
+                "next_offset": 1,
+                "error": null
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/edges"))
+            .and(body_json(
+                serde_json::json!({"min_weight": 10, "offset": 1}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "edges": [{"from_entity": 2, "to_entity": 3, "weight": 14}],
+                "total": 2,
+                "next_offset": null,
+                "error": null
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None);
+        let edges = client.edges_above(10).await.expect("edges");
+
+        assert_eq!(edges.len(), 2);
+        assert!(edges.iter().all(|e| e.weight >= 10));
+    }
+
+    #[tokio::test]
+    async fn fingerprint_returns_checksum() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/fingerprint"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "fingerprint": 18_446_744_073_709_551_557u64,
+                "error": null
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None);
+        assert_eq!(
+            client.fingerprint().await.expect("fingerprint"),
+            18_446_744_073_709_551_557
+        );
+    }
+
+    #[tokio::test]
+    async fn top_relationships_returns_ranked_pairs() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/entity/7/top"))
+            .and(query_param("k", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "neighbors": [
+                    {"entity_id": 3, "weight": 9},
+                    {"entity_id": 2, "weight": 4}
+                ],
+                "error": null
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None);
+        let top = client.top_relationships(7, 2).await.expect("top");
+        assert_eq!(top, vec![(3, 9), (2, 4)]);
+    }
+
+    #[tokio::test]
+    async fn explain_connection_returns_path_facts() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/explain"))
+            .and(query_param("start", "1"))
+            .and(query_param("end", "3"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "facts": [
+                    {"statement": "node 1 -> node 2 (weight 4)", "evidence_path": [1, 2]},
+                    {"statement": "node 2 -> node 3 (weight 2)", "evidence_path": [2, 3]}
+                ],
+                "inferences": [],
+                "unknowns": []
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None);
+        let explained = client.explain_connection(1, 3).await.expect("explain");
+
+        let path: Vec<Vec<u64>> = explained
+            .facts
+            .iter()
+            .map(|f| f.evidence_path.clone())
+            .collect();
+        assert_eq!(path, vec![vec![1, 2], vec![2, 3]]);
+        assert!(explained.unknowns.is_empty());
+    }
+
+    #[tokio::test]
+    async fn ready_reports_loading_then_ready() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ready"))
+            .respond_with(ResponseTemplate::new(503).set_body_json(serde_json::json!({
+                "ready": false,
+                "loaded_nodes": 40,
+                "total_nodes": 100
+            })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/ready"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "ready": true,
+                "loaded_nodes": 100,
+                "total_nodes": 100
+            })))
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None);
+        let loading = client.ready().await.expect("loading");
+        assert!(!loading.ready);
+        assert_eq!((loading.loaded_nodes, loading.total_nodes), (40, 100));
+
+        let ready = client.ready().await.expect("ready");
+        assert!(ready.ready);
+        assert_eq!(ready.loaded_nodes, 100);
+    }
+
+    #[tokio::test]
+    async fn binary_query_roundtrips_postcard() {
+        let server = MockServer::start().await;
+        let expected = BinaryQueryBody {
+            request: BinaryQueryRequest::Traverse {
+                node_id: 7,
+                depth: 2,
+                strategy: TraversalStrategy::Dfs,
+            },
+            deadline_ms: None,
+        };
+        let reply = BinaryQueryResponse {
+            success: true,
+            found: true,
+            path: vec![7, 8],
+            edges: vec![EdgeJson {
+                from: 7,
+                to: 8,
+                weight: 3,
+            }],
+            properties: vec![],
+            grounding: "fact".into(),
+            error: None,
+            diagnostic: None,
+            timed_out: false,
+        };
+        Mock::given(method("POST"))
+            .and(path("/query"))
+            .and(header("content-type", POSTCARD_CONTENT_TYPE))
+            .and(body_bytes(
+                postcard::to_allocvec(&expected).expect("encode"),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                postcard::to_allocvec(&reply).expect("encode"),
+                POSTCARD_CONTENT_TYPE,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None).with_binary_protocol();
+        let result = client
+            .query(serde_json::json!({"type": "traverse", "node_id": 7, "depth": 2, "strategy": "dfs"}))
+            .await
+            .expect("query");
+
+        assert_eq!(
+            result,
+            serde_json::json!({
+                "success": true,
+                "found": true,
+                "path": [7, 8],
+                "edges": [{"from": 7, "to": 8, "weight": 3}],
+                "grounding": "fact",
+                "error": null
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn snapshot_download_then_upload_roundtrips_bytes() {
+        let snapshot = b"KREM\x01canonical-bytes".to_vec();
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/export"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(snapshot.clone()))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/import"))
+            .and(header("authorization", "Bearer secret"))
+            .and(body_bytes(snapshot.clone()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "node_count": 3,
+                "edge_count": 2,
+                "error": null
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), Some("secret".into()));
+        let downloaded = client.download_snapshot().await.expect("download");
+        assert_eq!(downloaded, snapshot);
+        client.upload_snapshot(&downloaded).await.expect("upload");
+    }
+
+    #[tokio::test]
+    async fn upload_snapshot_without_key_is_unauthorized() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/import"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None);
+        assert!(matches!(
+            client.upload_snapshot(b"KREM").await,
+            Err(ClientError::Unauthorized)
+        ));
+    }
+
+    #[tokio::test]
+    async fn summary_parses_all_sections() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/summary"))
+            .and(query_param("top_k", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": {
+                    "node_count": 4,
+                    "edge_count": 5,
+                    "stable_edges": 1,
+                    "density_millionths": 416666,
+                    "ingest_rate": 3
+                },
+                "stage": {
+                    "stage": "S1",
+                    "name": "Pattern Crystallization",
+                    "progress_percent": 10,
+                    "stable_edges_needed": 100,
+                    "stable_edges_current": 1
+                },
+                "hubs": [
+                    { "entity_id": 7, "degree": 3 },
+                    { "entity_id": 2, "degree": 2 }
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None);
+        let summary = client.summary(2).await.expect("summary");
+
+        assert_eq!(summary.status.node_count, 4);
+        assert_eq!(summary.status.edge_count, 5);
+        assert_eq!(summary.stage.stage_enum(), Some(Stage::S1));
+        assert_eq!(
+            summary.hubs,
+            vec![
+                Hub {
+                    entity_id: 7,
+                    degree: 3
+                },
+                Hub {
+                    entity_id: 2,
+                    degree: 2
+                }
+            ]
+        );
+    }
+
+    fn fast_retry(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            base_delay_ms: 1,
+            max_delay_ms: 5,
+        }
+    }
+
+    #[tokio::test]
+    async fn retry_recovers_after_one_server_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/status"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("boom"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/status"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "node_count": 3 })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None).with_retry(fast_retry(3));
+        let status = client.status().await.expect("status after retry");
+        assert_eq!(status["node_count"], 3);
+    }
+
+    #[tokio::test]
+    async fn retry_gives_up_after_max_retries() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/signal"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("down"))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None).with_retry(fast_retry(2));
+        let result = client.ingest(1, "name", "Alice").await;
+        assert!(matches!(result, Err(ClientError::Status { code: 503, .. })));
+    }
+
+    #[tokio::test]
+    async fn retried_ingest_reuses_one_idempotency_key() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/signal"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/signal"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true, "node_id": 0, "error": null
+            })))
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None).with_retry(fast_retry(2));
+        client.ingest(1, "name", "Alice").await.expect("ingest");
+        client.ingest(2, "name", "Bob").await.expect("ingest");
+
+        let requests = server.received_requests().await.expect("recorded");
+        let keys: Vec<_> = requests
+            .iter()
+            .map(|r| r.headers.get("idempotency-key").cloned())
+            .collect();
+        assert_eq!(keys.len(), 3);
+        assert!(keys[0].is_some());
+        assert_eq!(keys[0], keys[1]);
+        assert_ne!(keys[1], keys[2]);
+    }
+
+    #[tokio::test]
+    async fn ingest_bulk_uses_retry_policy_and_retry_after() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/signals"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/signals"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"success": true, "node_id": 0, "error": null}
+            ])))
+            .mount(&server)
+            .await;
+
+        // Backoff alone would wait a minute; Retry-After: 0 must win
+        let slow = RetryPolicy {
+            max_retries: 0,
+            base_delay_ms: 60_000,
+            max_delay_ms: 60_000,
+        };
+        let client = KremisClient::new(server.uri(), None).with_retry(slow);
+        let summary = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            client.ingest_bulk(&[Signal::new(1, "k", "v")], BulkOptions::default()),
+        )
+        .await
+        .expect("Retry-After honored")
+        .expect("bulk ingest");
+        assert_eq!(summary.succeeded, 1);
+        assert_eq!(summary.retries, 1);
+    }
+
+    #[tokio::test]
+    async fn retry_skips_client_errors_and_honors_retry_after() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/stage"))
+            .respond_with(ResponseTemplate::new(400).set_body_string("bad"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/health"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/health"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "status": "ok" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None).with_retry(fast_retry(3));
+        // Sent once (checked by `expect(1)`) and surfaced as an error.
+        assert!(client.stage().await.is_err());
+        let health = client.health().await.expect("health after 429");
+        assert_eq!(health["status"], "ok");
+    }
+
+    #[tokio::test]
+    async fn ingest_edges_posts_entity_edges() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/edges/bulk"))
+            .and(body_json(serde_json::json!([
+                { "from_entity": 1, "to_entity": 2, "weight": 3 },
+                { "from_entity": 2, "to_entity": 3, "weight": 0 }
+            ])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "applied": 1,
+                "error": null
+            })))
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None);
+        let applied = client
+            .ingest_edges(&[(1, 2, 3), (2, 3, 0)])
+            .await
+            .expect("ingest_edges");
+        assert_eq!(applied, 1);
+    }
+
+    #[tokio::test]
+    async fn ingest_batch_returns_per_signal_results() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/signals"))
+            .and(body_json(serde_json::json!([
+                { "entity_id": 1, "attribute": "name", "value": "Alice" },
+                { "entity_id": 2, "attribute": "name", "value": "Bob" }
+            ])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "success": true, "node_id": 0, "error": null },
+                { "success": true, "node_id": 1, "error": null, "stability_confidence": 0 }
+            ])))
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None);
+        let results = client
+            .ingest_batch(&[
+                Signal::new(1, "name", "Alice"),
+                Signal::new(2, "name", "Bob"),
+            ])
+            .await
+            .expect("ingest_batch");
+
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r.success));
+        assert_eq!(results[1].node_id, Some(1));
+        assert_eq!(results[1].stability_confidence, Some(0));
+    }
+
+    #[tokio::test]
+    async fn ingest_batch_keeps_partial_failures_and_surfaces_rejections() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/signals"))
+            .and(body_json(serde_json::json!([
+                { "entity_id": 1, "attribute": "name", "value": "Alice" },
+                { "entity_id": 2, "attribute": "", "value": "Bob" }
+            ])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "success": true, "node_id": 0, "error": null },
+                { "success": false, "node_id": null, "error": "Invalid signal: empty attribute" }
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/signals"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "success": false,
+                "node_id": null,
+                "error": "Batch size 10001 exceeds maximum 10000"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None);
+        let results = client
+            .ingest_batch(&[Signal::new(1, "name", "Alice"), Signal::new(2, "", "Bob")])
+            .await
+            .expect("ingest_batch");
+        assert!(results[0].success);
+        assert!(!results[1].success);
+        assert!(
+            results[1]
+                .error
+                .as_deref()
+                .is_some_and(|e| e.contains("empty"))
+        );
+
+        let rejected = client.ingest_batch(&[Signal::new(3, "x", "y")]).await;
+        assert!(matches!(
+            rejected,
+            Err(ClientError::Status { code: 400, ref body }) if body.contains("exceeds")
+        ));
+    }
+
+    #[tokio::test]
+    async fn non_success_status_surfaces_code_and_body() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/health"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("no such route"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/status"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("warming up"))
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None);
+        let not_found = client.health().await;
+        assert!(matches!(
+            not_found,
+            Err(ClientError::Status { code: 404, ref body }) if body == "no such route"
+        ));
+
+        let unavailable = client.status().await;
+        assert!(matches!(
+            unavailable,
+            Err(ClientError::Status { code: 503, ref body }) if body == "warming up"
+        ));
+        assert_eq!(
+            unavailable.map_err(|e| e.to_string()).err().as_deref(),
+            Some("HTTP 503: warming up")
+        );
+    }
+    #[tokio::test]
+    async fn attribute_schema_sorts_by_descending_count() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/schema/attributes"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "attributes": [
+                    { "attribute": "role", "count": 1 },
+                    { "attribute": "name", "count": 3 },
+                    { "attribute": "email", "count": 2 },
+                    { "attribute": "age", "count": 1 }
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = KremisClient::new(server.uri(), None);
+        let schema = client.attribute_schema().await.expect("attribute_schema");
+        assert_eq!(
+            schema,
+            vec![
+                ("name".to_string(), 3),
+                ("email".to_string(), 2),
+                ("age".to_string(), 1),
+                ("role".to_string(), 1),
+            ]
+        );
+    }
+    #[tokio::test]
+    async fn traverse_paged_follows_next_offset() {
+        let server = MockServer::start().await;
+        for (offset, path_ids, next) in [
+            (0, vec![1, 2], serde_json::json!(2)),
+            (2, vec![3], serde_json::Value::Null),
+        ] {
+            Mock::given(method("POST"))
+                .and(path("/query"))
+                .and(body_partial_json(serde_json::json!({
+                    "type": "traverse",
+                    "limit": 2,
+                    "offset": offset
+                })))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "success": true,
+                    "found": true,
+                    "path": path_ids,
+                    "edges": [],
+                    "error": null,
+                    "page": { "total": 3, "next_offset": next }
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let client = KremisClient::new(server.uri(), None).with_binary_protocol();
+        let mut path_ids = Vec::new();
+        let pages = client
+            .traverse_paged(1, 3, 2, |page| {
+                path_ids.extend(page["path"].as_array().cloned().unwrap_or_default());
+            })
+            .await
+            .expect("traverse_paged");
+        assert_eq!(pages, 2);
+        assert_eq!(path_ids, vec![1, 2, 3]);
+    }
+}
@@ -9,6 +9,8 @@
 //! Communicates with AI clients (Claude, GPT) via MCP over stdio,
 //! and forwards requests to the Kremis HTTP API.
 
+#[cfg(feature = "blocking")]
+mod blocking;
 mod client;
 mod server;
 
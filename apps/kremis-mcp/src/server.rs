@@ -2,7 +2,7 @@
 //!
 //! Implements `ServerHandler` with 9 MCP tools that proxy to the Kremis HTTP API.
 
-use crate::client::KremisClient;
+use crate::client::{KremisClient, TraversalStrategy};
 use rmcp::{
     ErrorData as McpError, ServerHandler,
     handler::server::{tool::ToolRouter, wrapper::Parameters},
@@ -58,6 +58,9 @@ pub struct TraverseParams {
     /// Return only the K highest-weight edges (optional).
     #[schemars(description = "Return only the K highest-weight edges (optional)")]
     pub top_k: Option<u64>,
+    /// Traversal order: "bfs" (default) or "dfs". DFS ignores top_k.
+    #[schemars(description = "Traversal order: 'bfs' (default) or 'dfs'. DFS ignores top_k")]
+    pub strategy: Option<TraversalStrategy>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -156,14 +159,27 @@ impl KremisMcp {
         params: Parameters<TraverseParams>,
     ) -> Result<CallToolResult, McpError> {
         let depth = params.0.depth.unwrap_or(2);
-        let query = serde_json::json!({
-            "type": "traverse_filtered",
-            "node_id": params.0.node_id,
-            "depth": depth,
-            "min_weight": 0,
-            "top_k": params.0.top_k,
-        });
-        match self.client.query(query).await {
+        let result = match params.0.strategy.unwrap_or_default() {
+            TraversalStrategy::Dfs => self
+                .client
+                .traverse(params.0.node_id, depth as usize, TraversalStrategy::Dfs)
+                .await
+                .map(|mut resp| {
+                    retain_min_weight(&mut resp, params.0.node_id, TRAVERSE_MIN_WEIGHT);
+                    resp
+                }),
+            TraversalStrategy::Bfs => {
+                let query = serde_json::json!({
+                    "type": "traverse_filtered",
+                    "node_id": params.0.node_id,
+                    "depth": depth,
+                    "min_weight": TRAVERSE_MIN_WEIGHT,
+                    "top_k": params.0.top_k,
+                });
+                self.client.query(query).await
+            }
+        };
+        match result {
             Ok(resp) => Ok(CallToolResult::success(vec![Content::text(
                 format_query_response(&resp),
             )])),
@@ -319,6 +335,49 @@ impl ServerHandler for KremisMcp {
 // =============================================================================
 
 /// Format a query response JSON into human-readable text.
+/// Lowest edge weight `kremis_traverse` follows, whichever strategy is used.
+const TRAVERSE_MIN_WEIGHT: i64 = 0;
+
+/// Drop edges lighter than `min_weight` from a traversal response, along with
+/// the nodes only reachable from `start` through them.
+///
+/// The server's DFS traversal has no weight filter, so this gives DFS results
+/// the same cut-off `traverse_filtered` applies to BFS ones.
+fn retain_min_weight(resp: &mut serde_json::Value, start: u64, min_weight: i64) {
+    let Some(edges) = resp.get_mut("edges").and_then(|v| v.as_array_mut()) else {
+        return;
+    };
+    edges.retain(|edge| {
+        edge.get("weight")
+            .and_then(|v| v.as_i64())
+            .is_some_and(|w| w >= min_weight)
+    });
+
+    let mut reachable = std::collections::BTreeSet::from([start]);
+    let mut grew = true;
+    while grew {
+        grew = false;
+        for edge in edges.iter() {
+            let from = edge.get("from").and_then(|v| v.as_u64());
+            let to = edge.get("to").and_then(|v| v.as_u64());
+            if let (Some(from), Some(to)) = (from, to)
+                && reachable.contains(&from)
+            {
+                grew |= reachable.insert(to);
+            }
+        }
+    }
+    edges.retain(|edge| {
+        edge.get("from")
+            .and_then(|v| v.as_u64())
+            .is_some_and(|from| reachable.contains(&from))
+    });
+
+    if let Some(path) = resp.get_mut("path").and_then(|v| v.as_array_mut()) {
+        path.retain(|node| node.as_u64().is_some_and(|id| reachable.contains(&id)));
+    }
+}
+
 fn format_query_response(resp: &serde_json::Value) -> String {
     let found = resp.get("found").and_then(|v| v.as_bool()).unwrap_or(false);
     if !found {
@@ -385,3 +444,40 @@ fn format_query_response(resp: &serde_json::Value) -> String {
         parts.join("\n")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn traverse_params_reject_unknown_strategy() {
+        let params: TraverseParams =
+            serde_json::from_value(serde_json::json!({"node_id": 1, "strategy": "dfs"}))
+                .expect("dfs is a valid strategy");
+        assert_eq!(params.strategy, Some(TraversalStrategy::Dfs));
+
+        let typo = serde_json::from_value::<TraverseParams>(
+            serde_json::json!({"node_id": 1, "strategy": "dsf"}),
+        );
+        assert!(typo.is_err());
+    }
+
+    #[test]
+    fn retain_min_weight_prunes_light_edges_and_their_subtrees() {
+        let mut resp = serde_json::json!({
+            "found": true,
+            "path": [1, 2, 3, 4],
+            "edges": [
+                {"from": 1, "to": 2, "weight": 5},
+                {"from": 1, "to": 3, "weight": -1},
+                {"from": 3, "to": 4, "weight": 7},
+            ],
+        });
+        retain_min_weight(&mut resp, 1, 0);
+        assert_eq!(resp["path"], serde_json::json!([1, 2]));
+        assert_eq!(
+            resp["edges"],
+            serde_json::json!([{"from": 1, "to": 2, "weight": 5}])
+        );
+    }
+}
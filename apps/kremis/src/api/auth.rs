@@ -5,7 +5,7 @@
 //! ## Configuration
 //!
 //! Authentication is configured via environment variable:
-//! - `KREMIS_API_KEY`: If set, all requests (except /health) require this key
+//! - `KREMIS_API_KEY`: If set, all requests (except /health and /ready) require this key
 //!
 //! ## Usage
 //!
@@ -39,7 +39,7 @@ pub fn get_api_key_from_env() -> Option<String> {
 /// API key authentication middleware.
 ///
 /// If `KREMIS_API_KEY` is set:
-/// - `/health` endpoint is always allowed (for load balancer health checks)
+/// - `/health` and `/ready` endpoints are always allowed (for load balancer and orchestrator probes)
 /// - All other endpoints require `Authorization: Bearer <key>` header
 ///
 /// If `KREMIS_API_KEY` is not set, all requests are allowed.
@@ -54,8 +54,8 @@ pub async fn api_key_auth_middleware(
         return Ok(next.run(request).await);
     };
 
-    // Always allow health endpoint (for load balancer checks)
-    if request.uri().path() == "/health" {
+    // Always allow health and readiness endpoints (for load balancer checks)
+    if matches!(request.uri().path(), "/health" | "/ready") {
         return Ok(next.run(request).await);
     }
 
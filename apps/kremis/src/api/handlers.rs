@@ -4,19 +4,36 @@
 
 use super::{
     AppState,
+    auth::get_api_key_from_env,
     types::{
-        ExportResponse, HealthResponse, IngestRequest, IngestResponse, PropertyJson, QueryRequest,
-        QueryResponse, RetractRequest, RetractResponse, StageResponse, StatusResponse,
+        AdjustEdgeRequest, AdjustEdgeResponse, AttributeCountJson, AttributeSchemaResponse,
+        BatchLookupRequest, BatchLookupResponse, BinaryQueryBody, BinaryQueryResponse,
+        BulkEdgesResponse, DEFAULT_EDGE_PAGE, DEFAULT_TOP_K, EdgeJson, EdgeListResponse,
+        EdgePageResponse, EdgesAboveRequest, EntityEdgeJson, EntityWeightJson, ExplainQuery,
+        ExportResponse, FingerprintResponse, HealthResponse, HubJson, ImportResponse,
+        IngestRequest, IngestResponse, MAX_EDGE_PAGE, OCTET_STREAM_CONTENT_TYPE,
+        POSTCARD_CONTENT_TYPE, PropertyJson, ProvenanceQuery, ProvenanceResponse, PutEdgeRequest,
+        QueryBody, QueryRequest, QueryResponse, ReadyResponse, RetractRequest, RetractResponse,
+        SourceCountJson, StageResponse, StatusResponse, SummaryQuery, SummaryResponse, TopQuery,
+        TopRelationshipsResponse, TraversalStrategy, resolve_edge, resolve_entity,
     },
 };
-use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use axum::{
+    Json,
+    body::Bytes,
+    extract::{FromRequest, Path, Query, Request, State},
+    http::{HeaderMap, HeaderName, StatusCode, header},
+    response::{IntoResponse, Response},
+};
 use kremis_core::{
-    Artifact, EdgeWeight, EntityId, KremisError, NodeId, Session,
-    export::{canonical_checksum, canonical_crypto_hash, export_canonical},
+    Artifact, EdgeWeight, EntityId, Fact, GraphStore, HonestResponse, KremisError, NodeId, Session,
+    Unknown,
+    export::{canonical_checksum, canonical_crypto_hash, export_canonical, import_canonical},
     primitives::{MAX_INTERSECT_NODES, MAX_TRAVERSAL_DEPTH},
     system::{GraphMetrics, Stage, StageAssessor},
 };
 use std::collections::BTreeSet;
+use std::time::{Duration, Instant};
 
 // =============================================================================
 // HEALTH HANDLER
@@ -27,6 +44,29 @@ pub async fn health_handler() -> impl IntoResponse {
     Json(HealthResponse::default())
 }
 
+/// Readiness endpoint: `503` with progress until the initial load finishes.
+pub async fn ready_handler(State(state): State<AppState>) -> impl IntoResponse {
+    if let Some((loaded_nodes, total_nodes)) = state.load.progress() {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ReadyResponse {
+                ready: false,
+                loaded_nodes,
+                total_nodes,
+            }),
+        );
+    }
+    let nodes = state.session.read().await.node_count();
+    (
+        StatusCode::OK,
+        Json(ReadyResponse {
+            ready: true,
+            loaded_nodes: nodes,
+            total_nodes: nodes,
+        }),
+    )
+}
+
 // =============================================================================
 // STATUS HANDLER
 // =============================================================================
@@ -34,16 +74,19 @@ pub async fn health_handler() -> impl IntoResponse {
 /// Get graph status.
 pub async fn status_handler(State(state): State<AppState>) -> impl IntoResponse {
     let session = state.session.read().await;
-    let metrics = GraphMetrics::from_session(&session);
+    let response = status_response(&session, state.ingest_rate.lock().await.rate());
+    (StatusCode::OK, Json(response))
+}
 
-    let response = StatusResponse {
+fn status_response(session: &Session, ingest_rate: u64) -> StatusResponse {
+    let metrics = GraphMetrics::from_session(session);
+    StatusResponse {
         node_count: metrics.node_count,
         edge_count: metrics.edge_count,
         stable_edges: metrics.stable_edge_count,
         density_millionths: metrics.density_millionths,
-    };
-
-    (StatusCode::OK, Json(response))
+        ingest_rate,
+    }
 }
 
 // =============================================================================
@@ -53,27 +96,113 @@ pub async fn status_handler(State(state): State<AppState>) -> impl IntoResponse
 /// Get developmental stage.
 pub async fn stage_handler(State(state): State<AppState>) -> impl IntoResponse {
     let session = state.session.read().await;
-    let assessor = StageAssessor::new();
-    let progress = assessor.progress_to_next_session(&session);
+    (StatusCode::OK, Json(stage_response(&session)))
+}
 
-    let response = StageResponse {
-        stage: format!("{:?}", progress.current),
+fn stage_response(session: &Session) -> StageResponse {
+    let progress = StageAssessor::new().progress_to_next_session(session);
+    StageResponse {
+        stage: progress.current.code().to_string(),
         name: progress.current.name().to_string(),
         progress_percent: progress.percent,
         stable_edges_needed: progress.stable_edges_needed,
         stable_edges_current: progress.stable_edges_current,
+    }
+}
+
+// =============================================================================
+// SUMMARY HANDLER
+// =============================================================================
+
+/// Status, stage, and the `top_k` highest-degree entities in one call.
+pub async fn summary_handler(
+    State(state): State<AppState>,
+    Query(query): Query<SummaryQuery>,
+) -> Response {
+    let session = state.session.read().await;
+    let graph = match session.export_graph_snapshot() {
+        Ok(g) => g,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to build graph snapshot: {}", e),
+            )
+                .into_response();
+        }
     };
 
-    (StatusCode::OK, Json(response))
+    let hubs = graph
+        .hubs(query.top_k.unwrap_or(DEFAULT_TOP_K))
+        .into_iter()
+        .filter_map(|(node, degree)| {
+            session.entity_of(node).map(|entity| HubJson {
+                entity_id: entity.0,
+                degree,
+            })
+        })
+        .collect();
+    let response = SummaryResponse {
+        status: status_response(&session, state.ingest_rate.lock().await.rate()),
+        stage: stage_response(&session),
+        hubs,
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+// =============================================================================
+// SCHEMA HANDLER
+// =============================================================================
+
+/// Every ingested attribute with the number of entities carrying it.
+pub async fn attribute_schema_handler(State(state): State<AppState>) -> Response {
+    let session = state.session.read().await;
+    let graph = match session.export_graph_snapshot() {
+        Ok(g) => g,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to build graph snapshot: {}", e),
+            )
+                .into_response();
+        }
+    };
+
+    let attributes = graph
+        .attribute_usage()
+        .into_iter()
+        .map(|(attribute, count)| AttributeCountJson {
+            attribute: attribute.as_str().to_string(),
+            count,
+        })
+        .collect();
+    (StatusCode::OK, Json(AttributeSchemaResponse { attributes })).into_response()
 }
 
 // =============================================================================
 // INGEST HANDLER
 // =============================================================================
 
+/// Header naming a client-chosen key that makes an ingest idempotent.
+pub const IDEMPOTENCY_KEY_HEADER: HeaderName = HeaderName::from_static("idempotency-key");
+
+/// Idempotency cache key for `route`, if the request carries one.
+///
+/// Scoped by route so a `/signal` key never replays a `/signals` batch.
+fn idempotency_key(headers: &HeaderMap, route: &str) -> Option<String> {
+    headers
+        .get(&IDEMPOTENCY_KEY_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|key| format!("{route} {key}"))
+}
+
 /// Ingest a signal.
+///
+/// If an `Idempotency-Key` header repeats a recent successful ingest, the
+/// original response is returned without ingesting again (safe client
+/// retries).
 pub async fn ingest_handler(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(request): Json<IngestRequest>,
 ) -> impl IntoResponse {
     // Validate and convert request to signal
@@ -87,10 +216,32 @@ pub async fn ingest_handler(
         }
     };
 
+    let key = idempotency_key(&headers, "/signal");
+
     // Get write lock and ingest
     let mut session = state.session.write().await;
+    if let Some(key) = &key
+        && let Some(previous) = state.idempotency_cache.lock().await.get(key)
+        && let Some(response) = previous.first()
+    {
+        return (StatusCode::OK, Json(response.clone()));
+    }
+
     match session.ingest(&signal) {
-        Ok(node_id) => (StatusCode::OK, Json(IngestResponse::success(node_id))),
+        Ok(node_id) => {
+            state.invalidate_query_cache().await;
+            state.ingest_rate.lock().await.record(1);
+            let response = IngestResponse::success(node_id)
+                .with_stability(session.strongest_edge_weight(node_id));
+            if let Some(key) = key {
+                state
+                    .idempotency_cache
+                    .lock()
+                    .await
+                    .insert(key, vec![response.clone()]);
+            }
+            (StatusCode::OK, Json(response))
+        }
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(IngestResponse::error(format!("Ingest failed: {}", e))),
@@ -98,6 +249,92 @@ pub async fn ingest_handler(
     }
 }
 
+// =============================================================================
+// BATCH INGEST HANDLER
+// =============================================================================
+
+/// Maximum number of signals accepted by a single batch ingest.
+const MAX_INGEST_BATCH: usize = 10_000;
+
+/// Ingest a batch of signals, returning one response per signal in order.
+///
+/// The batch is a sequence: each ingested signal reinforces the edge from
+/// the previously ingested one, and that signal's `source` is recorded as
+/// the edge's provenance. Invalid signals are rejected individually and
+/// skipped; the rest are still ingested.
+/// If an `Idempotency-Key` header repeats a recent batch, the original
+/// responses are returned without ingesting again (safe client retries).
+pub async fn ingest_batch_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(requests): Json<Vec<IngestRequest>>,
+) -> Response {
+    if requests.len() > MAX_INGEST_BATCH {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(IngestResponse::error(format!(
+                "Batch size {} exceeds maximum {}",
+                requests.len(),
+                MAX_INGEST_BATCH
+            ))),
+        )
+            .into_response();
+    }
+
+    let key = idempotency_key(&headers, "/signals");
+
+    let mut session = state.session.write().await;
+    if let Some(key) = &key
+        && let Some(previous) = state.idempotency_cache.lock().await.get(key)
+    {
+        return (StatusCode::OK, Json(previous.clone())).into_response();
+    }
+
+    let mut provenance = state.provenance.lock().await;
+    let mut previous: Option<(NodeId, EntityId)> = None;
+    let mut responses = Vec::with_capacity(requests.len());
+    for request in &requests {
+        let response = match request.to_signal() {
+            Ok(signal) => match session.ingest_after(previous.map(|(node, _)| node), &signal) {
+                Ok(node_id) => {
+                    if let (Some((_, prev_entity)), Some(source)) = (previous, &signal.source)
+                        && session.attribute_weight(&signal.attribute) > 0
+                    {
+                        let count = provenance
+                            .entry((prev_entity.0, signal.entity.0))
+                            .or_default()
+                            .entry(source.clone())
+                            .or_default();
+                        *count = count.saturating_add(1);
+                    }
+                    previous = Some((node_id, signal.entity));
+                    IngestResponse::success(node_id)
+                        .with_stability(session.strongest_edge_weight(node_id))
+                }
+                Err(e) => IngestResponse::error(format!("Ingest failed: {}", e)),
+            },
+            Err(e) => IngestResponse::error(format!("Invalid signal: {}", e)),
+        };
+        responses.push(response);
+    }
+    drop(provenance);
+
+    let ingested = responses.iter().filter(|r| r.success).count() as u64;
+    if ingested > 0 {
+        state.invalidate_query_cache().await;
+        state.ingest_rate.lock().await.record(ingested);
+    }
+    if let Some(key) = key {
+        state
+            .idempotency_cache
+            .lock()
+            .await
+            .insert(key, responses.clone());
+    }
+
+    (StatusCode::OK, Json(responses)).into_response()
+}
+
 // =============================================================================
 // RETRACT HANDLER
 // =============================================================================
@@ -109,9 +346,19 @@ pub async fn retract_handler(
     State(state): State<AppState>,
     Json(request): Json<RetractRequest>,
 ) -> impl IntoResponse {
+    let (from_entity, to_entity) =
+        match resolve_edge(request.from_entity, request.to_entity, request.namespace) {
+            Ok(pair) => pair,
+            Err(e) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(RetractResponse::error(e.to_string())),
+                );
+            }
+        };
     let mut session = state.session.write().await;
 
-    let from_node = match session.lookup_entity(EntityId(request.from_entity)) {
+    let from_node = match session.lookup_entity(from_entity) {
         Some(n) => n,
         None => {
             return (
@@ -120,7 +367,7 @@ pub async fn retract_handler(
             );
         }
     };
-    let to_node = match session.lookup_entity(EntityId(request.to_entity)) {
+    let to_node = match session.lookup_entity(to_entity) {
         Some(n) => n,
         None => {
             return (
@@ -132,6 +379,7 @@ pub async fn retract_handler(
 
     match session.decrement_edge(from_node, to_node) {
         Ok(()) => {
+            state.invalidate_query_cache().await;
             let new_weight = session
                 .get_edge(from_node, to_node)
                 .map(|w| w.value())
@@ -150,24 +398,552 @@ pub async fn retract_handler(
 }
 
 // =============================================================================
-// QUERY HANDLER
+// EDGE ADJUST HANDLER
 // =============================================================================
 
-/// Execute a query.
-pub async fn query_handler(
+/// Adjust an edge's weight by a signed delta (saturating, removed at zero).
+///
+/// Returns 404 if either entity or the edge does not exist.
+pub async fn adjust_edge_handler(
+    State(state): State<AppState>,
+    Json(request): Json<AdjustEdgeRequest>,
+) -> impl IntoResponse {
+    let (from_entity, to_entity) =
+        match resolve_edge(request.from_entity, request.to_entity, request.namespace) {
+            Ok(pair) => pair,
+            Err(e) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(AdjustEdgeResponse::error(e.to_string())),
+                );
+            }
+        };
+    let mut session = state.session.write().await;
+
+    let (Some(from_node), Some(to_node)) = (
+        session.lookup_entity(from_entity),
+        session.lookup_entity(to_entity),
+    ) else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(AdjustEdgeResponse::error("entity not found")),
+        );
+    };
+
+    match session.adjust_edge(from_node, to_node, request.delta) {
+        Ok(weight) => {
+            state.invalidate_query_cache().await;
+            let key = (from_entity.0, to_entity.0);
+            let mut provenance = state.provenance.lock().await;
+            if weight.value() == 0 {
+                provenance.remove(&key);
+            } else if let Some(source) = request.source.filter(|_| request.delta > 0) {
+                let count = provenance
+                    .entry(key)
+                    .or_default()
+                    .entry(source)
+                    .or_default();
+                *count = count.saturating_add(1);
+            }
+            (
+                StatusCode::OK,
+                Json(AdjustEdgeResponse::success(weight.value())),
+            )
+        }
+        Err(KremisError::EdgeNotFound(_, _)) => (
+            StatusCode::NOT_FOUND,
+            Json(AdjustEdgeResponse::error("edge not found")),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(AdjustEdgeResponse::error(format!("adjust failed: {}", e))),
+        ),
+    }
+}
+
+/// List the sources that reinforced an edge via `PATCH /edge` or `POST /signals`.
+///
+/// Returns 404 if either entity or the edge does not exist. Provenance is
+/// held in server memory and dropped when the edge is removed.
+pub async fn provenance_handler(
+    State(state): State<AppState>,
+    Query(query): Query<ProvenanceQuery>,
+) -> impl IntoResponse {
+    let (from_entity, to_entity) =
+        match resolve_edge(query.from_entity, query.to_entity, query.namespace) {
+            Ok(pair) => pair,
+            Err(e) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(ProvenanceResponse::error(e.to_string())),
+                );
+            }
+        };
+    let session = state.session.read().await;
+    let edge = match (
+        session.lookup_entity(from_entity),
+        session.lookup_entity(to_entity),
+    ) {
+        (Some(from), Some(to)) => session.get_edge(from, to),
+        _ => None,
+    };
+    if edge.is_none() {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(ProvenanceResponse::error("edge not found")),
+        );
+    }
+
+    let sources = state
+        .provenance
+        .lock()
+        .await
+        .get(&(from_entity.0, to_entity.0))
+        .map(|sources| {
+            sources
+                .iter()
+                .map(|(source, reinforcements)| SourceCountJson {
+                    source: source.clone(),
+                    reinforcements: *reinforcements,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    (StatusCode::OK, Json(ProvenanceResponse::success(sources)))
+}
+
+// =============================================================================
+// EXPLAIN HANDLER
+// =============================================================================
+
+/// Explain how two nodes are connected.
+///
+/// Each edge on the strongest path becomes a fact whose evidence is the
+/// edge's endpoints. A missing node or path is reported as an unknown.
+pub async fn explain_handler(
+    State(state): State<AppState>,
+    Query(query): Query<ExplainQuery>,
+) -> impl IntoResponse {
+    let session = state.session.read().await;
+    Json(explain_connection(
+        &session,
+        NodeId(query.start),
+        NodeId(query.end),
+    ))
+}
+
+/// Build the honest explanation of the strongest path from `start` to `end`.
+fn explain_connection(session: &Session, start: NodeId, end: NodeId) -> HonestResponse {
+    let Some(path) = session.strongest_path(start, end) else {
+        return HonestResponse::new().with_unknown(Unknown::new(format!(
+            "no path from node {} to node {}",
+            start.0, end.0
+        )));
+    };
+
+    path.windows(2)
+        .fold(HonestResponse::new(), |response, pair| {
+            let (from, to) = (pair[0], pair[1]);
+            let weight = session.get_edge(from, to).map_or(0, |w| w.value());
+            response.with_fact(Fact::new(
+                format!("node {} -> node {} (weight {weight})", from.0, to.0),
+                vec![from, to],
+            ))
+        })
+}
+
+// =============================================================================
+// TOP RELATIONSHIPS HANDLER
+// =============================================================================
+
+/// List the `k` heaviest outgoing relationships of an entity, by entity id.
+///
+/// With `?namespace=`, the path id is resolved in that namespace; neighbor
+/// ids are always returned in full (namespace folded in).
+pub async fn top_relationships_handler(
+    State(state): State<AppState>,
+    Path(entity_id): Path<u64>,
+    Query(query): Query<TopQuery>,
+) -> impl IntoResponse {
+    let entity = match resolve_entity(entity_id, query.namespace) {
+        Ok(entity) => entity,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(TopRelationshipsResponse::error(e.to_string())),
+            );
+        }
+    };
+    let session = state.session.read().await;
+    let Some(node) = session.lookup_entity(entity) else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(TopRelationshipsResponse::error("entity not found")),
+        );
+    };
+
+    let neighbors = session
+        .top_neighbors(node, query.k.unwrap_or(DEFAULT_TOP_K))
+        .into_iter()
+        .filter_map(|(neighbor, weight)| {
+            session.entity_of(neighbor).map(|entity| EntityWeightJson {
+                entity_id: entity.0,
+                weight: weight.value(),
+            })
+        })
+        .collect();
+    (
+        StatusCode::OK,
+        Json(TopRelationshipsResponse::success(neighbors)),
+    )
+}
+
+// =============================================================================
+// ENTITY EDGE HANDLERS
+// =============================================================================
+
+/// List every edge by entity id, in deterministic order.
+pub async fn list_edges_handler(State(state): State<AppState>) -> impl IntoResponse {
+    let session = state.session.read().await;
+    let graph = match session.export_graph_snapshot() {
+        Ok(g) => g,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(EdgeListResponse::error(format!("Snapshot failed: {}", e))),
+            );
+        }
+    };
+
+    let edges = graph
+        .relationship_triples()
+        .map(|(from, _, to, weight)| EntityEdgeJson {
+            from_entity: from.0,
+            to_entity: to.0,
+            weight: weight.value(),
+        })
+        .collect();
+    (StatusCode::OK, Json(EdgeListResponse::success(edges)))
+}
+
+/// List edges with weight >= `min_weight` by entity id, one page at a time.
+pub async fn edges_above_handler(
+    State(state): State<AppState>,
+    Json(request): Json<EdgesAboveRequest>,
+) -> impl IntoResponse {
+    let session = state.session.read().await;
+    let graph = match session.export_graph_snapshot() {
+        Ok(g) => g,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(EdgePageResponse::error(format!("Snapshot failed: {}", e))),
+            );
+        }
+    };
+
+    let entity = |node: NodeId| graph.lookup(node).ok().flatten().map(|n| n.entity.0);
+    let matching: Vec<EntityEdgeJson> = graph
+        .stable_edges(EdgeWeight::new(request.min_weight))
+        .filter_map(|(from, to, weight)| {
+            Some(EntityEdgeJson {
+                from_entity: entity(from)?,
+                to_entity: entity(to)?,
+                weight: weight.value(),
+            })
+        })
+        .collect();
+
+    let limit = request
+        .limit
+        .unwrap_or(DEFAULT_EDGE_PAGE)
+        .min(MAX_EDGE_PAGE);
+    let total = matching.len();
+    let end = request.offset.saturating_add(limit);
+    let page = matching
+        .into_iter()
+        .skip(request.offset)
+        .take(limit)
+        .collect();
+    let next_offset = (end < total).then_some(end);
+    (
+        StatusCode::OK,
+        Json(EdgePageResponse::success(page, total, next_offset)),
+    )
+}
+
+/// Set an edge's weight by entity id, creating nodes and the edge if missing.
+///
+/// Weights must be positive.
+pub async fn put_edge_handler(
     State(state): State<AppState>,
-    Json(request): Json<QueryRequest>,
+    Json(request): Json<PutEdgeRequest>,
 ) -> impl IntoResponse {
+    if request.weight <= 0 {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(AdjustEdgeResponse::error("weight must be positive")),
+        );
+    }
+    let (from_entity, to_entity) =
+        match resolve_edge(request.from_entity, request.to_entity, request.namespace) {
+            Ok(pair) => pair,
+            Err(e) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(AdjustEdgeResponse::error(e.to_string())),
+                );
+            }
+        };
+
+    let mut session = state.session.write().await;
+    match session.set_entity_edge(from_entity, to_entity, EdgeWeight::new(request.weight)) {
+        Ok(_) => {
+            state.invalidate_query_cache().await;
+            (
+                StatusCode::OK,
+                Json(AdjustEdgeResponse::success(request.weight)),
+            )
+        }
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(AdjustEdgeResponse::error(format!("put edge failed: {}", e))),
+        ),
+    }
+}
+
+/// Largest number of edges accepted by `POST` or `PUT /edges/bulk`.
+const MAX_BULK_EDGES: usize = 10_000;
+
+/// Add weights onto edges by entity id, creating nodes and edges as needed.
+///
+/// Each weight is added to the current edge weight. Non-positive weights
+/// are skipped; the response counts the edges actually applied.
+pub async fn bulk_edges_handler(
+    State(state): State<AppState>,
+    Json(edges): Json<Vec<EntityEdgeJson>>,
+) -> impl IntoResponse {
+    if edges.len() > MAX_BULK_EDGES {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(BulkEdgesResponse::error(format!(
+                "Batch size {} exceeds maximum {}",
+                edges.len(),
+                MAX_BULK_EDGES
+            ))),
+        );
+    }
+
+    let edges: Vec<(EntityId, EntityId, EdgeWeight)> = edges
+        .iter()
+        .map(|e| {
+            (
+                EntityId(e.from_entity),
+                EntityId(e.to_entity),
+                EdgeWeight::new(e.weight),
+            )
+        })
+        .collect();
+    let mut session = state.session.write().await;
+    match session.ingest_edges(&edges) {
+        Ok(applied) => {
+            if applied > 0 {
+                state.invalidate_query_cache().await;
+            }
+            (StatusCode::OK, Json(BulkEdgesResponse::success(applied)))
+        }
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(BulkEdgesResponse::error(format!(
+                "bulk edges failed: {}",
+                e
+            ))),
+        ),
+    }
+}
+
+/// Set edge weights by entity id in one request, as `PUT /edge` does per edge.
+///
+/// Weights must be positive; a batch with any other weight is rejected
+/// before anything is written.
+pub async fn bulk_set_edges_handler(
+    State(state): State<AppState>,
+    Json(edges): Json<Vec<EntityEdgeJson>>,
+) -> impl IntoResponse {
+    if edges.len() > MAX_BULK_EDGES {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(BulkEdgesResponse::error(format!(
+                "Batch size {} exceeds maximum {}",
+                edges.len(),
+                MAX_BULK_EDGES
+            ))),
+        );
+    }
+    if edges.iter().any(|e| e.weight <= 0) {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(BulkEdgesResponse::error("weight must be positive")),
+        );
+    }
+
+    let mut session = state.session.write().await;
+    let mut applied = 0usize;
+    let result = edges.iter().try_for_each(|e| {
+        session.set_entity_edge(
+            EntityId(e.from_entity),
+            EntityId(e.to_entity),
+            EdgeWeight::new(e.weight),
+        )?;
+        applied = applied.saturating_add(1);
+        Ok::<_, KremisError>(())
+    });
+    if applied > 0 {
+        state.invalidate_query_cache().await;
+    }
+    match result {
+        Ok(()) => (StatusCode::OK, Json(BulkEdgesResponse::success(applied))),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(BulkEdgesResponse::error(format!(
+                "bulk edges failed: {}",
+                e
+            ))),
+        ),
+    }
+}
+
+// =============================================================================
+// QUERY HANDLER
+// =============================================================================
+
+/// Execute a query.
+///
+/// Bodies sent as [`POSTCARD_CONTENT_TYPE`] use the binary protocol and get
+/// a postcard-encoded reply; everything else is JSON. Successful responses
+/// are cached per request until the next mutation.
+pub async fn query_handler(State(state): State<AppState>, request: Request) -> Response {
+    let binary = request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .is_some_and(|v| v.as_bytes() == POSTCARD_CONTENT_TYPE.as_bytes());
+    if binary {
+        return binary_query(state, request).await;
+    }
+    match Json::<QueryBody>::from_request(request, &state).await {
+        Ok(Json(body)) => {
+            let (status, response) = run_query(&state, body).await;
+            (status, Json(response)).into_response()
+        }
+        Err(rejection) => rejection.into_response(),
+    }
+}
+
+/// Decode a postcard `/query` body, run it, and encode the reply.
+async fn binary_query(state: AppState, request: Request) -> Response {
+    let (status, response) = match Bytes::from_request(request, &state).await {
+        Ok(bytes) => match postcard::from_bytes::<BinaryQueryBody>(&bytes) {
+            Ok(body) => run_query(&state, body.into()).await,
+            Err(e) => (
+                StatusCode::BAD_REQUEST,
+                QueryResponse::error(format!("Invalid postcard body: {}", e)),
+            ),
+        },
+        Err(rejection) => return rejection.into_response(),
+    };
+    match postcard::to_allocvec(&BinaryQueryResponse::from(response)) {
+        Ok(encoded) => (
+            status,
+            [(header::CONTENT_TYPE, POSTCARD_CONTENT_TYPE)],
+            encoded,
+        )
+            .into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+/// Execute a decoded query against the session, consulting the cache.
+async fn run_query(state: &AppState, body: QueryBody) -> (StatusCode, QueryResponse) {
+    let QueryBody {
+        request,
+        deadline_ms,
+    } = body;
+    let deadline = deadline_ms.map(|ms| Instant::now() + Duration::from_millis(ms));
     let session = state.session.read().await;
-    match execute_query_session(&session, &request) {
-        Ok(response) => (StatusCode::OK, Json(response)),
+    if let Some(cached) = state.query_cache.lock().await.get(&request) {
+        return (StatusCode::OK, cached.clone());
+    }
+    match execute_query_session(&session, &request, deadline) {
+        Ok(response) => {
+            // Partial results must not answer later, unhurried queries
+            if !response.timed_out {
+                state
+                    .query_cache
+                    .lock()
+                    .await
+                    .insert(request, response.clone());
+            }
+            (StatusCode::OK, response)
+        }
         Err(e) => (
             StatusCode::BAD_REQUEST,
-            Json(QueryResponse::error(format!("Query failed: {}", e))),
+            QueryResponse::error(format!("Query failed: {}", e)),
         ),
     }
 }
 
+/// Stop-predicate calls between clock reads while a deadline is armed.
+const DEADLINE_CHECK_INTERVAL: usize = 32;
+
+/// BFS traversal bounded by an optional deadline.
+///
+/// Without a deadline this is a plain filtered traversal. With one, the clock
+/// is read on the first check and then every `DEADLINE_CHECK_INTERVAL` checks,
+/// so the overhead stays negligible on large graphs.
+fn traverse_until(
+    session: &Session,
+    start: NodeId,
+    depth: usize,
+    min_weight: EdgeWeight,
+    deadline: Option<Instant>,
+) -> Option<(Artifact, bool)> {
+    match deadline {
+        None => session.traverse_interruptible(start, depth, min_weight, |_| false),
+        Some(deadline) => {
+            session.traverse_interruptible(start, depth, min_weight, deadline_reached(deadline))
+        }
+    }
+}
+
+/// Stop predicate for interruptible searches: reads the clock on the first
+/// call and then every `DEADLINE_CHECK_INTERVAL` calls.
+///
+/// Counts its own calls rather than using the visited-node count, which can
+/// skip past any given value between calls.
+fn deadline_reached(deadline: Instant) -> impl FnMut(usize) -> bool {
+    let mut calls = 0usize;
+    move |_visited| {
+        let check = calls.is_multiple_of(DEADLINE_CHECK_INTERVAL);
+        calls = calls.wrapping_add(1);
+        check && Instant::now() >= deadline
+    }
+}
+
+/// Convert a deadline-bounded traversal into a query response.
+fn deadline_response(result: Option<(Artifact, bool)>, top_k: Option<usize>) -> QueryResponse {
+    match result {
+        Some((artifact, timed_out)) => {
+            let response = QueryResponse::with_artifact(&apply_top_k(artifact, top_k));
+            if timed_out {
+                response.with_timeout()
+            } else {
+                response
+            }
+        }
+        None => QueryResponse::not_found().with_diagnostic("node_not_found"),
+    }
+}
+
 /// Validate that depth is within bounds to prevent DoS.
 fn validate_depth(depth: usize) -> Result<(), KremisError> {
     if depth > MAX_TRAVERSAL_DEPTH {
@@ -225,26 +1001,72 @@ fn classify_grounding(request: &QueryRequest, found: bool) -> &'static str {
 fn execute_query_session(
     session: &Session,
     request: &QueryRequest,
+    deadline: Option<Instant>,
 ) -> Result<QueryResponse, KremisError> {
-    let mut response = execute_query_inner(session, request)?;
+    let page = match request {
+        QueryRequest::Traverse { limit, offset, .. }
+        | QueryRequest::Related { limit, offset, .. } => {
+            if *limit == Some(0) {
+                return Err(KremisError::ValidationError(
+                    "limit must be at least 1".to_string(),
+                ));
+            }
+            Some((*offset, *limit))
+        }
+        _ => None,
+    };
+    let mut response = execute_query_inner(session, request, deadline)?;
     response.grounding = classify_grounding(request, response.found).to_string();
+    if let Some((offset, limit)) = page {
+        response = response.paginate(offset, limit);
+    }
     Ok(response)
 }
 
 fn execute_query_inner(
     session: &Session,
     request: &QueryRequest,
+    deadline: Option<Instant>,
 ) -> Result<QueryResponse, KremisError> {
     match request {
-        QueryRequest::Lookup { entity_id } => match session.lookup_entity(EntityId(*entity_id)) {
+        QueryRequest::Lookup {
+            entity_id,
+            namespace,
+        } => match session.lookup_entity(resolve_entity(*entity_id, *namespace)?) {
             Some(node_id) => Ok(QueryResponse::with_path(vec![node_id])),
             None => Ok(QueryResponse::not_found().with_diagnostic("entity_not_found")),
         },
 
-        QueryRequest::Traverse { node_id, depth } => {
+        QueryRequest::Traverse {
+            node_id,
+            depth,
+            strategy,
+            ..
+        } => {
             // Validate depth to prevent DoS
             validate_depth(*depth)?;
-            match session.traverse(NodeId(*node_id), *depth) {
+            if let Some(deadline) = deadline {
+                let result = match strategy {
+                    TraversalStrategy::Bfs => traverse_until(
+                        session,
+                        NodeId(*node_id),
+                        *depth,
+                        EdgeWeight::new(i64::MIN),
+                        Some(deadline),
+                    ),
+                    TraversalStrategy::Dfs => session.traverse_dfs_interruptible(
+                        NodeId(*node_id),
+                        *depth,
+                        deadline_reached(deadline),
+                    ),
+                };
+                return Ok(deadline_response(result, None));
+            }
+            let artifact = match strategy {
+                TraversalStrategy::Bfs => session.traverse(NodeId(*node_id), *depth),
+                TraversalStrategy::Dfs => session.traverse_dfs(NodeId(*node_id), *depth),
+            };
+            match artifact {
                 Some(artifact) => Ok(QueryResponse::with_artifact(&artifact)),
                 None => Ok(QueryResponse::not_found().with_diagnostic("node_not_found")),
             }
@@ -258,6 +1080,16 @@ fn execute_query_inner(
         } => {
             // Validate depth to prevent DoS
             validate_depth(*depth)?;
+            if deadline.is_some() {
+                let result = traverse_until(
+                    session,
+                    NodeId(*node_id),
+                    *depth,
+                    EdgeWeight::new(*min_weight),
+                    deadline,
+                );
+                return Ok(deadline_response(result, *top_k));
+            }
             match session.traverse_filtered(NodeId(*node_id), *depth, EdgeWeight::new(*min_weight))
             {
                 Some(artifact) => {
@@ -269,7 +1101,21 @@ fn execute_query_inner(
         }
 
         QueryRequest::StrongestPath { start, end } => {
-            match session.strongest_path(NodeId(*start), NodeId(*end)) {
+            let path = match deadline {
+                Some(deadline) => {
+                    let (path, timed_out) = session.strongest_path_interruptible(
+                        NodeId(*start),
+                        NodeId(*end),
+                        deadline_reached(deadline),
+                    );
+                    if timed_out {
+                        return Ok(QueryResponse::not_found().with_timeout());
+                    }
+                    path
+                }
+                None => session.strongest_path(NodeId(*start), NodeId(*end)),
+            };
+            match path {
                 Some(path) => Ok(QueryResponse::with_path(path)),
                 None => {
                     let reason = if session.traverse(NodeId(*start), 0).is_none() {
@@ -299,9 +1145,19 @@ fn execute_query_inner(
             Ok(response)
         }
 
-        QueryRequest::Related { node_id, depth } => {
+        QueryRequest::Related { node_id, depth, .. } => {
             // Validate depth to prevent DoS
             validate_depth(*depth)?;
+            if deadline.is_some() {
+                let result = traverse_until(
+                    session,
+                    NodeId(*node_id),
+                    *depth,
+                    EdgeWeight::new(i64::MIN),
+                    deadline,
+                );
+                return Ok(deadline_response(result, None));
+            }
             // For Related queries, use compose which handles both backends
             match session.compose(NodeId(*node_id), *depth) {
                 Some(artifact) => Ok(QueryResponse::with_artifact(&artifact)),
@@ -328,6 +1184,168 @@ fn execute_query_inner(
     }
 }
 
+// =============================================================================
+// STREAMING QUERY HANDLER
+// =============================================================================
+
+/// Content type of `/query/stream` responses.
+pub const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// Edges buffered between the traversal and the client before it waits.
+const STREAM_CHANNEL_CAPACITY: usize = 64;
+
+/// How long a stream waits for the client to drain the channel.
+///
+/// The session read lock is held while waiting, and a queued writer blocks
+/// every later reader, so a client that stops reading loses its stream
+/// instead of stalling the server.
+const STREAM_SEND_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Run a traverse-family query, writing each edge as one NDJSON line.
+///
+/// Supports unpaged BFS `traverse` and `traverse_filtered` without `top_k`
+/// (ranking needs every edge first). Edges are sent as the BFS discovers them through
+/// a bounded channel, so neither side buffers the whole result; the session
+/// read lock is held until the traversal ends, the client disconnects or a
+/// send waits longer than [`STREAM_SEND_TIMEOUT`]. A traversal that fails
+/// part-way ends the stream with a single `{"error": ...}` line.
+pub async fn query_stream_handler(
+    State(state): State<AppState>,
+    Json(body): Json<QueryBody>,
+) -> Response {
+    let (start, depth, min_weight) = match body.request {
+        QueryRequest::Traverse {
+            node_id,
+            depth,
+            strategy: TraversalStrategy::Bfs,
+            limit: None,
+            offset: None,
+        } => (NodeId(node_id), depth, EdgeWeight::new(i64::MIN)),
+        QueryRequest::TraverseFiltered {
+            node_id,
+            depth,
+            min_weight,
+            top_k: None,
+        } => (NodeId(node_id), depth, EdgeWeight::new(min_weight)),
+        _ => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(QueryResponse::error(
+                    "Streaming supports unpaged BFS traverse and traverse_filtered without top_k",
+                )),
+            )
+                .into_response();
+        }
+    };
+    if let Err(e) = validate_depth(depth) {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(QueryResponse::error(format!("Query failed: {}", e))),
+        )
+            .into_response();
+    }
+
+    let session = state.session.clone().read_owned().await;
+    if session.traverse(start, 0).is_none() {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(QueryResponse::not_found().with_diagnostic("node_not_found")),
+        )
+            .into_response();
+    }
+
+    let (tx, rx) =
+        tokio::sync::mpsc::channel::<Result<Bytes, std::io::Error>>(STREAM_CHANNEL_CAPACITY);
+    let runtime = tokio::runtime::Handle::current();
+    tokio::task::spawn_blocking(move || {
+        // A closed channel means the client went away, a timeout that it
+        // stopped reading: either way, stop traversing and release the lock
+        let send = |line: Vec<u8>| {
+            runtime
+                .block_on(tx.send_timeout(Ok(Bytes::from(line)), STREAM_SEND_TIMEOUT))
+                .is_ok()
+        };
+        let result = session.traverse_streaming(start, depth, min_weight, |from, to, weight| {
+            let edge = EdgeJson {
+                from: from.0,
+                to: to.0,
+                weight: weight.value(),
+            };
+            send(ndjson_line(&edge))
+        });
+        if let Err(e) = result {
+            tracing::error!("Streaming traverse failed: {}", e);
+            send(ndjson_line(&serde_json::json!({
+                "error": format!("Query failed: {}", e)
+            })));
+        }
+    });
+
+    let stream = futures_util::stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|chunk| (chunk, rx))
+    });
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, NDJSON_CONTENT_TYPE)],
+        axum::body::Body::from_stream(stream),
+    )
+        .into_response()
+}
+
+/// Serialize `value` as one NDJSON line.
+fn ndjson_line<T: serde::Serialize>(value: &T) -> Vec<u8> {
+    let mut line = serde_json::to_vec(value).unwrap_or_default();
+    line.push(b'\n');
+    line
+}
+
+// =============================================================================
+// BATCH LOOKUP HANDLER
+// =============================================================================
+
+/// Maximum number of entity ids accepted by a single batch lookup.
+const MAX_LOOKUP_BATCH: usize = 10_000;
+
+/// Resolve many entity ids to node ids, preserving request order.
+pub async fn lookup_batch_handler(
+    State(state): State<AppState>,
+    Json(request): Json<BatchLookupRequest>,
+) -> impl IntoResponse {
+    if request.entity_ids.len() > MAX_LOOKUP_BATCH {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(BatchLookupResponse::error(format!(
+                "Batch size {} exceeds maximum {}",
+                request.entity_ids.len(),
+                MAX_LOOKUP_BATCH
+            ))),
+        );
+    }
+
+    let entities = match request
+        .entity_ids
+        .iter()
+        .map(|id| resolve_entity(*id, request.namespace))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(entities) => entities,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(BatchLookupResponse::error(e.to_string())),
+            );
+        }
+    };
+
+    let session = state.session.read().await;
+    let node_ids = entities
+        .into_iter()
+        .map(|entity| session.lookup_entity(entity).map(|n| n.0))
+        .collect();
+
+    (StatusCode::OK, Json(BatchLookupResponse::success(node_ids)))
+}
+
 // =============================================================================
 // HASH HANDLER
 // =============================================================================
@@ -359,6 +1377,24 @@ pub async fn hash_handler(State(state): State<AppState>) -> impl IntoResponse {
     )
 }
 
+/// Cheap graph fingerprint (canonical checksum) for sync verification.
+pub async fn fingerprint_handler(State(state): State<AppState>) -> impl IntoResponse {
+    let session = state.session.read().await;
+    match session.export_graph_snapshot() {
+        Ok(graph) => (
+            StatusCode::OK,
+            Json(FingerprintResponse::success(graph.canonical_hash())),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(FingerprintResponse::error(format!(
+                "Snapshot failed: {}",
+                e
+            ))),
+        ),
+    }
+}
+
 // =============================================================================
 // METRICS HANDLER
 // =============================================================================
@@ -375,6 +1411,7 @@ pub async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse
         Stage::S2 => 2u8,
         Stage::S3 => 3u8,
     };
+    let ingest_rate = state.ingest_rate.lock().await.rate();
     let body = format!(
         "# HELP kremis_node_count Total number of nodes in the graph\n\
          # TYPE kremis_node_count gauge\n\
@@ -393,13 +1430,17 @@ pub async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse
          kremis_stage {}\n\
          # HELP kremis_stage_progress_percent Progress toward next stage\n\
          # TYPE kremis_stage_progress_percent gauge\n\
-         kremis_stage_progress_percent {}\n",
+         kremis_stage_progress_percent {}\n\
+         # HELP kremis_ingest_rate Signals ingested during the last request window\n\
+         # TYPE kremis_ingest_rate gauge\n\
+         kremis_ingest_rate {}\n",
         metrics.node_count,
         metrics.edge_count,
         metrics.stable_edge_count,
         metrics.density_millionths,
         stage_num,
         progress.percent,
+        ingest_rate,
     );
     (
         StatusCode::OK,
@@ -453,3 +1494,76 @@ pub async fn export_handler(State(state): State<AppState>) -> impl IntoResponse
         ),
     }
 }
+
+/// Download the graph as raw canonical bytes, ready for `POST /import`.
+pub async fn snapshot_handler(State(state): State<AppState>) -> Response {
+    let session = state.session.read().await;
+    let exported = session
+        .export_graph_snapshot()
+        .and_then(|graph| export_canonical(&graph));
+
+    match exported {
+        Ok(data) => (
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, OCTET_STREAM_CONTENT_TYPE)],
+            data,
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ExportResponse::error(format!("Export failed: {}", e))),
+        )
+            .into_response(),
+    }
+}
+
+// =============================================================================
+// IMPORT HANDLER
+// =============================================================================
+
+/// Replace the served graph with a canonical snapshot.
+///
+/// Refused with 403 unless `KREMIS_API_KEY` is configured, since the auth
+/// middleware is the only thing standing between a client and a wiped
+/// graph. Persistent sessions answer 409, like `kremis import` for redb.
+pub async fn import_handler(State(state): State<AppState>, body: Bytes) -> impl IntoResponse {
+    if get_api_key_from_env().is_none() {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(ImportResponse::error(
+                "import requires KREMIS_API_KEY to be configured",
+            )),
+        );
+    }
+
+    let graph = match import_canonical(&body) {
+        Ok(graph) => graph,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ImportResponse::error(format!("Import failed: {}", e))),
+            );
+        }
+    };
+
+    let mut session = state.session.write().await;
+    if session.is_persistent() {
+        return (
+            StatusCode::CONFLICT,
+            Json(ImportResponse::error(
+                "import is not supported for the redb backend",
+            )),
+        );
+    }
+
+    *session = Session::with_graph(graph);
+    state.invalidate_query_cache().await;
+    state.provenance.lock().await.clear();
+    (
+        StatusCode::OK,
+        Json(ImportResponse::success(
+            session.node_count(),
+            session.edge_count(),
+        )),
+    )
+}
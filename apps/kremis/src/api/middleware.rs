@@ -7,6 +7,7 @@
 //! Rate limiting is configured via environment variable:
 //! - `KREMIS_RATE_LIMIT`: Requests per second (default: 100)
 
+use super::IngestRate;
 use axum::{
     body::Body,
     extract::State,
@@ -73,6 +74,20 @@ pub async fn rate_limit_middleware(
     }
 }
 
+// =============================================================================
+// INGEST CLOCK
+// =============================================================================
+
+/// Advance the ingest-rate clock once per handled request.
+pub async fn ingest_clock_middleware(
+    State(rate): State<Arc<tokio::sync::Mutex<IngestRate>>>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    rate.lock().await.tick();
+    next.run(request).await
+}
+
 // =============================================================================
 // TESTS
 // =============================================================================
@@ -4,13 +4,31 @@
 //!
 //! ## Endpoints
 //!
-//! - `POST /signal` - Ingest a new signal
-//! - `POST /query` - Execute a query
+//! - `POST /signal` - Ingest a new signal (honors `Idempotency-Key`)
+//! - `POST /signals` - Ingest a batch of signals (honors `Idempotency-Key`)
+//! - `POST /query` - Execute a query (JSON, or postcard with `Content-Type: application/x-postcard`)
+//! - `POST /query/stream` - Run a traversal, streaming edges as NDJSON
+//! - `POST /lookup/batch` - Resolve many entity ids to node ids
+//! - `PATCH /edge` - Adjust an edge weight by a signed delta
+//! - `PUT /edge` - Set an edge weight by entity ids (creates if missing)
+//! - `GET /edges` - List all edges by entity ids
+//! - `POST /edges` - List edges at or above a weight, paginated
+//! - `POST /edges/bulk` - Add edge weights by entity ids (creates if missing)
+//! - `PUT /edges/bulk` - Set edge weights by entity ids (creates if missing)
+//! - `GET /edge/provenance` - Sources that reinforced an edge
+//! - `GET /explain` - Explain how two nodes are connected
+//! - `GET /entity/{id}/top` - Heaviest outgoing relationships of an entity
 //! - `GET /status` - Get graph status
 //! - `GET /stage` - Get current developmental stage
+//! - `GET /summary` - Status, stage, and hub entities in one response
+//! - `GET /schema/attributes` - Ingested attributes with usage counts
 //! - `POST /export` - Export graph in canonical format
-//! - `GET /health` - Health check
+//! - `GET /export` - Download the canonical snapshot as raw bytes
+//! - `POST /import` - Replace the graph with a canonical snapshot (needs an API key)
+//! - `GET /health` - Health check (liveness)
+//! - `GET /ready` - Readiness, with initial load progress
 //! - `GET /hash` - BLAKE3 cryptographic hash of graph
+//! - `GET /fingerprint` - Canonical checksum of graph, for sync checks
 //! - `GET /metrics` - Prometheus metrics
 //!
 //! ## Security Configuration (Environment Variables)
@@ -30,24 +48,37 @@ pub use middleware::{create_rate_limiter, get_rate_limit_from_env};
 // Re-export handlers and types for integration tests (via `kremis::api::*`)
 #[allow(unused_imports)]
 pub use handlers::{
-    export_handler, hash_handler, health_handler, ingest_handler, metrics_handler, query_handler,
-    retract_handler, stage_handler, status_handler,
+    adjust_edge_handler, attribute_schema_handler, bulk_edges_handler, bulk_set_edges_handler,
+    edges_above_handler, explain_handler, export_handler, fingerprint_handler, hash_handler,
+    health_handler, import_handler, ingest_batch_handler, ingest_handler, list_edges_handler,
+    lookup_batch_handler, metrics_handler, provenance_handler, put_edge_handler, query_handler,
+    query_stream_handler, ready_handler, retract_handler, snapshot_handler, stage_handler,
+    status_handler, summary_handler, top_relationships_handler,
 };
 #[allow(unused_imports)]
 pub use types::{
-    EdgeJson, ExportResponse, HealthResponse, IngestRequest, IngestResponse, QueryRequest,
-    QueryResponse, RetractRequest, RetractResponse, StageResponse, StatusResponse,
+    AdjustEdgeRequest, AdjustEdgeResponse, AttributeCountJson, AttributeSchemaResponse,
+    BatchLookupRequest, BatchLookupResponse, BinaryQueryBody, BinaryQueryRequest,
+    BinaryQueryResponse, BulkEdgesResponse, EdgeJson, EdgeListResponse, EdgePageResponse,
+    EdgesAboveRequest, EntityEdgeJson, EntityWeightJson, ExplainQuery, ExportResponse,
+    FingerprintResponse, HealthResponse, HubJson, ImportResponse, IngestRequest, IngestResponse,
+    OCTET_STREAM_CONTENT_TYPE, POSTCARD_CONTENT_TYPE, PageJson, ProvenanceQuery,
+    ProvenanceResponse, PutEdgeRequest, QueryBody, QueryRequest, QueryResponse, ReadyResponse,
+    RetractRequest, RetractResponse, SourceCountJson, StageResponse, StatusResponse, SummaryQuery,
+    SummaryResponse, TopQuery, TopRelationshipsResponse, TraversalStrategy,
 };
 
 use axum::{
     Router,
     http::{HeaderValue, Method, header},
     middleware as axum_middleware,
-    routing::{get, post},
+    routing::{get, patch, post},
 };
-use kremis_core::{KremisError, Session};
+use kremis_core::{KremisError, LruCache, Session};
+use std::collections::BTreeMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use tokio::sync::{Mutex, RwLock};
 use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
 
@@ -55,11 +86,122 @@ use tower_http::trace::TraceLayer;
 // SERVER STATE
 // =============================================================================
 
+/// Maximum number of cached `/query` responses.
+pub const QUERY_CACHE_CAPACITY: usize = 256;
+
+/// Maximum number of remembered `Idempotency-Key` ingests.
+pub const IDEMPOTENCY_CACHE_CAPACITY: usize = 1024;
+
+/// Default request body limit for every endpoint except `POST /import`.
+pub const MAX_REQUEST_BODY_SIZE: usize = 2 * 1024 * 1024;
+
+/// Request body limit for `POST /import`, matching `kremis import`'s file cap.
+///
+/// A canonical snapshot at `MAX_IMPORT_NODE_COUNT` nodes and
+/// `MAX_IMPORT_EDGE_COUNT` edges encodes to at most about 320 MB before
+/// properties, so any graph the importer accepts fits.
+pub const MAX_IMPORT_BODY_SIZE: usize = 500 * 1024 * 1024;
+
 /// Shared server state containing the graph session.
 #[derive(Clone)]
 pub struct AppState {
     /// The session containing the graph.
     pub session: Arc<RwLock<Session>>,
+    /// Cached `/query` responses, cleared on every graph mutation.
+    pub query_cache: Arc<Mutex<LruCache<QueryRequest, QueryResponse>>>,
+    /// Responses of recent `/signal` and `/signals` ingests, keyed by
+    /// route and `Idempotency-Key`.
+    pub idempotency_cache: Arc<Mutex<LruCache<String, Vec<IngestResponse>>>>,
+    /// Per-edge provenance: (from_entity, to_entity) -> source -> reinforcements.
+    pub provenance: Arc<Mutex<EdgeProvenance>>,
+    /// Initial graph load progress, reported by `GET /ready`.
+    pub load: Arc<LoadProgress>,
+    /// Signals ingested per window of handled requests.
+    pub ingest_rate: Arc<Mutex<IngestRate>>,
+}
+
+/// Sources that reinforced each edge, keyed by entity ids.
+pub type EdgeProvenance = BTreeMap<(u64, u64), BTreeMap<String, u64>>;
+
+/// Progress of an initial graph load.
+///
+/// Idle (ready) by default. A loader reports `set_progress` while nodes
+/// arrive and `finish` once the session holds the whole graph.
+#[derive(Debug, Default)]
+pub struct LoadProgress {
+    loading: AtomicBool,
+    loaded_nodes: AtomicUsize,
+    total_nodes: AtomicUsize,
+}
+
+impl LoadProgress {
+    /// Mark a load as in progress; the server reports not ready.
+    pub fn set_progress(&self, loaded_nodes: usize, total_nodes: usize) {
+        self.loaded_nodes.store(loaded_nodes, Ordering::SeqCst);
+        self.total_nodes.store(total_nodes, Ordering::SeqCst);
+        self.loading.store(true, Ordering::SeqCst);
+    }
+
+    /// Mark the load as complete; the server reports ready.
+    pub fn finish(&self) {
+        self.loading.store(false, Ordering::SeqCst);
+    }
+
+    /// `(loaded_nodes, total_nodes)` while loading, `None` once ready.
+    #[must_use]
+    pub fn progress(&self) -> Option<(usize, usize)> {
+        self.loading.load(Ordering::SeqCst).then(|| {
+            (
+                self.loaded_nodes.load(Ordering::SeqCst),
+                self.total_nodes.load(Ordering::SeqCst),
+            )
+        })
+    }
+}
+
+/// Number of handled requests in one `IngestRate` window.
+pub const INGEST_RATE_WINDOW: u64 = 100;
+
+/// Signal ingest rate, clocked by the server's own request counter.
+///
+/// No wall clock is read: every handled request advances the clock by one,
+/// and the rate is the number of signals ingested during the last
+/// [`INGEST_RATE_WINDOW`] requests.
+#[derive(Debug, Default)]
+pub struct IngestRate {
+    /// Requests handled so far.
+    clock: u64,
+    /// (request tick, signals ingested), oldest first.
+    events: std::collections::VecDeque<(u64, u64)>,
+}
+
+impl IngestRate {
+    /// Advance the clock by one handled request, dropping expired events.
+    pub fn tick(&mut self) {
+        self.clock = self.clock.saturating_add(1);
+        while self
+            .events
+            .front()
+            .is_some_and(|(at, _)| at.saturating_add(INGEST_RATE_WINDOW) <= self.clock)
+        {
+            self.events.pop_front();
+        }
+    }
+
+    /// Record `signals` ingested at the current tick.
+    pub fn record(&mut self, signals: u64) {
+        if signals > 0 {
+            self.events.push_back((self.clock, signals));
+        }
+    }
+
+    /// Signals ingested during the current window.
+    #[must_use]
+    pub fn rate(&self) -> u64 {
+        self.events
+            .iter()
+            .fold(0u64, |total, (_, n)| total.saturating_add(*n))
+    }
 }
 
 impl AppState {
@@ -68,8 +210,21 @@ impl AppState {
     pub fn new(session: Session) -> Self {
         Self {
             session: Arc::new(RwLock::new(session)),
+            query_cache: Arc::new(Mutex::new(LruCache::new(QUERY_CACHE_CAPACITY))),
+            idempotency_cache: Arc::new(Mutex::new(LruCache::new(IDEMPOTENCY_CACHE_CAPACITY))),
+            provenance: Arc::new(Mutex::new(BTreeMap::new())),
+            load: Arc::new(LoadProgress::default()),
+            ingest_rate: Arc::new(Mutex::new(IngestRate::default())),
         }
     }
+
+    /// Drop all cached query responses.
+    ///
+    /// Call while still holding the session write lock so no reader can
+    /// cache a result computed against the pre-mutation graph.
+    pub async fn invalidate_query_cache(&self) {
+        self.query_cache.lock().await.clear();
+    }
 }
 
 // =============================================================================
@@ -126,8 +281,18 @@ fn build_cors_layer() -> CorsLayer {
             } else {
                 CorsLayer::new()
                     .allow_origin(allowed_origins)
-                    .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
-                    .allow_headers([header::CONTENT_TYPE, header::AUTHORIZATION])
+                    .allow_methods([
+                        Method::GET,
+                        Method::POST,
+                        Method::PATCH,
+                        Method::PUT,
+                        Method::OPTIONS,
+                    ])
+                    .allow_headers([
+                        header::CONTENT_TYPE,
+                        header::AUTHORIZATION,
+                        handlers::IDEMPOTENCY_KEY_HEADER,
+                    ])
             }
         }
         None => {
@@ -150,8 +315,18 @@ fn build_localhost_cors() -> CorsLayer {
 
     CorsLayer::new()
         .allow_origin(origins)
-        .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
-        .allow_headers([header::CONTENT_TYPE, header::AUTHORIZATION])
+        .allow_methods([
+            Method::GET,
+            Method::POST,
+            Method::PATCH,
+            Method::PUT,
+            Method::OPTIONS,
+        ])
+        .allow_headers([
+            header::CONTENT_TYPE,
+            header::AUTHORIZATION,
+            handlers::IDEMPOTENCY_KEY_HEADER,
+        ])
 }
 
 // =============================================================================
@@ -165,6 +340,7 @@ fn build_localhost_cors() -> CorsLayer {
 /// 2. Tracing - logs all requests
 /// 3. Rate Limiting - protects against DoS (if enabled)
 /// 4. Authentication - validates API key (if configured)
+/// 5. Ingest clock - advances the `IngestRate` request clock
 pub fn create_router(state: AppState) -> Router {
     let cors = build_cors_layer();
 
@@ -193,13 +369,46 @@ pub fn create_router(state: AppState) -> Router {
     // Build base router with routes
     let mut router = Router::new()
         .route("/health", get(handlers::health_handler))
+        .route("/ready", get(handlers::ready_handler))
         .route("/status", get(handlers::status_handler))
         .route("/stage", get(handlers::stage_handler))
+        .route("/summary", get(handlers::summary_handler))
+        .route(
+            "/schema/attributes",
+            get(handlers::attribute_schema_handler),
+        )
         .route("/signal", post(handlers::ingest_handler))
+        .route("/signals", post(handlers::ingest_batch_handler))
+        .route("/edge/provenance", get(handlers::provenance_handler))
+        .route("/explain", get(handlers::explain_handler))
+        .route("/entity/{id}/top", get(handlers::top_relationships_handler))
         .route("/signal/retract", post(handlers::retract_handler))
+        .route(
+            "/edge",
+            patch(handlers::adjust_edge_handler).put(handlers::put_edge_handler),
+        )
+        .route(
+            "/edges",
+            get(handlers::list_edges_handler).post(handlers::edges_above_handler),
+        )
+        .route(
+            "/edges/bulk",
+            post(handlers::bulk_edges_handler).put(handlers::bulk_set_edges_handler),
+        )
         .route("/query", post(handlers::query_handler))
-        .route("/export", post(handlers::export_handler))
+        .route("/query/stream", post(handlers::query_stream_handler))
+        .route("/lookup/batch", post(handlers::lookup_batch_handler))
+        .route(
+            "/export",
+            get(handlers::snapshot_handler).post(handlers::export_handler),
+        )
+        .route(
+            "/import",
+            post(handlers::import_handler)
+                .layer(axum::extract::DefaultBodyLimit::max(MAX_IMPORT_BODY_SIZE)),
+        )
         .route("/hash", get(handlers::hash_handler))
+        .route("/fingerprint", get(handlers::fingerprint_handler))
         .route("/metrics", get(handlers::metrics_handler));
 
     // Apply authentication middleware (innermost - runs last on request)
@@ -215,9 +424,15 @@ pub fn create_router(state: AppState) -> Router {
         ));
     }
 
+    // Advance the ingest-rate clock on every handled request
+    router = router.layer(axum_middleware::from_fn_with_state(
+        state.ingest_rate.clone(),
+        middleware::ingest_clock_middleware,
+    ));
+
     // Apply CORS, body limit, and tracing (outermost layers)
     router
-        .layer(axum::extract::DefaultBodyLimit::max(2 * 1024 * 1024))
+        .layer(axum::extract::DefaultBodyLimit::max(MAX_REQUEST_BODY_SIZE))
         .layer(cors)
         .layer(TraceLayer::new_for_http())
         .with_state(state)
@@ -227,15 +442,40 @@ pub fn create_router(state: AppState) -> Router {
 // SERVER STARTUP
 // =============================================================================
 
-/// Start the HTTP server.
-pub async fn run_server(addr: &str, session: Session) -> Result<(), KremisError> {
-    let state = AppState::new(session);
-    let router = create_router(state);
-
+/// Start the HTTP server, loading the session in the background.
+///
+/// Binds `addr` and hands over to [`serve`].
+pub async fn run_server<F>(addr: &str, load: F) -> Result<(), KremisError>
+where
+    F: FnOnce(Arc<LoadProgress>) -> Result<Session, KremisError> + Send + 'static,
+{
     let listener = tokio::net::TcpListener::bind(addr)
         .await
         .map_err(|e| KremisError::IoError(format!("Bind failed: {}", e)))?;
+    serve(listener, load).await
+}
 
+/// Serve on a bound listener, loading the session in the background.
+///
+/// The listener accepts connections before `load` runs, so `/health` answers
+/// at once and `/ready` reports not ready until the graph is in place.
+/// `load` receives the server's [`LoadProgress`] and reports into it while
+/// nodes are inserted. Every other endpoint waits on the session lock,
+/// which is held for the whole load.
+pub async fn serve<F>(listener: tokio::net::TcpListener, load: F) -> Result<(), KremisError>
+where
+    F: FnOnce(Arc<LoadProgress>) -> Result<Session, KremisError> + Send + 'static,
+{
+    let state = AppState::new(Session::new());
+    let mut session = Arc::clone(&state.session).write_owned().await;
+    state.load.set_progress(0, 0);
+    let progress = Arc::clone(&state.load);
+    let router = create_router(state);
+
+    let addr = listener
+        .local_addr()
+        .map(|a| a.to_string())
+        .unwrap_or_default();
     tracing::info!(
         event = "server_start",
         addr = addr,
@@ -243,7 +483,21 @@ pub async fn run_server(addr: &str, session: Session) -> Result<(), KremisError>
         addr
     );
 
-    axum::serve(listener, router)
-        .await
-        .map_err(|e| KremisError::IoError(format!("Server error: {}", e)))
+    let serve = async {
+        axum::serve(listener, router)
+            .await
+            .map_err(|e| KremisError::IoError(format!("Server error: {}", e)))
+    };
+    let load = async move {
+        let reporter = Arc::clone(&progress);
+        let loaded = tokio::task::spawn_blocking(move || load(reporter))
+            .await
+            .map_err(|e| KremisError::IoError(format!("Load task failed: {}", e)))??;
+        let nodes = loaded.node_count();
+        *session = loaded;
+        progress.finish();
+        tracing::info!(event = "server_ready", nodes = nodes, "Graph loaded");
+        Ok(())
+    };
+    tokio::try_join!(serve, load).map(|_| ())
 }
@@ -3,10 +3,12 @@
 //! This module defines the JSON structures for the HTTP API.
 
 use kremis_core::{
-    Artifact, Attribute, EntityId, KremisError, NodeId, Signal, Value,
-    primitives::{MAX_ATTRIBUTE_LENGTH, MAX_VALUE_LENGTH},
+    Artifact, Attribute, EdgeWeight, EntityId, KremisError, NodeId, Signal, Value,
+    confidence::stability_confidence,
+    primitives::{ENTITY_ID_MASK, MAX_ATTRIBUTE_LENGTH, MAX_VALUE_LENGTH},
 };
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 // =============================================================================
 // HEALTH RESPONSE
@@ -28,6 +30,14 @@ impl Default for HealthResponse {
     }
 }
 
+/// Readiness response. `loaded_nodes` trails `total_nodes` while loading.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadyResponse {
+    pub ready: bool,
+    pub loaded_nodes: usize,
+    pub total_nodes: usize,
+}
+
 // =============================================================================
 // STATUS RESPONSE
 // =============================================================================
@@ -39,6 +49,9 @@ pub struct StatusResponse {
     pub edge_count: usize,
     pub stable_edges: usize,
     pub density_millionths: u64,
+    /// Signals ingested during the last `INGEST_RATE_WINDOW` requests.
+    #[serde(default)]
+    pub ingest_rate: u64,
 }
 
 // =============================================================================
@@ -55,16 +68,72 @@ pub struct StageResponse {
     pub stable_edges_current: usize,
 }
 
+// =============================================================================
+// SUMMARY RESPONSE
+// =============================================================================
+
+/// Query parameters for `GET /summary`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SummaryQuery {
+    /// Number of hubs to return (default: 10).
+    pub top_k: Option<usize>,
+}
+
+/// An entity with its degree (incoming + outgoing edges).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HubJson {
+    pub entity_id: u64,
+    pub degree: usize,
+}
+
+/// Status, stage, and hubs in one response, for dashboards.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SummaryResponse {
+    pub status: StatusResponse,
+    pub stage: StageResponse,
+    /// Highest-degree entities, most connected first.
+    pub hubs: Vec<HubJson>,
+}
+
+// =============================================================================
+// ATTRIBUTE SCHEMA RESPONSE
+// =============================================================================
+
+/// An attribute with the number of entities that carry it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttributeCountJson {
+    pub attribute: String,
+    pub count: usize,
+}
+
+/// Response from `GET /schema/attributes`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttributeSchemaResponse {
+    /// Attributes, most common first (ties by name).
+    pub attributes: Vec<AttributeCountJson>,
+}
+
 // =============================================================================
 // INGEST REQUEST/RESPONSE
 // =============================================================================
 
 /// Signal ingest request.
+///
+/// Also accepts the field names `id`, `attr` and `val`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IngestRequest {
+    #[serde(alias = "id")]
     pub entity_id: u64,
+    #[serde(alias = "attr")]
     pub attribute: String,
+    #[serde(alias = "val")]
     pub value: String,
+    /// Optional source namespace folded into the entity id.
+    #[serde(default)]
+    pub namespace: Option<u16>,
+    /// Optional producer of the signal, kept for auditing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
 }
 
 impl IngestRequest {
@@ -103,11 +172,18 @@ impl IngestRequest {
             )));
         }
 
-        Ok(Signal::new(
-            EntityId(self.entity_id),
-            Attribute::new(&self.attribute),
-            Value::new(&self.value),
-        ))
+        let entity = EntityId(self.entity_id);
+        let attribute = Attribute::new(&self.attribute);
+        let value = Value::new(&self.value);
+        let signal = match self.namespace {
+            Some(namespace) => Signal::with_namespace(namespace, entity, attribute, value)
+                .ok_or_else(|| namespace_range_error(self.entity_id))?,
+            None => Signal::new(entity, attribute, value),
+        };
+        Ok(match &self.source {
+            Some(source) => signal.with_source(source.clone()),
+            None => signal,
+        })
     }
 }
 
@@ -117,6 +193,9 @@ pub struct IngestResponse {
     pub success: bool,
     pub node_id: Option<u64>,
     pub error: Option<String>,
+    /// How established the node's strongest edge is (0-100), if it has any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stability_confidence: Option<u8>,
 }
 
 impl IngestResponse {
@@ -125,6 +204,7 @@ impl IngestResponse {
             success: true,
             node_id: Some(node_id.0),
             error: None,
+            stability_confidence: None,
         }
     }
 
@@ -133,8 +213,16 @@ impl IngestResponse {
             success: false,
             node_id: None,
             error: Some(msg.into()),
+            stability_confidence: None,
         }
     }
+
+    /// Attach the stability confidence of the node's strongest edge.
+    #[must_use]
+    pub fn with_stability(mut self, weight: Option<EdgeWeight>) -> Self {
+        self.stability_confidence = weight.map(stability_confidence);
+        self
+    }
 }
 
 // =============================================================================
@@ -146,6 +234,9 @@ impl IngestResponse {
 pub struct RetractRequest {
     pub from_entity: u64,
     pub to_entity: u64,
+    /// Optional source namespace applied to both entity ids.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<u16>,
 }
 
 /// Signal retraction response.
@@ -174,20 +265,359 @@ impl RetractResponse {
     }
 }
 
+// =============================================================================
+// EDGE ADJUST REQUEST/RESPONSE
+// =============================================================================
+
+/// Partial edge update — add `delta` (possibly negative) to an edge's weight.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdjustEdgeRequest {
+    pub from_entity: u64,
+    pub to_entity: u64,
+    pub delta: i64,
+    /// Optional source namespace applied to both entity ids.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<u16>,
+    /// Producer of a reinforcement (positive delta), recorded as provenance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+/// Edge adjustment response.
+///
+/// `removed` is true when the weight reached zero and the edge was deleted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdjustEdgeResponse {
+    pub success: bool,
+    pub new_weight: Option<i64>,
+    pub removed: bool,
+    pub error: Option<String>,
+}
+
+impl AdjustEdgeResponse {
+    pub fn success(new_weight: i64) -> Self {
+        Self {
+            success: true,
+            new_weight: Some(new_weight),
+            removed: new_weight == 0,
+            error: None,
+        }
+    }
+
+    pub fn error(msg: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            new_weight: None,
+            removed: false,
+            error: Some(msg.into()),
+        }
+    }
+}
+
+// =============================================================================
+// EDGE PROVENANCE
+// =============================================================================
+
+/// Query parameters for `GET /edge/provenance`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvenanceQuery {
+    pub from_entity: u64,
+    pub to_entity: u64,
+    /// Optional source namespace applied to both entity ids.
+    #[serde(default)]
+    pub namespace: Option<u16>,
+}
+
+/// One source that reinforced an edge, with how often it did.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceCountJson {
+    pub source: String,
+    pub reinforcements: u64,
+}
+
+/// Edge provenance response. Sources are sorted by name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvenanceResponse {
+    pub success: bool,
+    pub sources: Vec<SourceCountJson>,
+    pub error: Option<String>,
+}
+
+impl ProvenanceResponse {
+    pub fn success(sources: Vec<SourceCountJson>) -> Self {
+        Self {
+            success: true,
+            sources,
+            error: None,
+        }
+    }
+
+    pub fn error(msg: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            sources: vec![],
+            error: Some(msg.into()),
+        }
+    }
+}
+
+// =============================================================================
+// EXPLAIN
+// =============================================================================
+
+/// Query parameters for `GET /explain` (node ids).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExplainQuery {
+    pub start: u64,
+    pub end: u64,
+}
+
+// =============================================================================
+// FINGERPRINT
+// =============================================================================
+
+/// Graph fingerprint response (the canonical checksum).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FingerprintResponse {
+    pub success: bool,
+    pub fingerprint: u64,
+    pub error: Option<String>,
+}
+
+impl FingerprintResponse {
+    pub fn success(fingerprint: u64) -> Self {
+        Self {
+            success: true,
+            fingerprint,
+            error: None,
+        }
+    }
+
+    pub fn error(msg: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            fingerprint: 0,
+            error: Some(msg.into()),
+        }
+    }
+}
+
+// =============================================================================
+// TOP RELATIONSHIPS
+// =============================================================================
+
+/// Default number of neighbors returned by `GET /entity/{id}/top`.
+pub const DEFAULT_TOP_K: usize = 10;
+
+/// Query parameters for `GET /entity/{id}/top`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopQuery {
+    /// Number of neighbors to return (default: 10).
+    pub k: Option<usize>,
+    /// Optional source namespace of the path entity id.
+    #[serde(default)]
+    pub namespace: Option<u16>,
+}
+
+/// A neighbor entity with the weight of the edge leading to it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntityWeightJson {
+    pub entity_id: u64,
+    pub weight: i64,
+}
+
+/// Top relationships response, heaviest first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopRelationshipsResponse {
+    pub success: bool,
+    pub neighbors: Vec<EntityWeightJson>,
+    pub error: Option<String>,
+}
+
+impl TopRelationshipsResponse {
+    pub fn success(neighbors: Vec<EntityWeightJson>) -> Self {
+        Self {
+            success: true,
+            neighbors,
+            error: None,
+        }
+    }
+
+    pub fn error(msg: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            neighbors: vec![],
+            error: Some(msg.into()),
+        }
+    }
+}
+
+// =============================================================================
+// ENTITY EDGE LISTING
+// =============================================================================
+
+/// An edge addressed by entity ids (stable across databases, unlike NodeIds).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntityEdgeJson {
+    pub from_entity: u64,
+    pub to_entity: u64,
+    pub weight: i64,
+}
+
+/// `PUT /edge` request: an entity edge, optionally in a source namespace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PutEdgeRequest {
+    pub from_entity: u64,
+    pub to_entity: u64,
+    pub weight: i64,
+    /// Optional source namespace applied to both entity ids.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<u16>,
+}
+
+/// Response listing every edge by entity id.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EdgeListResponse {
+    pub success: bool,
+    pub edges: Vec<EntityEdgeJson>,
+    pub error: Option<String>,
+}
+
+impl EdgeListResponse {
+    pub fn success(edges: Vec<EntityEdgeJson>) -> Self {
+        Self {
+            success: true,
+            edges,
+            error: None,
+        }
+    }
+
+    pub fn error(msg: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            edges: Vec::new(),
+            error: Some(msg.into()),
+        }
+    }
+}
+
+/// Response to `POST /edges/bulk`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkEdgesResponse {
+    pub success: bool,
+    /// Edges added or reinforced (non-positive weights are skipped).
+    pub applied: usize,
+    pub error: Option<String>,
+}
+
+impl BulkEdgesResponse {
+    pub fn success(applied: usize) -> Self {
+        Self {
+            success: true,
+            applied,
+            error: None,
+        }
+    }
+
+    pub fn error(msg: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            applied: 0,
+            error: Some(msg.into()),
+        }
+    }
+}
+
+/// Default page size of `POST /edges`.
+pub const DEFAULT_EDGE_PAGE: usize = 1000;
+
+/// Largest page size accepted by `POST /edges`.
+pub const MAX_EDGE_PAGE: usize = 10_000;
+
+/// Request for `POST /edges`: edges with weight >= `min_weight`, paginated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EdgesAboveRequest {
+    pub min_weight: i64,
+    /// Number of matching edges to skip (default: 0).
+    #[serde(default)]
+    pub offset: usize,
+    /// Page size (default: 1000, capped at 10000).
+    pub limit: Option<usize>,
+}
+
+/// One page of edges by entity id.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EdgePageResponse {
+    pub success: bool,
+    pub edges: Vec<EntityEdgeJson>,
+    /// Number of matching edges across all pages.
+    pub total: usize,
+    /// Offset of the next page, if any edges remain.
+    pub next_offset: Option<usize>,
+    pub error: Option<String>,
+}
+
+impl EdgePageResponse {
+    pub fn success(edges: Vec<EntityEdgeJson>, total: usize, next_offset: Option<usize>) -> Self {
+        Self {
+            success: true,
+            edges,
+            total,
+            next_offset,
+            error: None,
+        }
+    }
+
+    pub fn error(msg: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            edges: Vec::new(),
+            total: 0,
+            next_offset: None,
+            error: Some(msg.into()),
+        }
+    }
+}
+
 // =============================================================================
 // QUERY REQUEST/RESPONSE
 // =============================================================================
 
+/// Traversal order for `Traverse` queries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TraversalStrategy {
+    /// Breadth-first (level by level).
+    #[default]
+    Bfs,
+    /// Depth-first (follow each branch to the depth limit).
+    Dfs,
+}
+
 /// Query request (tagged union).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Ordered so identical requests can key the server-side query cache.
+/// `Traverse` and `Related` accept optional `limit`/`offset` paging over the
+/// result path; see [`QueryResponse::paginate`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum QueryRequest {
     Lookup {
         entity_id: u64,
+        #[serde(default)]
+        namespace: Option<u16>,
     },
     Traverse {
         node_id: u64,
         depth: usize,
+        #[serde(default)]
+        strategy: TraversalStrategy,
+        /// Maximum `path` entries to return; a limit of 0 is rejected.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit: Option<usize>,
+        /// `path` entries to skip before the page starts.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        offset: Option<usize>,
     },
     TraverseFiltered {
         node_id: u64,
@@ -206,14 +636,51 @@ pub enum QueryRequest {
     Related {
         node_id: u64,
         depth: usize,
+        /// Maximum `path` entries to return; a limit of 0 is rejected.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit: Option<usize>,
+        /// `path` entries to skip before the page starts.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        offset: Option<usize>,
     },
     Properties {
         node_id: u64,
     },
 }
 
+/// Resolve a raw entity id, folding in its namespace when present.
+///
+/// Fails if a namespaced raw id does not fit in `ENTITY_ID_MASK`.
+pub fn resolve_entity(entity_id: u64, namespace: Option<u16>) -> Result<EntityId, KremisError> {
+    match namespace {
+        Some(namespace) => EntityId::checked_namespaced(namespace, entity_id)
+            .ok_or_else(|| namespace_range_error(entity_id)),
+        None => Ok(EntityId(entity_id)),
+    }
+}
+
+/// Resolve both ends of an entity edge in the same namespace.
+pub fn resolve_edge(
+    from_entity: u64,
+    to_entity: u64,
+    namespace: Option<u16>,
+) -> Result<(EntityId, EntityId), KremisError> {
+    Ok((
+        resolve_entity(from_entity, namespace)?,
+        resolve_entity(to_entity, namespace)?,
+    ))
+}
+
+/// Error for a raw entity id too large to carry a namespace.
+fn namespace_range_error(entity_id: u64) -> KremisError {
+    KremisError::ValidationError(format!(
+        "Entity id {} exceeds maximum namespaced id {}",
+        entity_id, ENTITY_ID_MASK
+    ))
+}
+
 /// Property JSON representation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PropertyJson {
     pub attribute: String,
     pub value: String,
@@ -223,6 +690,27 @@ fn default_grounding() -> String {
     "unknown".to_string()
 }
 
+/// `/query` request body: a query plus an optional deadline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryBody {
+    #[serde(flatten)]
+    pub request: QueryRequest,
+    /// Milliseconds the server may spend on a traversal before returning
+    /// a partial result with `timed_out: true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub deadline_ms: Option<u64>,
+}
+
+/// Where a paged `traverse`/`related` response sits in the full result.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageJson {
+    /// Number of `path` entries across all pages.
+    pub total: usize,
+    /// Offset of the next page, if any entries remain.
+    pub next_offset: Option<usize>,
+}
+
 /// Query response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryResponse {
@@ -239,6 +727,14 @@ pub struct QueryResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub diagnostic: Option<String>,
+    /// The deadline elapsed and `path`/`edges` are partial.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    #[serde(default)]
+    pub timed_out: bool,
+    /// Paging position, present only when the request set `limit`/`offset`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub page: Option<PageJson>,
 }
 
 impl QueryResponse {
@@ -252,6 +748,8 @@ impl QueryResponse {
             grounding: "unknown".to_string(),
             error: None,
             diagnostic: None,
+            timed_out: false,
+            page: None,
         }
     }
 
@@ -265,6 +763,8 @@ impl QueryResponse {
             grounding: "unknown".to_string(),
             error: None,
             diagnostic: None,
+            timed_out: false,
+            page: None,
         }
     }
 
@@ -292,6 +792,8 @@ impl QueryResponse {
             grounding: "unknown".to_string(),
             error: None,
             diagnostic: None,
+            timed_out: false,
+            page: None,
         }
     }
 
@@ -305,6 +807,8 @@ impl QueryResponse {
             properties,
             error: None,
             diagnostic: None,
+            timed_out: false,
+            page: None,
         }
     }
 
@@ -318,6 +822,8 @@ impl QueryResponse {
             grounding: "unknown".to_string(),
             error: Some(msg.into()),
             diagnostic: None,
+            timed_out: false,
+            page: None,
         }
     }
 
@@ -325,20 +831,239 @@ impl QueryResponse {
         self.diagnostic = Some(reason.to_string());
         self
     }
+
+    /// Mark the response as a partial result cut short by the deadline.
+    pub fn with_timeout(mut self) -> Self {
+        self.timed_out = true;
+        self.with_diagnostic("deadline_exceeded")
+    }
+
+    /// Cut `path` down to one page, keeping the edges that leave it.
+    ///
+    /// Every edge of a traversal starts at a path node, so the pages of one
+    /// result partition its edges. No-op when both `offset` and `limit` are
+    /// `None`. Callers reject a `limit` of 0 first; an empty page could
+    /// never advance `next_offset`.
+    pub fn paginate(mut self, offset: Option<usize>, limit: Option<usize>) -> Self {
+        if offset.is_none() && limit.is_none() {
+            return self;
+        }
+        let total = self.path.len();
+        let start = offset.unwrap_or(0).min(total);
+        let end = limit.map_or(total, |limit| start.saturating_add(limit).min(total));
+        self.path = self.path.drain(start..end).collect();
+        let kept: BTreeSet<u64> = self.path.iter().copied().collect();
+        self.edges.retain(|edge| kept.contains(&edge.from));
+        self.page = Some(PageJson {
+            total,
+            next_offset: (end < total).then_some(end),
+        });
+        self
+    }
+
+    /// Sort `edges` by `(from, to)` for stable display.
+    ///
+    /// View-only convenience for clients: the server never calls it, and
+    /// the original order (the order the traversal found the edges) is lost.
+    #[allow(dead_code)] // Client-side helper, unused by the binary.
+    pub fn sort_edges(&mut self) {
+        self.edges.sort_by_key(|edge| (edge.from, edge.to));
+    }
 }
 
 /// Edge JSON representation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EdgeJson {
     pub from: u64,
     pub to: u64,
     pub weight: i64,
 }
 
+// =============================================================================
+// BINARY QUERY PROTOCOL
+// =============================================================================
+
+/// Content type selecting the postcard-encoded `/query` protocol.
+pub const POSTCARD_CONTENT_TYPE: &str = "application/x-postcard";
+
+/// [`QueryRequest`] for the binary protocol.
+///
+/// Postcard is not self-describing, so the JSON `type` tag becomes an
+/// externally tagged enum and every field is always present. Variant and
+/// field order are part of the wire format. Paging (`limit`/`offset`) is
+/// JSON-only.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BinaryQueryRequest {
+    Lookup {
+        entity_id: u64,
+        namespace: Option<u16>,
+    },
+    Traverse {
+        node_id: u64,
+        depth: usize,
+        strategy: TraversalStrategy,
+    },
+    TraverseFiltered {
+        node_id: u64,
+        depth: usize,
+        min_weight: i64,
+        top_k: Option<usize>,
+    },
+    StrongestPath {
+        start: u64,
+        end: u64,
+    },
+    Intersect {
+        nodes: Vec<u64>,
+    },
+    Related {
+        node_id: u64,
+        depth: usize,
+    },
+    Properties {
+        node_id: u64,
+    },
+}
+
+impl From<BinaryQueryRequest> for QueryRequest {
+    fn from(request: BinaryQueryRequest) -> Self {
+        match request {
+            BinaryQueryRequest::Lookup {
+                entity_id,
+                namespace,
+            } => Self::Lookup {
+                entity_id,
+                namespace,
+            },
+            BinaryQueryRequest::Traverse {
+                node_id,
+                depth,
+                strategy,
+            } => Self::Traverse {
+                node_id,
+                depth,
+                strategy,
+                limit: None,
+                offset: None,
+            },
+            BinaryQueryRequest::TraverseFiltered {
+                node_id,
+                depth,
+                min_weight,
+                top_k,
+            } => Self::TraverseFiltered {
+                node_id,
+                depth,
+                min_weight,
+                top_k,
+            },
+            BinaryQueryRequest::StrongestPath { start, end } => Self::StrongestPath { start, end },
+            BinaryQueryRequest::Intersect { nodes } => Self::Intersect { nodes },
+            BinaryQueryRequest::Related { node_id, depth } => Self::Related {
+                node_id,
+                depth,
+                limit: None,
+                offset: None,
+            },
+            BinaryQueryRequest::Properties { node_id } => Self::Properties { node_id },
+        }
+    }
+}
+
+/// [`QueryBody`] for the binary protocol.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BinaryQueryBody {
+    pub request: BinaryQueryRequest,
+    pub deadline_ms: Option<u64>,
+}
+
+impl From<BinaryQueryBody> for QueryBody {
+    fn from(body: BinaryQueryBody) -> Self {
+        Self {
+            request: body.request.into(),
+            deadline_ms: body.deadline_ms,
+        }
+    }
+}
+
+/// [`QueryResponse`] for the binary protocol, with every field always present.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BinaryQueryResponse {
+    pub success: bool,
+    pub found: bool,
+    pub path: Vec<u64>,
+    pub edges: Vec<EdgeJson>,
+    pub properties: Vec<PropertyJson>,
+    pub grounding: String,
+    pub error: Option<String>,
+    pub diagnostic: Option<String>,
+    pub timed_out: bool,
+}
+
+impl From<QueryResponse> for BinaryQueryResponse {
+    fn from(response: QueryResponse) -> Self {
+        Self {
+            success: response.success,
+            found: response.found,
+            path: response.path,
+            edges: response.edges,
+            properties: response.properties,
+            grounding: response.grounding,
+            error: response.error,
+            diagnostic: response.diagnostic,
+            timed_out: response.timed_out,
+        }
+    }
+}
+
+// =============================================================================
+// BATCH LOOKUP REQUEST/RESPONSE
+// =============================================================================
+
+/// Batch lookup request — resolves many entity ids in one call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchLookupRequest {
+    pub entity_ids: Vec<u64>,
+    /// Optional source namespace applied to every id.
+    #[serde(default)]
+    pub namespace: Option<u16>,
+}
+
+/// Batch lookup response.
+///
+/// `node_ids[i]` is the node for `entity_ids[i]`, or `null` if unknown.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchLookupResponse {
+    pub success: bool,
+    pub node_ids: Vec<Option<u64>>,
+    pub error: Option<String>,
+}
+
+impl BatchLookupResponse {
+    pub fn success(node_ids: Vec<Option<u64>>) -> Self {
+        Self {
+            success: true,
+            node_ids,
+            error: None,
+        }
+    }
+
+    pub fn error(msg: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            node_ids: vec![],
+            error: Some(msg.into()),
+        }
+    }
+}
+
 // =============================================================================
 // EXPORT RESPONSE
 // =============================================================================
 
+/// Content type of raw canonical snapshot bytes (`GET /export`, `POST /import`).
+pub const OCTET_STREAM_CONTENT_TYPE: &str = "application/octet-stream";
+
 /// Export response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExportResponse {
@@ -370,3 +1095,36 @@ impl ExportResponse {
         }
     }
 }
+
+// =============================================================================
+// IMPORT RESPONSE
+// =============================================================================
+
+/// Response to `POST /import`, with the size of the graph now served.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportResponse {
+    pub success: bool,
+    pub node_count: Option<usize>,
+    pub edge_count: Option<usize>,
+    pub error: Option<String>,
+}
+
+impl ImportResponse {
+    pub fn success(node_count: usize, edge_count: usize) -> Self {
+        Self {
+            success: true,
+            node_count: Some(node_count),
+            edge_count: Some(edge_count),
+            error: None,
+        }
+    }
+
+    pub fn error(msg: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            node_count: None,
+            edge_count: None,
+            error: Some(msg.into()),
+        }
+    }
+}
@@ -4,10 +4,13 @@
 
 use crate::api;
 use kremis_core::{
-    Graph, KremisError, NodeId, Session,
-    export::{canonical_checksum, export_canonical, import_canonical},
+    EdgeWeight, Graph, GraphStore, KremisError, NodeId, Session,
+    export::{
+        canonical_checksum, export_canonical, export_msgpack, export_stable_canonical,
+        export_with_manifest, import_canonical, import_canonical_with_progress, import_msgpack,
+    },
     primitives::MAX_SEQUENCE_LENGTH,
-    system::{GraphMetrics, StageAssessor},
+    system::{GraphMetrics, STABLE_THRESHOLD, StageAssessor},
 };
 use std::path::PathBuf;
 
@@ -111,8 +114,6 @@ pub async fn cmd_server(
     host: &str,
     port: u16,
 ) -> Result<(), KremisError> {
-    let session = load_or_create_session(db_path, backend)?;
-
     println!("Kremis Honest AGI Server Starting...");
     println!();
     println!("Configuration:");
@@ -128,12 +129,19 @@ pub async fn cmd_server(
     println!("  GET  /stage  - Get developmental stage");
     println!("  POST /export - Export graph");
     println!("  GET  /health - Health check");
+    println!("  GET  /ready  - Readiness (graph loaded)");
     println!();
     println!("Press Ctrl+C to stop");
     println!();
 
     let addr = format!("{}:{}", host, port);
-    api::run_server(&addr, session).await
+    let (db_path, backend) = (db_path.clone(), backend.to_string());
+    api::run_server(&addr, move |progress| {
+        load_or_create_session_with_progress(&db_path, &backend, |loaded, total| {
+            progress.set_progress(loaded, total);
+        })
+    })
+    .await
 }
 
 // =============================================================================
@@ -144,6 +152,10 @@ pub async fn cmd_server(
 pub fn cmd_status(db_path: &PathBuf, backend: &str, json_mode: bool) -> Result<(), KremisError> {
     let session = load_or_create_session(db_path, backend)?;
     let metrics = GraphMetrics::from_session(&session);
+    let degrees = match session.graph_opt() {
+        Some(graph) => graph.degree_histogram(),
+        None => session.export_graph_snapshot()?.degree_histogram(),
+    };
 
     if json_mode {
         let output = serde_json::json!({
@@ -153,7 +165,8 @@ pub fn cmd_status(db_path: &PathBuf, backend: &str, json_mode: bool) -> Result<(
             "edge_count": metrics.edge_count,
             "stable_edges": metrics.stable_edge_count,
             "density_per_thousand": metrics.density_per_thousand(),
-            "max_depth": metrics.max_depth
+            "max_depth": metrics.max_depth,
+            "degree_histogram": degrees
         });
         println!(
             "{}",
@@ -175,6 +188,13 @@ pub fn cmd_status(db_path: &PathBuf, backend: &str, json_mode: bool) -> Result<(
         metrics.density_per_thousand()
     );
     println!("Max Depth:    {}", metrics.max_depth);
+    if !degrees.is_empty() {
+        let histogram: Vec<String> = degrees
+            .iter()
+            .map(|(degree, count)| format!("degree {degree}: {count}"))
+            .collect();
+        println!("Nodes with {}", histogram.join(", "));
+    }
 
     Ok(())
 }
@@ -183,12 +203,31 @@ pub fn cmd_status(db_path: &PathBuf, backend: &str, json_mode: bool) -> Result<(
 // STAGE COMMAND
 // =============================================================================
 
+/// Number of cells in the `stage --bar` progress bar.
+const STAGE_BAR_WIDTH: usize = 20;
+
+/// Render `[####····]` with `current / needed` of `width` cells filled.
+///
+/// Integer math only; `needed == 0` renders a full bar.
+pub fn render_progress_bar(current: usize, needed: usize, width: usize) -> String {
+    let filled = match needed {
+        0 => width,
+        _ => current.min(needed).saturating_mul(width) / needed,
+    };
+    format!(
+        "[{}{}]",
+        "#".repeat(filled),
+        "\u{b7}".repeat(width.saturating_sub(filled))
+    )
+}
+
 /// Show developmental stage.
 pub fn cmd_stage(
     db_path: &PathBuf,
     backend: &str,
     json_mode: bool,
     detailed: bool,
+    bar: bool,
 ) -> Result<(), KremisError> {
     let session = load_or_create_session(db_path, backend)?;
 
@@ -233,6 +272,17 @@ pub fn cmd_stage(
         println!("Terminal stage reached (S3)");
     }
 
+    if bar {
+        println!(
+            "{}",
+            render_progress_bar(
+                progress.stable_edges_current,
+                progress.stable_edges_needed,
+                STAGE_BAR_WIDTH
+            )
+        );
+    }
+
     if detailed {
         println!();
         println!("Metrics:");
@@ -253,19 +303,111 @@ pub fn cmd_stage(
 // INGEST COMMAND
 // =============================================================================
 
+/// Signals ingested between node-count checks when auto-spill is on.
+const AUTO_SPILL_CHUNK: usize = 256;
+
 /// Ingest signals from a file.
+///
+/// With `auto_spill` set on the file backend, signals are ingested in
+/// chunks and the session moves to redb once it holds more than that many
+/// nodes. The redb database then replaces the file at `db_path`.
 pub fn cmd_ingest(
     db_path: &PathBuf,
     backend: &str,
     _json_mode: bool,
     file: &PathBuf,
     format: &str,
+    auto_spill: Option<usize>,
 ) -> Result<(), KremisError> {
-    use kremis_core::{Attribute, EntityId, Signal, Value};
-
     tracing::info!("Ingesting from {:?} (format: {})", file, format);
 
     let mut session = load_or_create_session(db_path, backend)?;
+    let signals = read_signals_file(file, format)?;
+
+    // Ingest signals
+    let count = signals.len();
+    let spill_path = db_path.with_extension("spill.redb");
+    match auto_spill {
+        Some(threshold) if !session.is_persistent() => {
+            ingest_with_spill(&mut session, &signals, threshold, &spill_path)?;
+        }
+        _ => {
+            session.ingest_sequence(&signals)?;
+        }
+    }
+
+    // Save graph
+    save_session(&session, db_path)?;
+
+    println!("Ingested {} signals", count);
+    println!(
+        "Graph now has {} nodes, {} edges",
+        session.node_count(),
+        session.edge_count()
+    );
+
+    if session.is_persistent() && backend != "redb" {
+        // Release the redb lock before moving the database into place
+        drop(session);
+        std::fs::rename(&spill_path, db_path)
+            .map_err(|e| KremisError::IoError(format!("Move spilled db: {}", e)))?;
+        println!(
+            "Spilled to redb at {:?}; open it with --backend redb",
+            db_path
+        );
+    }
+
+    Ok(())
+}
+
+/// Ingest `signals` in chunks, spilling to redb at `spill_path` once the
+/// node count exceeds `threshold`.
+///
+/// Chunking would drop the association between the last signal of one
+/// chunk and the first of the next, so that edge is reinforced here to
+/// match a single `ingest_sequence` call.
+fn ingest_with_spill(
+    session: &mut Session,
+    signals: &[kremis_core::Signal],
+    threshold: usize,
+    spill_path: &PathBuf,
+) -> Result<(), KremisError> {
+    let mut previous: Option<&kremis_core::Signal> = None;
+    for chunk in signals.chunks(AUTO_SPILL_CHUNK) {
+        session.ingest_sequence(chunk)?;
+        if let (Some(prev), Some(first)) = (previous, chunk.first())
+            && let (Some(from), Some(to)) = (
+                session.lookup_entity(prev.entity),
+                session.lookup_entity(first.entity),
+            )
+        {
+            let weight = session.get_edge(from, to).map_or(0, |w| w.value());
+            session.set_entity_edge(
+                prev.entity,
+                first.entity,
+                EdgeWeight::new(weight.saturating_add(1)),
+            )?;
+        }
+        previous = chunk.last();
+
+        if !session.is_persistent() && session.node_count() > threshold {
+            tracing::info!(
+                "Node count {} exceeds {}, spilling to redb",
+                session.node_count(),
+                threshold
+            );
+            session.spill_to_redb(spill_path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Read and parse a signals file (`json` or `text` format).
+fn read_signals_file(
+    file: &std::path::Path,
+    format: &str,
+) -> Result<Vec<kremis_core::Signal>, KremisError> {
+    use kremis_core::{Attribute, EntityId, Signal, Value};
 
     // L1 FIX: Validate file path for security (prevents path traversal)
     let validated_path = validate_file_path(file)?;
@@ -350,24 +492,50 @@ pub fn cmd_ingest(
     };
 
     // Validate signal count
-    if signals.len() > kremis_core::primitives::MAX_SEQUENCE_LENGTH {
+    if signals.len() > MAX_SEQUENCE_LENGTH {
         return Err(KremisError::SerializationError(format!(
             "Signal count {} exceeds maximum {}",
             signals.len(),
-            kremis_core::primitives::MAX_SEQUENCE_LENGTH
+            MAX_SEQUENCE_LENGTH
         )));
     }
 
-    // Ingest signals
-    let count = signals.len();
-    session.ingest_sequence(&signals)?;
+    Ok(signals)
+}
 
-    // Save graph
+// =============================================================================
+// REPLAY COMMAND
+// =============================================================================
+
+/// Replay a signal log into a fresh database.
+///
+/// The target database must not exist yet. Ingestion is deterministic, so
+/// replaying the same log always yields a byte-identical canonical export.
+pub fn cmd_replay(
+    signals_file: &std::path::Path,
+    format: &str,
+    db_path: &PathBuf,
+    backend: &str,
+) -> Result<(), KremisError> {
+    if db_path.exists() {
+        return Err(KremisError::SerializationError(format!(
+            "Database {:?} already exists. Replay requires a fresh database.",
+            db_path
+        )));
+    }
+
+    let signals = read_signals_file(signals_file, format)?;
+
+    let mut session = match backend {
+        "redb" => Session::with_redb(db_path)?,
+        _ => Session::new(),
+    };
+    session.ingest_sequence(&signals)?;
     save_session(&session, db_path)?;
 
-    println!("Ingested {} signals", count);
+    println!("Replayed {} signals into {:?}", signals.len(), db_path);
     println!(
-        "Graph now has {} nodes, {} edges",
+        "Graph has {} nodes, {} edges",
         session.node_count(),
         session.edge_count()
     );
@@ -545,6 +713,7 @@ pub fn cmd_export(
     backend: &str,
     output: &std::path::Path,
     format: &str,
+    min_weight: Option<i64>,
 ) -> Result<(), KremisError> {
     // L1 FIX: Validate output path for security (prevents path traversal)
     let validated_output = validate_output_path(output)?;
@@ -554,21 +723,46 @@ pub fn cmd_export(
     // M3 FIX: Use export_graph_snapshot() which works with both backends
     let graph = session.export_graph_snapshot()?;
 
+    if format == "ndjson-stream" {
+        let lines = write_ndjson_stream(&graph, &validated_output)?;
+        println!("Exported {} records to {:?}", lines, validated_output);
+        return Ok(());
+    }
+
     let data = match format {
         "canonical" => {
-            let data = export_canonical(&graph)?;
-            let checksum = canonical_checksum(&graph);
-            println!("Checksum: {}", checksum);
+            let source_name = db_path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let (data, manifest) = export_with_manifest(&graph, &source_name)?;
+            println!("Checksum: {}", manifest.canonical_hash);
+
+            let manifest_path = validated_output.with_extension("manifest.json");
+            let json = serde_json::to_vec_pretty(&manifest)
+                .map_err(|e| KremisError::SerializationError(e.to_string()))?;
+            std::fs::write(&manifest_path, json)
+                .map_err(|e| KremisError::SerializationError(format!("Write file: {}", e)))?;
+            println!("Manifest written to {:?}", manifest_path);
             data
         }
+        "stable" => {
+            let threshold = EdgeWeight::new(min_weight.unwrap_or(STABLE_THRESHOLD));
+            println!(
+                "Stable subgraph: edges with weight >= {}",
+                threshold.value()
+            );
+            export_stable_canonical(&graph, threshold)?
+        }
         "json" => {
             let serializable = kremis_core::SerializableGraph::from(&graph);
             serde_json::to_vec_pretty(&serializable)
                 .map_err(|e| KremisError::SerializationError(e.to_string()))?
         }
+        "msgpack" => export_msgpack(&graph)?,
         _ => {
             return Err(KremisError::SerializationError(format!(
-                "Unknown format: {}. Use: canonical, json",
+                "Unknown format: {}. Use: canonical, stable, json, msgpack, ndjson-stream",
                 format
             )));
         }
@@ -582,15 +776,74 @@ pub fn cmd_export(
     Ok(())
 }
 
+/// Stream a graph as newline-delimited JSON through a buffered writer.
+///
+/// Writes a header line, then one line per node (with its properties) and
+/// one per edge, each a standalone JSON object. Records are serialized one
+/// at a time, so the full document is never held in memory. Returns the
+/// number of lines written.
+fn write_ndjson_stream(graph: &Graph, path: &std::path::Path) -> Result<usize, KremisError> {
+    use std::io::Write;
+
+    let file = std::fs::File::create(path)
+        .map_err(|e| KremisError::SerializationError(format!("Create file: {}", e)))?;
+    let mut writer = std::io::BufWriter::new(file);
+    let mut lines = 0usize;
+    let mut write_line = |record: serde_json::Value| -> Result<(), KremisError> {
+        serde_json::to_writer(&mut writer, &record)
+            .map_err(|e| KremisError::SerializationError(e.to_string()))?;
+        writer
+            .write_all(b"\n")
+            .map_err(|e| KremisError::SerializationError(format!("Write file: {}", e)))?;
+        lines = lines.saturating_add(1);
+        Ok(())
+    };
+
+    write_line(serde_json::json!({
+        "type": "header",
+        "format": "kremis-ndjson",
+        "version": env!("CARGO_PKG_VERSION"),
+        "node_count": graph.node_count()?,
+        "edge_count": graph.edge_count()?,
+    }))?;
+    for node in graph.nodes() {
+        let properties: Vec<serde_json::Value> = graph
+            .get_properties(node.id)?
+            .into_iter()
+            .map(|(attr, value)| serde_json::json!([attr.as_str(), value.as_str()]))
+            .collect();
+        write_line(serde_json::json!({
+            "type": "node",
+            "id": node.id.0,
+            "entity": node.entity.0,
+            "properties": properties,
+        }))?;
+    }
+    for (from, to, weight) in graph.edges() {
+        write_line(serde_json::json!({
+            "type": "edge",
+            "from": from.0,
+            "to": to.0,
+            "weight": weight.value(),
+        }))?;
+    }
+
+    writer
+        .flush()
+        .map_err(|e| KremisError::SerializationError(format!("Write file: {}", e)))?;
+    Ok(lines)
+}
+
 // =============================================================================
 // IMPORT COMMAND
 // =============================================================================
 
-/// Import graph.
+/// Import graph from a `canonical` or `msgpack` export.
 pub fn cmd_import(
     db_path: &PathBuf,
     backend: &str,
     input: &std::path::Path,
+    format: &str,
 ) -> Result<(), KremisError> {
     // L1 FIX: Validate file path for security (prevents path traversal)
     let validated_path = validate_file_path(input)?;
@@ -601,7 +854,16 @@ pub fn cmd_import(
     let data = std::fs::read(&validated_path)
         .map_err(|e| KremisError::SerializationError(format!("Read file: {}", e)))?;
 
-    let graph = import_canonical(&data)?;
+    let graph = match format {
+        "canonical" => import_canonical(&data)?,
+        "msgpack" => import_msgpack(&data)?,
+        _ => {
+            return Err(KremisError::SerializationError(format!(
+                "Unknown format: {}. Use: canonical, msgpack",
+                format
+            )));
+        }
+    };
     let session = Session::with_graph(graph);
 
     if backend == "redb" {
@@ -621,6 +883,48 @@ pub fn cmd_import(
     Ok(())
 }
 
+// =============================================================================
+// MERGE COMMAND
+// =============================================================================
+
+/// Merge several databases into one output database (file backend only).
+///
+/// Inputs are read with `backend` and matched by entity; shared edges have
+/// their weights summed. The output is overwritten.
+pub fn cmd_merge(
+    inputs: &[PathBuf],
+    output: &std::path::Path,
+    backend: &str,
+) -> Result<(), KremisError> {
+    if inputs.is_empty() {
+        return Err(KremisError::SerializationError(
+            "Merge needs at least one input".to_string(),
+        ));
+    }
+
+    // L1 FIX: Validate output path for security (prevents path traversal)
+    let validated_output = validate_output_path(output)?;
+
+    let mut merged = Graph::new();
+    for input in inputs {
+        let validated_input = validate_file_path(input)?;
+        let session = load_or_create_session(&validated_input, backend)?;
+        merged.merge(&session.export_graph_snapshot()?);
+    }
+
+    let session = Session::with_graph(merged);
+    save_session(&session, &validated_output)?;
+
+    println!(
+        "Merged {} databases: {} nodes, {} edges",
+        inputs.len(),
+        session.node_count(),
+        session.edge_count()
+    );
+
+    Ok(())
+}
+
 // =============================================================================
 // INIT COMMAND
 // =============================================================================
@@ -685,15 +989,27 @@ pub fn cmd_hash(db_path: &PathBuf, backend: &str, json_mode: bool) -> Result<(),
 
 /// Load or create a session from a database path with specified backend.
 pub fn load_or_create_session(db_path: &PathBuf, backend: &str) -> Result<Session, KremisError> {
+    load_or_create_session_with_progress(db_path, backend, |_, _| {})
+}
+
+/// Load or create a session, calling `progress(loaded_nodes, total_nodes)`
+/// while a canonical file's nodes are inserted.
+///
+/// The redb backend reads lazily and reports nothing.
+pub fn load_or_create_session_with_progress(
+    db_path: &PathBuf,
+    backend: &str,
+    progress: impl FnMut(usize, usize),
+) -> Result<Session, KremisError> {
     match backend {
-        "redb" => Session::with_redb(db_path),
+        "redb" => Ok(Session::with_redb(db_path)?),
         _ => {
             if db_path.exists() {
                 let data = std::fs::read(db_path)
                     .map_err(|e| KremisError::SerializationError(format!("Read db: {}", e)))?;
 
                 // Try canonical format first
-                if let Ok(graph) = import_canonical(&data) {
+                if let Ok(graph) = import_canonical_with_progress(&data, progress) {
                     return Ok(Session::with_graph(graph));
                 }
 
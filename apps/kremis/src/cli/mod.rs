@@ -8,16 +8,18 @@
 //! - `status` - Show graph status
 //! - `stage` - Show developmental stage
 //! - `ingest` - Ingest signals from a file
+//! - `replay` - Rebuild a fresh database from a signal log
 //! - `query` - Execute a query on the graph
 //! - `export` - Export graph to file
 //! - `import` - Import graph from file
+//! - `merge` - Merge several databases into one
 //! - `init` - Initialize new database
 //! - `hash` - Compute BLAKE3 cryptographic hash of graph
 
 mod commands;
 
 use clap::{Parser, Subcommand};
-use kremis_core::KremisError;
+use kremis_core::{KremisError, SessionError};
 use std::path::PathBuf;
 
 pub use commands::*;
@@ -81,6 +83,10 @@ pub enum Commands {
         /// Show detailed progress information
         #[arg(short, long)]
         detailed: bool,
+
+        /// Show progress to the next stage as an ASCII bar
+        #[arg(long)]
+        bar: bool,
     },
 
     /// Ingest signals from a file
@@ -92,6 +98,21 @@ pub enum Commands {
         /// Input format (json, text)
         #[arg(short = 't', long, default_value = "json")]
         format: String,
+
+        /// Switch the file backend to redb once the graph exceeds this many nodes
+        #[arg(long, value_name = "NODES")]
+        auto_spill: Option<usize>,
+    },
+
+    /// Replay a signal log into a fresh database
+    Replay {
+        /// Path to the signal log (JSON or text)
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Input format (json, text)
+        #[arg(short = 't', long, default_value = "json")]
+        format: String,
     },
 
     /// Execute a query on the graph
@@ -131,9 +152,13 @@ pub enum Commands {
         #[arg(short, long)]
         output: PathBuf,
 
-        /// Export format (canonical, json)
+        /// Export format (canonical, stable, json, msgpack, ndjson-stream)
         #[arg(short = 't', long, default_value = "canonical")]
         format: String,
+
+        /// Minimum edge weight for the `stable` format (default: stable threshold)
+        #[arg(long)]
+        min_weight: Option<i64>,
     },
 
     /// Import graph from canonical format (file backend only)
@@ -141,6 +166,21 @@ pub enum Commands {
         /// Input file path
         #[arg(short, long)]
         input: PathBuf,
+
+        /// Import format (canonical, msgpack)
+        #[arg(short = 't', long, default_value = "canonical")]
+        format: String,
+    },
+
+    /// Merge several databases into one file database (sums shared edge weights)
+    Merge {
+        /// Input database paths (read with --backend)
+        #[arg(short, long, num_args = 1.., required = true)]
+        inputs: Vec<PathBuf>,
+
+        /// Output database path (file backend)
+        #[arg(short, long)]
+        output: PathBuf,
     },
 
     /// Initialize a new empty database
@@ -154,6 +194,28 @@ pub enum Commands {
     Hash,
 }
 
+// =============================================================================
+// EXIT CODES
+// =============================================================================
+
+/// Exit code for invalid input data (sysexits `EX_DATAERR`).
+pub const EXIT_DATA_ERROR: i32 = 65;
+
+/// Exit code for storage read or write failures (sysexits `EX_IOERR`).
+pub const EXIT_IO_ERROR: i32 = 74;
+
+/// Exit code for any other failure.
+pub const EXIT_FAILURE: i32 = 1;
+
+/// Map a failed command to its process exit code.
+pub fn exit_code(error: KremisError) -> i32 {
+    match SessionError::from(error) {
+        SessionError::InvalidSignal | SessionError::SequenceTooLong { .. } => EXIT_DATA_ERROR,
+        SessionError::Io(_) => EXIT_IO_ERROR,
+        SessionError::Graph(_) => EXIT_FAILURE,
+    }
+}
+
 // =============================================================================
 // COMMAND EXECUTION
 // =============================================================================
@@ -168,11 +230,23 @@ pub async fn execute(cli: Cli) -> Result<(), KremisError> {
             cmd_server(&cli.database, backend, &host, port).await
         }
         Some(Commands::Status) => cmd_status(&cli.database, backend, json_mode),
-        Some(Commands::Stage { detailed }) => {
-            cmd_stage(&cli.database, backend, json_mode, detailed)
+        Some(Commands::Stage { detailed, bar }) => {
+            cmd_stage(&cli.database, backend, json_mode, detailed, bar)
         }
-        Some(Commands::Ingest { file, format }) => {
-            cmd_ingest(&cli.database, backend, json_mode, &file, &format)
+        Some(Commands::Ingest {
+            file,
+            format,
+            auto_spill,
+        }) => cmd_ingest(
+            &cli.database,
+            backend,
+            json_mode,
+            &file,
+            &format,
+            auto_spill,
+        ),
+        Some(Commands::Replay { file, format }) => {
+            cmd_replay(&file, &format, &cli.database, backend)
         }
         Some(Commands::Query {
             query_type,
@@ -194,10 +268,15 @@ pub async fn execute(cli: Cli) -> Result<(), KremisError> {
             nodes,
             min_weight,
         ),
-        Some(Commands::Export { output, format }) => {
-            cmd_export(&cli.database, backend, &output, &format)
+        Some(Commands::Export {
+            output,
+            format,
+            min_weight,
+        }) => cmd_export(&cli.database, backend, &output, &format, min_weight),
+        Some(Commands::Import { input, format }) => {
+            cmd_import(&cli.database, backend, &input, &format)
         }
-        Some(Commands::Import { input }) => cmd_import(&cli.database, backend, &input),
+        Some(Commands::Merge { inputs, output }) => cmd_merge(&inputs, &output, backend),
         Some(Commands::Init { force }) => cmd_init(&cli.database, backend, force),
         Some(Commands::Hash) => cmd_hash(&cli.database, backend, json_mode),
         None => {
@@ -83,7 +83,7 @@ async fn main() {
     // Execute command
     if let Err(e) = cli::execute(cli).await {
         tracing::error!("Error: {}", e);
-        std::process::exit(1);
+        std::process::exit(cli::exit_code(e));
     }
 }
 
@@ -10,8 +10,14 @@
 use axum::http::HeaderValue;
 use axum_test::TestServer;
 use kremis::api::{
-    AppState, ExportResponse, HealthResponse, IngestRequest, IngestResponse, QueryRequest,
-    QueryResponse, RetractRequest, RetractResponse, StageResponse, StatusResponse, create_router,
+    AdjustEdgeRequest, AdjustEdgeResponse, AppState, AttributeSchemaResponse, BatchLookupRequest,
+    BatchLookupResponse, BinaryQueryBody, BinaryQueryRequest, BinaryQueryResponse,
+    BulkEdgesResponse, EdgeJson, EdgeListResponse, EdgePageResponse, EntityEdgeJson,
+    EntityWeightJson, ExportResponse, FingerprintResponse, HealthResponse, ImportResponse,
+    IngestRequest, IngestResponse, OCTET_STREAM_CONTENT_TYPE, POSTCARD_CONTENT_TYPE,
+    ProvenanceResponse, PutEdgeRequest, QueryRequest, QueryResponse, ReadyResponse, RetractRequest,
+    RetractResponse, SourceCountJson, StageResponse, StatusResponse, SummaryResponse,
+    TopRelationshipsResponse, TraversalStrategy, create_router,
 };
 use kremis_core::Session;
 use serde_json::json;
@@ -106,6 +112,98 @@ async fn test_health_returns_correct_version() {
     assert_eq!(health.version, env!("CARGO_PKG_VERSION"));
 }
 
+#[tokio::test]
+async fn test_ready_reports_load_progress() {
+    let guard = AUTH_TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+    // SAFETY: Tests run sequentially under AUTH_TEST_MUTEX, so no concurrent env access.
+    unsafe { std::env::remove_var("KREMIS_API_KEY") };
+
+    let state = AppState::new(Session::new());
+    let load = state.load.clone();
+    let server = TestServer::new(create_router(state)).unwrap();
+    let _guard = TestGuard { _guard: guard };
+
+    load.set_progress(4, 10);
+    let response = server.get("/ready").await;
+    response.assert_status(axum::http::StatusCode::SERVICE_UNAVAILABLE);
+    let ready: ReadyResponse = response.json();
+    assert!(!ready.ready);
+    assert_eq!((ready.loaded_nodes, ready.total_nodes), (4, 10));
+
+    // Liveness is unaffected by loading
+    server.get("/health").await.assert_status_ok();
+
+    load.finish();
+    let response = server.get("/ready").await;
+    response.assert_status_ok();
+    let ready: ReadyResponse = response.json();
+    assert!(ready.ready);
+}
+
+/// Minimal HTTP/1.1 GET against a real listener: `(status, body)`.
+async fn raw_get(addr: std::net::SocketAddr, path: &str) -> (u16, String) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+    let request = format!("GET {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n");
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+    (status, body.to_string())
+}
+
+#[tokio::test]
+async fn test_ready_reports_progress_from_server_loader() {
+    let guard = AUTH_TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+    // SAFETY: Tests run sequentially under AUTH_TEST_MUTEX, so no concurrent env access.
+    unsafe { std::env::remove_var("KREMIS_API_KEY") };
+    let _guard = TestGuard { _guard: guard };
+
+    let mut graph = kremis_core::Graph::new();
+    let entities: Vec<_> = (1..=5).map(kremis_core::EntityId).collect();
+    graph.insert_nodes(&entities);
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("graph.kremis");
+    std::fs::write(&db_path, kremis_core::export_canonical(&graph).unwrap()).unwrap();
+
+    // Hold the loader after its third node until the test has looked at /ready
+    let (resume_tx, resume_rx) = std::sync::mpsc::channel::<()>();
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = tokio::spawn(kremis::api::serve(listener, move |progress| {
+        kremis::cli::load_or_create_session_with_progress(&db_path, "file", |loaded, total| {
+            progress.set_progress(loaded, total);
+            if loaded == 3 {
+                let _ = resume_rx.recv();
+            }
+        })
+    }));
+
+    let ready = loop {
+        let (status, body) = raw_get(addr, "/ready").await;
+        let ready: ReadyResponse = serde_json::from_str(&body).unwrap();
+        if ready.loaded_nodes > 0 {
+            assert_eq!(status, 503);
+            break ready;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    };
+    assert!(!ready.ready);
+    assert_eq!((ready.loaded_nodes, ready.total_nodes), (3, 5));
+
+    resume_tx.send(()).unwrap();
+    let ready = loop {
+        let (status, body) = raw_get(addr, "/ready").await;
+        if status == 200 {
+            break serde_json::from_str::<ReadyResponse>(&body).unwrap();
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    };
+    assert!(ready.ready);
+    server.abort();
+}
+
 // =============================================================================
 // STATUS ENDPOINT TESTS
 // =============================================================================
@@ -135,6 +233,50 @@ async fn test_status_populated_graph() {
     assert!(status.edge_count > 0, "Should have edges");
 }
 
+#[tokio::test]
+async fn test_summary_combines_status_stage_and_hubs() {
+    let (server, _guard) = create_populated_test_server();
+    let status: StatusResponse = server.get("/status").await.json();
+    let stage: StageResponse = server.get("/stage").await.json();
+
+    let response = server.get("/summary").add_query_param("top_k", 2).await;
+
+    response.assert_status_ok();
+    let summary: SummaryResponse = response.json();
+    assert_eq!(summary.status.node_count, status.node_count);
+    assert_eq!(summary.status.edge_count, status.edge_count);
+    assert_eq!(summary.stage.stage, stage.stage);
+    assert_eq!(summary.hubs.len(), 2);
+    assert!(summary.hubs[0].degree >= summary.hubs[1].degree);
+}
+
+#[tokio::test]
+async fn test_schema_attributes_counts_entities() {
+    let (server, _guard) = create_test_server();
+    for (entity_id, attribute, value) in [
+        (1, "name", "Alice"),
+        (1, "email", "alice@example.com"),
+        (2, "name", "Bob"),
+    ] {
+        server
+            .post("/signal")
+            .json(&json!({"entity_id": entity_id, "attribute": attribute, "value": value}))
+            .await
+            .assert_status_ok();
+    }
+
+    let response = server.get("/schema/attributes").await;
+
+    response.assert_status_ok();
+    let schema: AttributeSchemaResponse = response.json();
+    let counts: Vec<(&str, usize)> = schema
+        .attributes
+        .iter()
+        .map(|a| (a.attribute.as_str(), a.count))
+        .collect();
+    assert_eq!(counts, vec![("name", 2), ("email", 1)]);
+}
+
 // =============================================================================
 // STAGE ENDPOINT TESTS
 // =============================================================================
@@ -180,6 +322,8 @@ async fn test_ingest_valid_signal() {
         entity_id: 1,
         attribute: "name".to_string(),
         value: "Alice".to_string(),
+        namespace: None,
+        source: None,
     };
 
     let response = server.post("/signal").json(&request).await;
@@ -235,6 +379,8 @@ async fn test_ingest_multiple_signals() {
         entity_id: 1,
         attribute: "name".to_string(),
         value: "Alice".to_string(),
+        namespace: None,
+        source: None,
     };
     let response1 = server.post("/signal").json(&request1).await;
     let result1: IngestResponse = response1.json();
@@ -245,6 +391,8 @@ async fn test_ingest_multiple_signals() {
         entity_id: 2,
         attribute: "name".to_string(),
         value: "Bob".to_string(),
+        namespace: None,
+        source: None,
     };
     let response2 = server.post("/signal").json(&request2).await;
     let result2: IngestResponse = response2.json();
@@ -264,7 +412,10 @@ async fn test_ingest_multiple_signals() {
 async fn test_query_lookup_not_found() {
     let (server, _guard) = create_test_server();
 
-    let request = QueryRequest::Lookup { entity_id: 999 };
+    let request = QueryRequest::Lookup {
+        entity_id: 999,
+        namespace: None,
+    };
     let response = server.post("/query").json(&request).await;
 
     response.assert_status_ok();
@@ -279,7 +430,10 @@ async fn test_query_lookup_not_found() {
 async fn test_query_lookup_found() {
     let (server, _guard) = create_populated_test_server();
 
-    let request = QueryRequest::Lookup { entity_id: 1 };
+    let request = QueryRequest::Lookup {
+        entity_id: 1,
+        namespace: None,
+    };
     let response = server.post("/query").json(&request).await;
 
     response.assert_status_ok();
@@ -295,7 +449,10 @@ async fn test_query_traverse() {
     let (server, _guard) = create_populated_test_server();
 
     // First lookup to get a node ID
-    let lookup = QueryRequest::Lookup { entity_id: 1 };
+    let lookup = QueryRequest::Lookup {
+        entity_id: 1,
+        namespace: None,
+    };
     let lookup_response = server.post("/query").json(&lookup).await;
     let lookup_result: QueryResponse = lookup_response.json();
 
@@ -310,7 +467,13 @@ async fn test_query_traverse() {
 
     let node_id = lookup_result.path[0];
 
-    let request = QueryRequest::Traverse { node_id, depth: 2 };
+    let request = QueryRequest::Traverse {
+        node_id,
+        depth: 2,
+        strategy: TraversalStrategy::Bfs,
+        limit: None,
+        offset: None,
+    };
     let response = server.post("/query").json(&request).await;
 
     response.assert_status_ok();
@@ -337,7 +500,10 @@ async fn test_query_traverse_filtered() {
     let (server, _guard) = create_populated_test_server();
 
     // First verify node 1 exists
-    let lookup = QueryRequest::Lookup { entity_id: 1 };
+    let lookup = QueryRequest::Lookup {
+        entity_id: 1,
+        namespace: None,
+    };
     let lookup_response = server.post("/query").json(&lookup).await;
     let lookup_result: QueryResponse = lookup_response.json();
     assert!(lookup_result.found, "Entity 1 should exist");
@@ -387,8 +553,14 @@ async fn test_query_strongest_path() {
     let (server, _guard) = create_populated_test_server();
 
     // First verify both nodes exist
-    let lookup1 = QueryRequest::Lookup { entity_id: 1 };
-    let lookup2 = QueryRequest::Lookup { entity_id: 2 };
+    let lookup1 = QueryRequest::Lookup {
+        entity_id: 1,
+        namespace: None,
+    };
+    let lookup2 = QueryRequest::Lookup {
+        entity_id: 2,
+        namespace: None,
+    };
     let resp1 = server.post("/query").json(&lookup1).await;
     let resp2 = server.post("/query").json(&lookup2).await;
     let result1: QueryResponse = resp1.json();
@@ -430,8 +602,14 @@ async fn test_query_intersect() {
     let (server, _guard) = create_populated_test_server();
 
     // Get actual node IDs from lookups
-    let lookup1 = QueryRequest::Lookup { entity_id: 1 };
-    let lookup2 = QueryRequest::Lookup { entity_id: 2 };
+    let lookup1 = QueryRequest::Lookup {
+        entity_id: 1,
+        namespace: None,
+    };
+    let lookup2 = QueryRequest::Lookup {
+        entity_id: 2,
+        namespace: None,
+    };
     let resp1 = server.post("/query").json(&lookup1).await;
     let resp2 = server.post("/query").json(&lookup2).await;
     let result1: QueryResponse = resp1.json();
@@ -482,18 +660,114 @@ async fn test_query_intersect_nonexistent_nodes() {
     assert_eq!(result.grounding, "unknown");
 }
 
+#[tokio::test]
+async fn test_query_traverse_paged_matches_full_result() {
+    let (server, _guard) = create_test_server();
+    let edges: Vec<_> = [(1, 2), (1, 3), (1, 4), (2, 5), (3, 6), (6, 7)]
+        .into_iter()
+        .map(|(from, to)| json!({"from_entity": from, "to_entity": to, "weight": 1}))
+        .collect();
+    server
+        .post("/edges/bulk")
+        .json(&edges)
+        .await
+        .assert_status_ok();
+    let lookup: QueryResponse = server
+        .post("/query")
+        .json(&QueryRequest::Lookup {
+            entity_id: 1,
+            namespace: None,
+        })
+        .await
+        .json();
+    let node_id = lookup.path[0];
+    let traverse = |limit, offset| QueryRequest::Traverse {
+        node_id,
+        depth: 3,
+        strategy: TraversalStrategy::Bfs,
+        limit,
+        offset,
+    };
+
+    let full: QueryResponse = server
+        .post("/query")
+        .json(&traverse(None, None))
+        .await
+        .json();
+    assert!(full.page.is_none());
+    assert!(
+        full.path.len() > 2,
+        "populated graph should yield several nodes"
+    );
+
+    let mut path = Vec::new();
+    let mut edges = Vec::new();
+    let mut offset = None;
+    loop {
+        let page: QueryResponse = server
+            .post("/query")
+            .json(&traverse(Some(2), offset))
+            .await
+            .json();
+        assert!(page.path.len() <= 2);
+        path.extend(page.path);
+        edges.extend(page.edges);
+        let info = page.page.expect("paged response carries page info");
+        assert_eq!(info.total, full.path.len());
+        match info.next_offset {
+            Some(next) => offset = Some(next),
+            None => break,
+        }
+    }
+    assert_eq!(path, full.path);
+    assert_eq!(edges, full.edges);
+}
+
+#[tokio::test]
+async fn test_query_rejects_zero_page_limit() {
+    let (server, _guard) = create_populated_test_server();
+
+    let traverse = QueryRequest::Traverse {
+        node_id: 0,
+        depth: 2,
+        strategy: TraversalStrategy::Bfs,
+        limit: Some(0),
+        offset: None,
+    };
+    server
+        .post("/query")
+        .json(&traverse)
+        .await
+        .assert_status_bad_request();
+
+    let related = json!({"type": "related", "node_id": 0, "depth": 2, "limit": 0});
+    server
+        .post("/query")
+        .json(&related)
+        .await
+        .assert_status_bad_request();
+}
+
 #[tokio::test]
 async fn test_query_related() {
     let (server, _guard) = create_populated_test_server();
 
     // First get actual node ID
-    let lookup = QueryRequest::Lookup { entity_id: 1 };
+    let lookup = QueryRequest::Lookup {
+        entity_id: 1,
+        namespace: None,
+    };
     let lookup_response = server.post("/query").json(&lookup).await;
     let lookup_result: QueryResponse = lookup_response.json();
     assert!(lookup_result.found, "Entity 1 should exist");
     let node_id = lookup_result.path[0];
 
-    let request = QueryRequest::Related { node_id, depth: 2 };
+    let request = QueryRequest::Related {
+        node_id,
+        depth: 2,
+        limit: None,
+        offset: None,
+    };
     let response = server.post("/query").json(&request).await;
 
     response.assert_status_ok();
@@ -519,6 +793,8 @@ async fn test_query_related_nonexistent_node() {
     let request = QueryRequest::Related {
         node_id: 99999,
         depth: 2,
+        limit: None,
+        offset: None,
     };
     let response = server.post("/query").json(&request).await;
 
@@ -536,6 +812,50 @@ async fn test_query_related_nonexistent_node() {
     assert_eq!(result.grounding, "unknown");
 }
 
+#[tokio::test]
+async fn test_query_traverse_dfs_order() {
+    use kremis_core::{EdgeWeight, EntityId, Graph, GraphStore};
+
+    let guard = AUTH_TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+    // SAFETY: Tests run sequentially under AUTH_TEST_MUTEX, so no concurrent env access.
+    unsafe { std::env::remove_var("KREMIS_API_KEY") };
+    let _guard = TestGuard { _guard: guard };
+
+    // 0 -> 1 -> 3, 0 -> 2
+    let mut graph = Graph::new();
+    let n: Vec<_> = (0..4)
+        .map(|i| graph.insert_node(EntityId(i)).unwrap())
+        .collect();
+    for (from, to) in [(0, 1), (0, 2), (1, 3)] {
+        graph
+            .insert_edge(n[from], n[to], EdgeWeight::new(1))
+            .unwrap();
+    }
+    let server = TestServer::new(create_router(AppState::new(Session::with_graph(graph)))).unwrap();
+
+    let query = |strategy| QueryRequest::Traverse {
+        node_id: n[0].0,
+        depth: 3,
+        strategy,
+        limit: None,
+        offset: None,
+    };
+    let bfs: QueryResponse = server
+        .post("/query")
+        .json(&query(TraversalStrategy::Bfs))
+        .await
+        .json();
+    let dfs: QueryResponse = server
+        .post("/query")
+        .json(&query(TraversalStrategy::Dfs))
+        .await
+        .json();
+
+    let ids = |idx: [usize; 4]| idx.map(|i| n[i].0).to_vec();
+    assert_eq!(bfs.path, ids([0, 1, 2, 3]));
+    assert_eq!(dfs.path, ids([0, 1, 3, 2]));
+}
+
 // =============================================================================
 // TOP_K TESTS
 // =============================================================================
@@ -582,7 +902,10 @@ async fn test_traverse_filtered_top_k_limits_result_count() {
     let (server, _guard) = create_star_graph_server();
 
     // Lookup hub node
-    let lookup = QueryRequest::Lookup { entity_id: 1 };
+    let lookup = QueryRequest::Lookup {
+        entity_id: 1,
+        namespace: None,
+    };
     let resp = server.post("/query").json(&lookup).await;
     let result: QueryResponse = resp.json();
     assert!(result.found, "Hub entity 1 must exist");
@@ -610,7 +933,10 @@ async fn test_traverse_filtered_top_k_limits_result_count() {
 async fn test_traverse_filtered_top_k_returns_highest_weights() {
     let (server, _guard) = create_star_graph_server();
 
-    let lookup = QueryRequest::Lookup { entity_id: 1 };
+    let lookup = QueryRequest::Lookup {
+        entity_id: 1,
+        namespace: None,
+    };
     let resp = server.post("/query").json(&lookup).await;
     let result: QueryResponse = resp.json();
     assert!(result.found);
@@ -645,7 +971,10 @@ async fn test_traverse_filtered_top_k_returns_highest_weights() {
 async fn test_traverse_filtered_top_k_none_returns_all() {
     let (server, _guard) = create_star_graph_server();
 
-    let lookup = QueryRequest::Lookup { entity_id: 1 };
+    let lookup = QueryRequest::Lookup {
+        entity_id: 1,
+        namespace: None,
+    };
     let resp = server.post("/query").json(&lookup).await;
     let result: QueryResponse = resp.json();
     assert!(result.found);
@@ -716,7 +1045,10 @@ fn create_isolated_pair_server() -> (TestServer, TestGuard) {
 async fn test_query_lookup_missing_has_diagnostic() {
     let (server, _guard) = create_test_server();
 
-    let request = QueryRequest::Lookup { entity_id: 99999 };
+    let request = QueryRequest::Lookup {
+        entity_id: 99999,
+        namespace: None,
+    };
     let response = server.post("/query").json(&request).await;
 
     response.assert_status_ok();
@@ -733,6 +1065,9 @@ async fn test_query_traverse_missing_node_has_diagnostic() {
     let request = QueryRequest::Traverse {
         node_id: 99999,
         depth: 2,
+        strategy: TraversalStrategy::Bfs,
+        limit: None,
+        offset: None,
     };
     let response = server.post("/query").json(&request).await;
 
@@ -748,13 +1083,22 @@ async fn test_query_traverse_found_no_diagnostic() {
     let (server, _guard) = create_populated_test_server();
 
     // Lookup to get a valid node ID
-    let lookup = QueryRequest::Lookup { entity_id: 1 };
+    let lookup = QueryRequest::Lookup {
+        entity_id: 1,
+        namespace: None,
+    };
     let lookup_resp = server.post("/query").json(&lookup).await;
     let lookup_result: QueryResponse = lookup_resp.json();
     assert!(lookup_result.found, "Entity 1 should exist");
     let node_id = lookup_result.path[0];
 
-    let request = QueryRequest::Traverse { node_id, depth: 2 };
+    let request = QueryRequest::Traverse {
+        node_id,
+        depth: 2,
+        strategy: TraversalStrategy::Bfs,
+        limit: None,
+        offset: None,
+    };
     let response = server.post("/query").json(&request).await;
 
     response.assert_status_ok();
@@ -789,7 +1133,10 @@ async fn test_query_path_end_not_found_has_diagnostic() {
     let (server, _guard) = create_populated_test_server();
 
     // Lookup entity 1 to get a real start node
-    let lookup = QueryRequest::Lookup { entity_id: 1 };
+    let lookup = QueryRequest::Lookup {
+        entity_id: 1,
+        namespace: None,
+    };
     let lookup_resp = server.post("/query").json(&lookup).await;
     let lookup_result: QueryResponse = lookup_resp.json();
     assert!(lookup_result.found, "Entity 1 should exist");
@@ -813,8 +1160,14 @@ async fn test_query_path_no_path_has_diagnostic() {
     let (server, _guard) = create_isolated_pair_server();
 
     // Lookup both isolated entities to get their node IDs
-    let lookup1 = QueryRequest::Lookup { entity_id: 100 };
-    let lookup2 = QueryRequest::Lookup { entity_id: 200 };
+    let lookup1 = QueryRequest::Lookup {
+        entity_id: 100,
+        namespace: None,
+    };
+    let lookup2 = QueryRequest::Lookup {
+        entity_id: 200,
+        namespace: None,
+    };
     let resp1 = server.post("/query").json(&lookup1).await;
     let resp2 = server.post("/query").json(&lookup2).await;
     let result1: QueryResponse = resp1.json();
@@ -912,6 +1265,95 @@ async fn test_export_populated_graph() {
     assert!(decoded.is_ok());
 }
 
+#[tokio::test]
+async fn test_snapshot_download_and_import_roundtrip() {
+    let (source, _guard) = create_populated_test_server();
+
+    let response = source.get("/export").await;
+    response.assert_status_ok();
+    assert_eq!(
+        response.header(axum::http::header::CONTENT_TYPE),
+        OCTET_STREAM_CONTENT_TYPE
+    );
+    let snapshot = response.as_bytes().clone();
+    let status: StatusResponse = source.get("/status").await.json();
+
+    // Without a configured API key the endpoint refuses to replace the graph.
+    let response = source.post("/import").bytes(snapshot.clone()).await;
+    response.assert_status(axum::http::StatusCode::FORBIDDEN);
+
+    let api_key = "import-key";
+    let target = create_auth_test_server(api_key);
+    let bearer = format!("Bearer {}", api_key);
+
+    let response = target
+        .post("/import")
+        .add_header(axum::http::header::AUTHORIZATION, bearer.clone())
+        .bytes(bytes::Bytes::from_static(b"not a snapshot"))
+        .await;
+    response.assert_status(axum::http::StatusCode::BAD_REQUEST);
+
+    let response = target
+        .post("/import")
+        .add_header(axum::http::header::AUTHORIZATION, bearer.clone())
+        .bytes(snapshot.clone())
+        .await;
+    response.assert_status_ok();
+    let result: ImportResponse = response.json();
+    assert!(result.success);
+    assert_eq!(result.node_count, Some(status.node_count));
+    assert_eq!(result.edge_count, Some(status.edge_count));
+
+    let response = target
+        .get("/export")
+        .add_header(axum::http::header::AUTHORIZATION, bearer)
+        .await;
+    assert_eq!(response.as_bytes(), &snapshot);
+}
+
+#[tokio::test]
+async fn test_import_accepts_snapshot_above_default_body_limit() {
+    use kremis_core::{Attribute, EntityId, Signal, Value};
+
+    let guard = AUTH_TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+    let _guard = TestGuard { _guard: guard };
+
+    let mut session = Session::new();
+    let value = "v".repeat(60_000);
+    for entity in 0..48 {
+        let signal = Signal::new(EntityId(entity), Attribute::new("blob"), Value::new(&value));
+        session.ingest(&signal).unwrap();
+    }
+    let snapshot = kremis_core::export_canonical(session.graph_opt().unwrap()).unwrap();
+    assert!(snapshot.len() > kremis::api::MAX_REQUEST_BODY_SIZE);
+
+    let api_key = "import-key";
+    let server = create_auth_test_server(api_key);
+    let response = server
+        .post("/import")
+        .add_header(
+            axum::http::header::AUTHORIZATION,
+            format!("Bearer {}", api_key),
+        )
+        .bytes(snapshot.into())
+        .await;
+    response.assert_status_ok();
+    let result: ImportResponse = response.json();
+    assert_eq!(result.node_count, Some(48));
+
+    // Other endpoints keep the default limit
+    let response = server
+        .post("/signals")
+        .add_header(
+            axum::http::header::AUTHORIZATION,
+            format!("Bearer {}", api_key),
+        )
+        .content_type("application/json")
+        .bytes(vec![b' '; kremis::api::MAX_REQUEST_BODY_SIZE + 1].into())
+        .await;
+    response.assert_status(axum::http::StatusCode::PAYLOAD_TOO_LARGE);
+}
+
 // =============================================================================
 // CORS TESTS
 // =============================================================================
@@ -1187,6 +1629,29 @@ async fn test_hash_after_ingest() {
 // METRICS ENDPOINT TESTS
 // =============================================================================
 
+#[tokio::test]
+async fn test_ingest_rate_reports_recent_burst() {
+    let (server, _guard) = create_test_server();
+    let status: StatusResponse = server.get("/status").await.json();
+    assert_eq!(status.ingest_rate, 0);
+
+    let batch = json!([
+        {"entity_id": 1, "attribute": "name", "value": "Alice"},
+        {"entity_id": 2, "attribute": "name", "value": "Bob"},
+        {"entity_id": 3, "attribute": "name", "value": "Carol"}
+    ]);
+    server
+        .post("/signals")
+        .json(&batch)
+        .await
+        .assert_status_ok();
+
+    let status: StatusResponse = server.get("/status").await.json();
+    assert_eq!(status.ingest_rate, 3);
+    let metrics = server.get("/metrics").await.text();
+    assert!(metrics.contains("kremis_ingest_rate 3"));
+}
+
 #[tokio::test]
 async fn test_metrics_content_type() {
     let (server, _guard) = create_test_server();
@@ -1262,6 +1727,7 @@ async fn test_retract_reduces_edge_weight() {
     let request = RetractRequest {
         from_entity: 1,
         to_entity: 2,
+        namespace: None,
     };
     let response = server.post("/signal/retract").json(&request).await;
 
@@ -1279,6 +1745,7 @@ async fn test_retract_from_entity_not_found_returns_404() {
     let request = RetractRequest {
         from_entity: 99999,
         to_entity: 1,
+        namespace: None,
     };
     let response = server.post("/signal/retract").json(&request).await;
 
@@ -1295,6 +1762,7 @@ async fn test_retract_to_entity_not_found_returns_404() {
     let request = RetractRequest {
         from_entity: 1,
         to_entity: 99999,
+        namespace: None,
     };
     let response = server.post("/signal/retract").json(&request).await;
 
@@ -1328,6 +1796,7 @@ async fn test_retract_edge_not_found_returns_404() {
     let request = RetractRequest {
         from_entity: 2,
         to_entity: 1,
+        namespace: None,
     };
     let response = server.post("/signal/retract").json(&request).await;
 
@@ -1360,6 +1829,7 @@ async fn test_retract_multiple_times_floors_at_zero() {
     let request = RetractRequest {
         from_entity: 10,
         to_entity: 11,
+        namespace: None,
     };
 
     // First retract: 1 → 0
@@ -1374,3 +1844,985 @@ async fn test_retract_multiple_times_floors_at_zero() {
     let result: RetractResponse = response.json();
     assert_eq!(result.new_weight, Some(0));
 }
+
+// =============================================================================
+// BATCH LOOKUP TESTS
+// =============================================================================
+
+#[tokio::test]
+async fn test_lookup_batch_preserves_order() {
+    let (server, _guard) = create_populated_test_server();
+
+    let request = BatchLookupRequest {
+        entity_ids: vec![2, 99, 1],
+        namespace: None,
+    };
+    let response = server.post("/lookup/batch").json(&request).await;
+
+    response.assert_status_ok();
+    let result: BatchLookupResponse = response.json();
+    assert!(result.success);
+    assert_eq!(result.node_ids.len(), 3);
+    assert!(result.node_ids[0].is_some());
+    assert_eq!(result.node_ids[1], None);
+    assert!(result.node_ids[2].is_some());
+    assert_ne!(result.node_ids[0], result.node_ids[2]);
+}
+
+#[tokio::test]
+async fn test_lookup_batch_empty() {
+    let (server, _guard) = create_test_server();
+
+    let response = server
+        .post("/lookup/batch")
+        .json(&json!({"entity_ids": []}))
+        .await;
+
+    response.assert_status_ok();
+    let result: BatchLookupResponse = response.json();
+    assert!(result.success);
+    assert!(result.node_ids.is_empty());
+}
+
+// =============================================================================
+// BINARY QUERY PROTOCOL TESTS
+// =============================================================================
+
+#[tokio::test]
+async fn test_binary_query_matches_json() {
+    let (server, _guard) = create_chain_test_server();
+    let lookup = QueryRequest::Lookup {
+        entity_id: 0,
+        namespace: None,
+    };
+    let node_id = server
+        .post("/query")
+        .json(&lookup)
+        .await
+        .json::<QueryResponse>()
+        .path[0];
+
+    let body = BinaryQueryBody {
+        request: BinaryQueryRequest::Traverse {
+            node_id,
+            depth: 3,
+            strategy: TraversalStrategy::Bfs,
+        },
+        deadline_ms: None,
+    };
+    let response = server
+        .post("/query")
+        .content_type(POSTCARD_CONTENT_TYPE)
+        .bytes(postcard::to_allocvec(&body).unwrap().into())
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.header("content-type"), POSTCARD_CONTENT_TYPE);
+    let binary: BinaryQueryResponse = postcard::from_bytes(response.as_bytes()).unwrap();
+
+    let json: QueryResponse = server
+        .post("/query")
+        .json(&QueryRequest::Traverse {
+            node_id,
+            depth: 3,
+            strategy: TraversalStrategy::Bfs,
+            limit: None,
+            offset: None,
+        })
+        .await
+        .json();
+
+    assert_eq!(binary.path.len(), 4);
+    assert_eq!(binary, BinaryQueryResponse::from(json));
+}
+
+#[tokio::test]
+async fn test_binary_query_rejects_malformed_body() {
+    let (server, _guard) = create_test_server();
+
+    let response = server
+        .post("/query")
+        .content_type(POSTCARD_CONTENT_TYPE)
+        .bytes(vec![0xff, 0xff, 0xff].into())
+        .await;
+    response.assert_status_bad_request();
+    let result: BinaryQueryResponse = postcard::from_bytes(response.as_bytes()).unwrap();
+    assert!(!result.success);
+}
+
+// =============================================================================
+// QUERY CACHE TESTS
+// =============================================================================
+
+#[tokio::test]
+async fn test_query_cache_serves_repeat_and_ingest_busts_it() {
+    use kremis_core::{Attribute, EntityId, Signal, Value};
+
+    let guard = AUTH_TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+    // SAFETY: Tests run sequentially under AUTH_TEST_MUTEX, so no concurrent env access.
+    unsafe { std::env::remove_var("KREMIS_API_KEY") };
+    let _guard = TestGuard { _guard: guard };
+
+    let state = AppState::new(Session::new());
+    let server = TestServer::new(create_router(state.clone())).unwrap();
+    let request = QueryRequest::Lookup {
+        entity_id: 7,
+        namespace: None,
+    };
+
+    let first: QueryResponse = server.post("/query").json(&request).await.json();
+    assert!(!first.found);
+    assert_eq!(state.query_cache.lock().await.len(), 1);
+
+    // Mutate the session behind the API's back: the cached miss is still served.
+    state
+        .session
+        .write()
+        .await
+        .ingest(&Signal::new(
+            EntityId(7),
+            Attribute::new("name"),
+            Value::new("hidden"),
+        ))
+        .unwrap();
+    let cached: QueryResponse = server.post("/query").json(&request).await.json();
+    assert!(!cached.found);
+
+    // Ingest through the API invalidates the cache.
+    let ingest = IngestRequest {
+        entity_id: 8,
+        attribute: "name".to_string(),
+        value: "visible".to_string(),
+        namespace: None,
+        source: None,
+    };
+    server
+        .post("/signal")
+        .json(&ingest)
+        .await
+        .assert_status_ok();
+    assert!(state.query_cache.lock().await.is_empty());
+
+    let fresh: QueryResponse = server.post("/query").json(&request).await.json();
+    assert!(fresh.found);
+}
+
+// =============================================================================
+// QUERY DEADLINE TESTS
+// =============================================================================
+
+/// Server over a 200-node chain, deep enough for any deadline check to fire.
+fn create_chain_test_server() -> (TestServer, TestGuard) {
+    use kremis_core::{EdgeWeight, EntityId, Graph, GraphStore};
+
+    let guard = AUTH_TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+    // SAFETY: Tests run sequentially under AUTH_TEST_MUTEX, so no concurrent env access.
+    unsafe { std::env::remove_var("KREMIS_API_KEY") };
+
+    let mut graph = Graph::new();
+    let entities: Vec<EntityId> = (0..200).map(EntityId).collect();
+    let nodes = graph.insert_nodes(&entities);
+    for pair in nodes.windows(2) {
+        graph
+            .insert_edge(pair[0], pair[1], EdgeWeight::new(1))
+            .unwrap();
+    }
+
+    let router = create_router(AppState::new(Session::with_graph(graph)));
+    (
+        TestServer::new(router).unwrap(),
+        TestGuard { _guard: guard },
+    )
+}
+
+#[tokio::test]
+async fn test_query_deadline_returns_partial_result() {
+    let (server, _guard) = create_chain_test_server();
+
+    let response = server
+        .post("/query")
+        .json(&json!({"type": "traverse", "node_id": 0, "depth": 100, "deadline_ms": 0}))
+        .await;
+
+    response.assert_status_ok();
+    let result: QueryResponse = response.json();
+    assert!(result.success);
+    assert!(result.timed_out);
+    assert_eq!(result.path, vec![0]);
+    assert_eq!(result.diagnostic.as_deref(), Some("deadline_exceeded"));
+}
+
+#[tokio::test]
+async fn test_query_deadline_interrupts_dfs_and_strongest_path() {
+    let (server, _guard) = create_chain_test_server();
+
+    let body = json!({
+        "type": "traverse",
+        "node_id": 0,
+        "depth": 100,
+        "strategy": "dfs",
+        "deadline_ms": 0
+    });
+    let result: QueryResponse = server.post("/query").json(&body).await.json();
+    assert!(result.timed_out);
+    assert_eq!(result.path, vec![0]);
+    assert_eq!(result.diagnostic.as_deref(), Some("deadline_exceeded"));
+
+    let body = json!({"type": "strongest_path", "start": 0, "end": 199, "deadline_ms": 0});
+    let result: QueryResponse = server.post("/query").json(&body).await.json();
+    assert!(result.timed_out);
+    assert!(!result.found);
+    assert_eq!(result.diagnostic.as_deref(), Some("deadline_exceeded"));
+
+    // A generous deadline finds the whole path
+    let body = json!({"type": "strongest_path", "start": 0, "end": 199, "deadline_ms": 60_000});
+    let result: QueryResponse = server.post("/query").json(&body).await.json();
+    assert!(!result.timed_out);
+    assert_eq!(result.path.len(), 200);
+}
+
+#[tokio::test]
+async fn test_query_generous_deadline_completes() {
+    let (server, _guard) = create_chain_test_server();
+
+    let body = json!({
+        "type": "traverse_filtered",
+        "node_id": 0,
+        "depth": 50,
+        "min_weight": 1,
+        "deadline_ms": 60_000
+    });
+    let result: QueryResponse = server.post("/query").json(&body).await.json();
+    assert!(!result.timed_out);
+    assert_eq!(result.path.len(), 51);
+
+    // Timed-out responses are not cached, so a later query still completes
+    let body = json!({"type": "related", "node_id": 0, "depth": 5, "deadline_ms": 0});
+    let partial: QueryResponse = server.post("/query").json(&body).await.json();
+    assert!(partial.timed_out);
+    let body = json!({"type": "related", "node_id": 0, "depth": 5});
+    let full: QueryResponse = server.post("/query").json(&body).await.json();
+    assert!(!full.timed_out);
+    assert_eq!(full.path.len(), 6);
+}
+
+// =============================================================================
+// NAMESPACE TESTS
+// =============================================================================
+
+#[tokio::test]
+async fn test_namespaces_keep_entities_apart() {
+    let (server, _guard) = create_test_server();
+
+    let mut node_ids = Vec::new();
+    for namespace in [1u16, 2] {
+        let request = IngestRequest {
+            entity_id: 5,
+            attribute: "source".to_string(),
+            value: format!("ns{namespace}"),
+            namespace: Some(namespace),
+            source: None,
+        };
+        let result: IngestResponse = server.post("/signal").json(&request).await.json();
+        node_ids.push(result.node_id.unwrap());
+    }
+    assert_ne!(node_ids[0], node_ids[1]);
+
+    let lookup = |namespace| QueryRequest::Lookup {
+        entity_id: 5,
+        namespace,
+    };
+    let ns2: QueryResponse = server.post("/query").json(&lookup(Some(2))).await.json();
+    assert_eq!(ns2.path, vec![node_ids[1]]);
+
+    let bare: QueryResponse = server.post("/query").json(&lookup(None)).await.json();
+    assert!(!bare.found);
+}
+
+#[tokio::test]
+async fn test_namespaced_raw_id_out_of_range_is_rejected() {
+    let (server, _guard) = create_test_server();
+    // 2^48 + 5 would otherwise truncate onto raw id 5
+    let too_large = (1u64 << 48) + 5;
+
+    let in_range = IngestRequest {
+        entity_id: 5,
+        attribute: "name".to_string(),
+        value: "five".to_string(),
+        namespace: Some(1),
+        source: None,
+    };
+    server
+        .post("/signal")
+        .json(&in_range)
+        .await
+        .assert_status_ok();
+    let response = server
+        .post("/signal")
+        .json(&IngestRequest {
+            entity_id: too_large,
+            ..in_range
+        })
+        .await;
+    response.assert_status_bad_request();
+
+    let response = server
+        .post("/query")
+        .json(&QueryRequest::Lookup {
+            entity_id: too_large,
+            namespace: Some(1),
+        })
+        .await;
+    response.assert_status_bad_request();
+
+    let response = server
+        .post("/lookup/batch")
+        .json(&BatchLookupRequest {
+            entity_ids: vec![5, too_large],
+            namespace: Some(1),
+        })
+        .await;
+    response.assert_status_bad_request();
+    let result: BatchLookupResponse = response.json();
+    assert!(!result.success);
+    assert!(
+        result
+            .error
+            .is_some_and(|e| e.starts_with("Validation error:"))
+    );
+
+    let response = server
+        .put("/edge")
+        .json(&PutEdgeRequest {
+            from_entity: 5,
+            to_entity: too_large,
+            weight: 1,
+            namespace: Some(1),
+        })
+        .await;
+    response.assert_status_bad_request();
+}
+
+#[tokio::test]
+async fn test_edge_endpoints_resolve_namespaces() {
+    let (server, _guard) = create_test_server();
+    let namespaced = |raw: u64| (1u64 << 48) | raw;
+
+    // The same raw ids exist in two namespaces; only namespace 1 is touched
+    for namespace in [None, Some(1)] {
+        server
+            .put("/edge")
+            .json(&PutEdgeRequest {
+                from_entity: 1,
+                to_entity: 2,
+                weight: 5,
+                namespace,
+            })
+            .await
+            .assert_status_ok();
+    }
+
+    let adjusted: AdjustEdgeResponse = server
+        .patch("/edge")
+        .json(&AdjustEdgeRequest {
+            from_entity: 1,
+            to_entity: 2,
+            delta: 2,
+            namespace: Some(1),
+            source: Some("crawler".to_string()),
+        })
+        .await
+        .json();
+    assert_eq!(adjusted.new_weight, Some(7));
+
+    let retracted: RetractResponse = server
+        .post("/signal/retract")
+        .json(&RetractRequest {
+            from_entity: 1,
+            to_entity: 2,
+            namespace: Some(1),
+        })
+        .await
+        .json();
+    assert_eq!(retracted.new_weight, Some(6));
+
+    let provenance: ProvenanceResponse = server
+        .get("/edge/provenance?from_entity=1&to_entity=2&namespace=1")
+        .await
+        .json();
+    assert_eq!(provenance.sources.len(), 1);
+
+    let top: TopRelationshipsResponse = server.get("/entity/1/top?namespace=1").await.json();
+    assert_eq!(
+        top.neighbors,
+        vec![EntityWeightJson {
+            entity_id: namespaced(2),
+            weight: 6,
+        }]
+    );
+    let top: TopRelationshipsResponse = server.get("/entity/1/top").await.json();
+    assert_eq!(top.neighbors[0].weight, 5);
+}
+
+// =============================================================================
+// EDGE ADJUST TESTS
+// =============================================================================
+
+#[tokio::test]
+async fn test_adjust_edge_positive_and_negative_delta() {
+    use kremis_core::{Attribute, EntityId, Signal, Value};
+
+    let guard = AUTH_TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+    unsafe { std::env::remove_var("KREMIS_API_KEY") };
+
+    let mut session = Session::new();
+    let signals = vec![
+        Signal::new(EntityId(1), Attribute::new("type"), Value::new("word")),
+        Signal::new(EntityId(2), Attribute::new("type"), Value::new("word")),
+    ];
+    session.ingest_sequence(&signals).unwrap();
+
+    let server = TestServer::new(create_router(AppState::new(session))).unwrap();
+    let _guard = TestGuard { _guard: guard };
+
+    let adjust = |delta| AdjustEdgeRequest {
+        from_entity: 1,
+        to_entity: 2,
+        delta,
+        namespace: None,
+        source: None,
+    };
+
+    let response = server.patch("/edge").json(&adjust(4)).await;
+    response.assert_status_ok();
+    let result: AdjustEdgeResponse = response.json();
+    assert_eq!(result.new_weight, Some(5));
+    assert!(!result.removed);
+
+    let result: AdjustEdgeResponse = server.patch("/edge").json(&adjust(-10)).await.json();
+    assert!(result.success);
+    assert_eq!(result.new_weight, Some(0));
+    assert!(result.removed);
+
+    // Edge is gone now
+    let response = server.patch("/edge").json(&adjust(1)).await;
+    response.assert_status(axum::http::StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_adjust_edge_unknown_entity_returns_404() {
+    let (server, _guard) = create_test_server();
+
+    let request = AdjustEdgeRequest {
+        from_entity: 1,
+        to_entity: 2,
+        delta: 1,
+        namespace: None,
+        source: None,
+    };
+    let response = server.patch("/edge").json(&request).await;
+
+    response.assert_status(axum::http::StatusCode::NOT_FOUND);
+    let result: AdjustEdgeResponse = response.json();
+    assert!(!result.success);
+}
+
+#[tokio::test]
+async fn test_edge_provenance_accumulates_sources() {
+    use kremis_core::{Attribute, EntityId, Signal, Value};
+
+    let guard = AUTH_TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+    unsafe { std::env::remove_var("KREMIS_API_KEY") };
+
+    let mut session = Session::new();
+    let signals = vec![
+        Signal::new(EntityId(1), Attribute::new("type"), Value::new("word")),
+        Signal::new(EntityId(2), Attribute::new("type"), Value::new("word")),
+    ];
+    session.ingest_sequence(&signals).unwrap();
+
+    let server = TestServer::new(create_router(AppState::new(session))).unwrap();
+    let _guard = TestGuard { _guard: guard };
+
+    let reinforce = |delta, source: Option<&str>| AdjustEdgeRequest {
+        from_entity: 1,
+        to_entity: 2,
+        delta,
+        namespace: None,
+        source: source.map(str::to_string),
+    };
+    for request in [
+        reinforce(1, Some("crawler")),
+        reinforce(1, Some("importer")),
+        reinforce(2, Some("crawler")),
+        reinforce(1, None),
+        // Weakening is not a contribution
+        reinforce(-1, Some("auditor")),
+    ] {
+        server
+            .patch("/edge")
+            .json(&request)
+            .await
+            .assert_status_ok();
+    }
+
+    let response = server
+        .get("/edge/provenance")
+        .add_query_param("from_entity", 1)
+        .add_query_param("to_entity", 2)
+        .await;
+    response.assert_status_ok();
+    let result: ProvenanceResponse = response.json();
+    assert_eq!(
+        result.sources,
+        vec![
+            SourceCountJson {
+                source: "crawler".to_string(),
+                reinforcements: 2,
+            },
+            SourceCountJson {
+                source: "importer".to_string(),
+                reinforcements: 1,
+            },
+        ]
+    );
+
+    // Removing the edge drops its provenance
+    server
+        .patch("/edge")
+        .json(&reinforce(-100, None))
+        .await
+        .assert_status_ok();
+    server
+        .get("/edge/provenance")
+        .add_query_param("from_entity", 1)
+        .add_query_param("to_entity", 2)
+        .await
+        .assert_status(axum::http::StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_ingest_batch_records_signal_sources() {
+    let (server, _guard) = create_test_server();
+
+    let signal = |entity_id: u64, source: Option<&str>| json!({"entity_id": entity_id, "attribute": "type", "value": "word", "source": source});
+    for batch in [
+        json!([signal(1, Some("crawler")), signal(2, Some("crawler"))]),
+        json!([signal(1, Some("importer")), signal(2, Some("importer"))]),
+        json!([signal(1, None), signal(2, Some("crawler"))]),
+        // Unsourced reinforcement is not attributed
+        json!([signal(1, Some("crawler")), signal(2, None)]),
+    ] {
+        server
+            .post("/signals")
+            .json(&batch)
+            .await
+            .assert_status_ok();
+    }
+
+    let response = server
+        .get("/edge/provenance")
+        .add_query_param("from_entity", 1)
+        .add_query_param("to_entity", 2)
+        .await;
+    response.assert_status_ok();
+    let result: ProvenanceResponse = response.json();
+    assert_eq!(
+        result.sources,
+        vec![
+            SourceCountJson {
+                source: "crawler".to_string(),
+                reinforcements: 2,
+            },
+            SourceCountJson {
+                source: "importer".to_string(),
+                reinforcements: 1,
+            },
+        ]
+    );
+}
+
+// =============================================================================
+// EXPLAIN TESTS
+// =============================================================================
+
+#[tokio::test]
+async fn test_explain_lists_path_edges_as_facts() {
+    use kremis_core::{HonestResponse, NodeId};
+
+    let (server, _guard) = create_chain_test_server();
+    let ids = server
+        .post("/lookup/batch")
+        .json(&json!({"entity_ids": [0, 3]}))
+        .await
+        .json::<BatchLookupResponse>()
+        .node_ids;
+    let (start, end) = (ids[0].unwrap(), ids[1].unwrap());
+
+    let response = server
+        .get("/explain")
+        .add_query_param("start", start)
+        .add_query_param("end", end)
+        .await;
+    response.assert_status_ok();
+    let explained: HonestResponse = response.json();
+
+    assert_eq!(explained.facts.len(), 3);
+    assert!(explained.unknowns.is_empty());
+    assert_eq!(explained.facts[0].evidence_path[0], NodeId(start));
+    assert_eq!(explained.facts[2].evidence_path[1], NodeId(end));
+    for pair in explained.facts.windows(2) {
+        assert_eq!(pair[0].evidence_path[1], pair[1].evidence_path[0]);
+    }
+
+    // Unreachable pair: nothing proven, one unknown
+    let explained: HonestResponse = server
+        .get("/explain")
+        .add_query_param("start", end)
+        .add_query_param("end", start)
+        .await
+        .json();
+    assert!(explained.facts.is_empty());
+    assert_eq!(explained.unknowns.len(), 1);
+}
+
+#[tokio::test]
+async fn test_post_edges_filters_by_weight_and_paginates() {
+    let (server, _guard) = create_test_server();
+    for (to_entity, weight) in [(2, 4), (3, 9), (4, 12), (5, 15)] {
+        let edge = EntityEdgeJson {
+            from_entity: 1,
+            to_entity,
+            weight,
+        };
+        server.put("/edge").json(&edge).await.assert_status_ok();
+    }
+
+    let page: EdgePageResponse = server
+        .post("/edges")
+        .json(&json!({"min_weight": 9, "limit": 2}))
+        .await
+        .json();
+    assert_eq!(page.total, 3);
+    assert_eq!(page.next_offset, Some(2));
+    let weights: Vec<i64> = page.edges.iter().map(|e| e.weight).collect();
+    assert_eq!(weights, vec![9, 12]);
+
+    let page: EdgePageResponse = server
+        .post("/edges")
+        .json(&json!({"min_weight": 9, "offset": 2, "limit": 2}))
+        .await
+        .json();
+    assert_eq!(page.edges.len(), 1);
+    assert_eq!(page.edges[0].weight, 15);
+    assert_eq!(page.next_offset, None);
+}
+
+#[tokio::test]
+async fn test_fingerprint_changes_with_graph() {
+    let (server, _guard) = create_test_server();
+    let empty: FingerprintResponse = server.get("/fingerprint").await.json();
+    assert!(empty.success);
+
+    let edge = EntityEdgeJson {
+        from_entity: 1,
+        to_entity: 2,
+        weight: 3,
+    };
+    server.put("/edge").json(&edge).await.assert_status_ok();
+    let first: FingerprintResponse = server.get("/fingerprint").await.json();
+    let again: FingerprintResponse = server.get("/fingerprint").await.json();
+    assert_ne!(first.fingerprint, empty.fingerprint);
+    assert_eq!(first.fingerprint, again.fingerprint);
+}
+
+#[tokio::test]
+async fn test_top_relationships_ranks_by_weight() {
+    let (server, _guard) = create_test_server();
+    for (to_entity, weight) in [(2, 4), (3, 9), (4, 1)] {
+        let edge = EntityEdgeJson {
+            from_entity: 1,
+            to_entity,
+            weight,
+        };
+        server.put("/edge").json(&edge).await.assert_status_ok();
+    }
+
+    let response = server.get("/entity/1/top").add_query_param("k", 2).await;
+    response.assert_status_ok();
+    let top: TopRelationshipsResponse = response.json();
+    assert!(top.success);
+    assert_eq!(
+        top.neighbors,
+        vec![
+            EntityWeightJson {
+                entity_id: 3,
+                weight: 9
+            },
+            EntityWeightJson {
+                entity_id: 2,
+                weight: 4
+            },
+        ]
+    );
+
+    let response = server.get("/entity/99/top").await;
+    response.assert_status(axum::http::StatusCode::NOT_FOUND);
+}
+
+// =============================================================================
+// BATCH INGEST TESTS
+// =============================================================================
+
+#[tokio::test]
+async fn test_ingest_batch_reports_per_signal_results() {
+    let (server, _guard) = create_test_server();
+
+    let batch = json!([
+        {"entity_id": 1, "attribute": "name", "value": "Alice"},
+        {"entity_id": 2, "attribute": "", "value": "invalid"},
+        {"entity_id": 3, "attribute": "name", "value": "Carol"}
+    ]);
+    let response = server.post("/signals").json(&batch).await;
+
+    response.assert_status_ok();
+    let results: Vec<IngestResponse> = response.json();
+    let success: Vec<bool> = results.iter().map(|r| r.success).collect();
+    assert_eq!(success, vec![true, false, true]);
+}
+
+#[tokio::test]
+async fn test_ingest_batch_idempotency_key_replays() {
+    let (server, _guard) = create_test_server();
+
+    let batch = json!([{"entity_id": 1, "attribute": "name", "value": "Alice"}]);
+    let key = HeaderValue::from_static("batch-1");
+
+    let first: Vec<IngestResponse> = server
+        .post("/signals")
+        .add_header("idempotency-key", key.clone())
+        .json(&batch)
+        .await
+        .json();
+    let replay: Vec<IngestResponse> = server
+        .post("/signals")
+        .add_header("idempotency-key", key)
+        .json(&batch)
+        .await
+        .json();
+    assert_eq!(first[0].node_id, replay[0].node_id);
+
+    // The replay must not have stored the property a second time
+    let props: QueryResponse = server
+        .post("/query")
+        .json(&json!({"type": "properties", "node_id": first[0].node_id}))
+        .await
+        .json();
+    assert_eq!(props.properties.len(), 1);
+}
+
+#[tokio::test]
+async fn test_ingest_idempotency_key_replays() {
+    let (server, _guard) = create_test_server();
+
+    let signal = json!({"entity_id": 1, "attribute": "name", "value": "Alice"});
+    let key = HeaderValue::from_static("signal-1");
+
+    let first: IngestResponse = server
+        .post("/signal")
+        .add_header("idempotency-key", key.clone())
+        .json(&signal)
+        .await
+        .json();
+    let replay: IngestResponse = server
+        .post("/signal")
+        .add_header("idempotency-key", key.clone())
+        .json(&signal)
+        .await
+        .json();
+    assert!(first.success);
+    assert_eq!(first.node_id, replay.node_id);
+
+    let props: QueryResponse = server
+        .post("/query")
+        .json(&json!({"type": "properties", "node_id": first.node_id}))
+        .await
+        .json();
+    assert_eq!(props.properties.len(), 1);
+
+    // The same key on /signals is a different request, not a replay
+    let batch: Vec<IngestResponse> = server
+        .post("/signals")
+        .add_header("idempotency-key", key)
+        .json(&json!([{"entity_id": 2, "attribute": "name", "value": "Bob"}]))
+        .await
+        .json();
+    assert_eq!(batch.len(), 1);
+    assert_ne!(batch[0].node_id, first.node_id);
+}
+
+// =============================================================================
+// ENTITY EDGE TESTS
+// =============================================================================
+
+#[tokio::test]
+async fn test_put_edge_then_list_edges() {
+    let (server, _guard) = create_test_server();
+
+    let edge = EntityEdgeJson {
+        from_entity: 10,
+        to_entity: 20,
+        weight: 3,
+    };
+    let response = server.put("/edge").json(&edge).await;
+    response.assert_status_ok();
+
+    let listing: EdgeListResponse = server.get("/edges").await.json();
+    assert!(listing.success);
+    assert_eq!(listing.edges, vec![edge]);
+}
+
+#[tokio::test]
+async fn test_put_edge_rejects_non_positive_weight() {
+    let (server, _guard) = create_test_server();
+
+    let edge = EntityEdgeJson {
+        from_entity: 1,
+        to_entity: 2,
+        weight: 0,
+    };
+    let response = server.put("/edge").json(&edge).await;
+    response.assert_status(axum::http::StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_bulk_edges_create_and_reinforce() {
+    let (server, _guard) = create_test_server();
+
+    let body = json!([
+        {"from_entity": 1, "to_entity": 2, "weight": 2},
+        {"from_entity": 2, "to_entity": 3, "weight": -1},
+        {"from_entity": 1, "to_entity": 2, "weight": 5}
+    ]);
+    let response = server.post("/edges/bulk").json(&body).await;
+    response.assert_status_ok();
+    let result: BulkEdgesResponse = response.json();
+    assert!(result.success);
+    assert_eq!(result.applied, 2);
+
+    let listing: EdgeListResponse = server.get("/edges").await.json();
+    assert_eq!(
+        listing.edges,
+        vec![EntityEdgeJson {
+            from_entity: 1,
+            to_entity: 2,
+            weight: 7,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_bulk_put_edges_sets_weights() {
+    let (server, _guard) = create_test_server();
+
+    let edge = |from_entity, to_entity, weight| EntityEdgeJson {
+        from_entity,
+        to_entity,
+        weight,
+    };
+    server
+        .post("/edges/bulk")
+        .json(&vec![edge(1, 2, 9)])
+        .await
+        .assert_status_ok();
+
+    // Overwrites rather than adds, and creates missing edges
+    let response = server
+        .put("/edges/bulk")
+        .json(&vec![edge(1, 2, 3), edge(2, 3, 4)])
+        .await;
+    response.assert_status_ok();
+    let result: BulkEdgesResponse = response.json();
+    assert_eq!(result.applied, 2);
+    let listing: EdgeListResponse = server.get("/edges").await.json();
+    assert_eq!(listing.edges, vec![edge(1, 2, 3), edge(2, 3, 4)]);
+
+    // A non-positive weight rejects the whole batch
+    let response = server
+        .put("/edges/bulk")
+        .json(&vec![edge(1, 2, 8), edge(2, 3, 0)])
+        .await;
+    response.assert_status(axum::http::StatusCode::BAD_REQUEST);
+    let listing: EdgeListResponse = server.get("/edges").await.json();
+    assert_eq!(listing.edges, vec![edge(1, 2, 3), edge(2, 3, 4)]);
+}
+
+#[tokio::test]
+async fn test_query_stream_matches_traverse() {
+    let (server, _guard) = create_chain_test_server();
+    let query = json!({"type": "traverse", "node_id": 0, "depth": 50});
+
+    let buffered: QueryResponse = server.post("/query").json(&query).await.json();
+    let response = server.post("/query/stream").json(&query).await;
+    response.assert_status_ok();
+    assert_eq!(response.header("content-type"), "application/x-ndjson");
+
+    let streamed: Vec<EdgeJson> = response
+        .text()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(streamed.len(), 50);
+    assert_eq!(streamed, buffered.edges);
+}
+
+#[tokio::test]
+async fn test_query_stream_rejects_unsupported_and_missing() {
+    let (server, _guard) = create_chain_test_server();
+
+    let response = server
+        .post("/query/stream")
+        .json(&json!({"type": "lookup", "entity_id": 1}))
+        .await;
+    response.assert_status_bad_request();
+
+    let response = server
+        .post("/query/stream")
+        .json(&json!({"type": "traverse", "node_id": 9999, "depth": 2}))
+        .await;
+    response.assert_status_not_found();
+}
+
+#[tokio::test]
+async fn test_ingest_reports_stability_confidence() {
+    let (server, _guard) = create_test_server();
+    let signal = |entity_id| json!({"entity_id": entity_id, "attribute": "name", "value": "x"});
+
+    // No edges yet: nothing to report
+    let result: IngestResponse = server.post("/signal").json(&signal(1)).await.json();
+    assert_eq!(result.stability_confidence, None);
+
+    for (to_entity, weight) in [(2, 1), (3, 40)] {
+        server
+            .put("/edge")
+            .json(&EntityEdgeJson {
+                from_entity: 10 + to_entity,
+                to_entity,
+                weight,
+            })
+            .await
+            .assert_status_ok();
+    }
+
+    let fresh: IngestResponse = server.post("/signal").json(&signal(2)).await.json();
+    assert_eq!(fresh.stability_confidence, Some(0));
+    let reinforced: IngestResponse = server.post("/signal").json(&signal(3)).await.json();
+    assert_eq!(reinforced.stability_confidence, Some(100));
+
+    let batch: Vec<IngestResponse> = server
+        .post("/signals")
+        .json(&json!([signal(2), signal(3)]))
+        .await
+        .json();
+    assert_eq!(batch[0].stability_confidence, Some(0));
+    assert_eq!(batch[1].stability_confidence, Some(100));
+}
@@ -6,10 +6,10 @@
 #![allow(clippy::unwrap_used, clippy::panic)]
 
 use kremis::cli::{
-    cmd_export, cmd_import, cmd_ingest, cmd_init, cmd_query, cmd_stage, cmd_status,
-    load_or_create_session, save_session,
+    cmd_export, cmd_import, cmd_ingest, cmd_init, cmd_merge, cmd_query, cmd_replay, cmd_stage,
+    cmd_status, load_or_create_session, save_session,
 };
-use kremis_core::{Attribute, EntityId, Session, Signal, Value};
+use kremis_core::{Attribute, EdgeWeight, EntityId, Session, Signal, Value};
 use std::path::PathBuf;
 use tempfile::TempDir;
 
@@ -175,7 +175,7 @@ fn test_stage_empty_graph() {
     let db_path = temp.path().join("test.db");
     cmd_init(&db_path, "file", false).unwrap();
 
-    let result = cmd_stage(&db_path, "file", false, false);
+    let result = cmd_stage(&db_path, "file", false, false, false);
     assert!(result.is_ok());
 }
 
@@ -185,17 +185,53 @@ fn test_stage_json_mode() {
     let db_path = temp.path().join("test.db");
     cmd_init(&db_path, "file", false).unwrap();
 
-    let result = cmd_stage(&db_path, "file", true, false);
+    let result = cmd_stage(&db_path, "file", true, false, false);
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_stage_bar_shows_fill_ratio() {
+    use kremis_core::GraphStore;
+
+    let temp = create_temp_dir();
+    let db_path = temp.path().join("test.db");
+
+    // 25 of the 100 stable edges needed for S1
+    let mut session = Session::new();
+    let graph = session.graph_mut().unwrap();
+    let entities: Vec<_> = (0..26).map(EntityId).collect();
+    let ids = graph.insert_nodes(&entities);
+    for pair in ids.windows(2) {
+        graph
+            .insert_edge(pair[0], pair[1], EdgeWeight::new(10))
+            .unwrap();
+    }
+    save_session(&session, &db_path).unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_kremis"))
+        .args(["-q", "-B", "file", "-D"])
+        .arg(&db_path)
+        .args(["stage", "--bar"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let bar = stdout
+        .lines()
+        .find(|line| line.starts_with('['))
+        .expect("bar line");
+    assert_eq!(bar.matches('#').count(), 5);
+    assert_eq!(bar.matches('\u{b7}').count(), 15);
+}
+
 #[test]
 fn test_stage_detailed_mode() {
     let temp = create_temp_dir();
     let db_path = temp.path().join("test.db");
     cmd_init(&db_path, "file", false).unwrap();
 
-    let result = cmd_stage(&db_path, "file", false, true);
+    let result = cmd_stage(&db_path, "file", false, true, false);
     assert!(result.is_ok());
 }
 
@@ -210,7 +246,7 @@ fn test_ingest_json_format() {
     let signals_file = create_signals_json(&temp);
 
     cmd_init(&db_path, "file", false).unwrap();
-    let result = cmd_ingest(&db_path, "file", false, &signals_file, "json");
+    let result = cmd_ingest(&db_path, "file", false, &signals_file, "json", None);
     assert!(result.is_ok());
 
     // Verify data was ingested
@@ -218,6 +254,57 @@ fn test_ingest_json_format() {
     assert!(session.node_count() > 0);
 }
 
+#[test]
+fn test_ingest_auto_spill_switches_to_redb() {
+    let temp = create_temp_dir();
+    let db_path = temp.path().join("test.db");
+    let signals_path = temp.path().join("signals.json");
+    // 700 signals over 400 entities: spills after the first chunk and
+    // revisits early entities after the switch
+    let signals: Vec<Signal> = (0..700u64)
+        .map(|i| {
+            Signal::new(
+                EntityId(i % 400),
+                Attribute::new("seen"),
+                Value::new(i.to_string()),
+            )
+        })
+        .collect();
+    let json: Vec<serde_json::Value> = signals
+        .iter()
+        .map(|s| {
+            serde_json::json!({
+                "entity_id": s.entity.0,
+                "attribute": "seen",
+                "value": s.value.as_str(),
+            })
+        })
+        .collect();
+    std::fs::write(&signals_path, serde_json::to_vec(&json).unwrap()).unwrap();
+
+    cmd_init(&db_path, "file", false).unwrap();
+    cmd_ingest(&db_path, "file", false, &signals_path, "json", Some(100)).unwrap();
+
+    let mut expected = Session::new();
+    expected.ingest_sequence(&signals).unwrap();
+    let expected = expected.export_graph_snapshot().unwrap();
+
+    assert!(load_or_create_session(&db_path, "file").is_err());
+    assert!(!db_path.with_extension("spill.redb").exists());
+    let session = Session::with_redb(&db_path).unwrap();
+    assert!(session.is_persistent());
+    let spilled = session.export_graph_snapshot().unwrap();
+    assert_eq!(session.node_count(), 400);
+    assert_eq!(
+        spilled.edges().collect::<Vec<_>>(),
+        expected.edges().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        session.attribute_value(EntityId(5), &Attribute::new("seen")),
+        Some(Value::new("405"))
+    );
+}
+
 #[test]
 fn test_ingest_text_format() {
     let temp = create_temp_dir();
@@ -225,7 +312,7 @@ fn test_ingest_text_format() {
     let signals_file = create_signals_text(&temp);
 
     cmd_init(&db_path, "file", false).unwrap();
-    let result = cmd_ingest(&db_path, "file", false, &signals_file, "text");
+    let result = cmd_ingest(&db_path, "file", false, &signals_file, "text", None);
     assert!(result.is_ok());
 
     // Verify data was ingested
@@ -240,7 +327,7 @@ fn test_ingest_invalid_format() {
     let signals_file = create_signals_json(&temp);
 
     cmd_init(&db_path, "file", false).unwrap();
-    let result = cmd_ingest(&db_path, "file", false, &signals_file, "unknown");
+    let result = cmd_ingest(&db_path, "file", false, &signals_file, "unknown", None);
     assert!(result.is_err());
 }
 
@@ -252,7 +339,7 @@ fn test_ingest_invalid_json() {
     std::fs::write(&bad_file, "not valid json").unwrap();
 
     cmd_init(&db_path, "file", false).unwrap();
-    let result = cmd_ingest(&db_path, "file", false, &bad_file, "json");
+    let result = cmd_ingest(&db_path, "file", false, &bad_file, "json", None);
     assert!(result.is_err());
 }
 
@@ -289,7 +376,7 @@ fn test_query_lookup_found() {
     let signals_file = create_signals_json(&temp);
 
     cmd_init(&db_path, "file", false).unwrap();
-    cmd_ingest(&db_path, "file", false, &signals_file, "json").unwrap();
+    cmd_ingest(&db_path, "file", false, &signals_file, "json", None).unwrap();
 
     let result = cmd_query(
         &db_path,
@@ -313,7 +400,7 @@ fn test_query_traverse() {
     let signals_file = create_signals_json(&temp);
 
     cmd_init(&db_path, "file", false).unwrap();
-    cmd_ingest(&db_path, "file", false, &signals_file, "json").unwrap();
+    cmd_ingest(&db_path, "file", false, &signals_file, "json", None).unwrap();
 
     let result = cmd_query(
         &db_path,
@@ -337,7 +424,7 @@ fn test_query_traverse_filtered() {
     let signals_file = create_signals_json(&temp);
 
     cmd_init(&db_path, "file", false).unwrap();
-    cmd_ingest(&db_path, "file", false, &signals_file, "json").unwrap();
+    cmd_ingest(&db_path, "file", false, &signals_file, "json", None).unwrap();
 
     let result = cmd_query(
         &db_path,
@@ -361,7 +448,7 @@ fn test_query_path() {
     let signals_file = create_signals_json(&temp);
 
     cmd_init(&db_path, "file", false).unwrap();
-    cmd_ingest(&db_path, "file", false, &signals_file, "json").unwrap();
+    cmd_ingest(&db_path, "file", false, &signals_file, "json", None).unwrap();
 
     let result = cmd_query(
         &db_path,
@@ -385,7 +472,7 @@ fn test_query_intersect() {
     let signals_file = create_signals_json(&temp);
 
     cmd_init(&db_path, "file", false).unwrap();
-    cmd_ingest(&db_path, "file", false, &signals_file, "json").unwrap();
+    cmd_ingest(&db_path, "file", false, &signals_file, "json", None).unwrap();
 
     let result = cmd_query(
         &db_path,
@@ -436,11 +523,18 @@ fn test_export_canonical_format() {
     let output_path = temp.path().join("export.bin");
 
     cmd_init(&db_path, "file", false).unwrap();
-    cmd_ingest(&db_path, "file", false, &signals_file, "json").unwrap();
+    cmd_ingest(&db_path, "file", false, &signals_file, "json", None).unwrap();
 
-    let result = cmd_export(&db_path, "file", &output_path, "canonical");
+    let result = cmd_export(&db_path, "file", &output_path, "canonical", None);
     assert!(result.is_ok());
     assert!(output_path.exists());
+
+    let manifest: kremis_core::Manifest = serde_json::from_str(
+        &std::fs::read_to_string(temp.path().join("export.manifest.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(manifest.source_name, "test.db");
+    assert_eq!(manifest.node_count, 2);
 }
 
 #[test]
@@ -451,9 +545,9 @@ fn test_export_json_format() {
     let output_path = temp.path().join("export.json");
 
     cmd_init(&db_path, "file", false).unwrap();
-    cmd_ingest(&db_path, "file", false, &signals_file, "json").unwrap();
+    cmd_ingest(&db_path, "file", false, &signals_file, "json", None).unwrap();
 
-    let result = cmd_export(&db_path, "file", &output_path, "json");
+    let result = cmd_export(&db_path, "file", &output_path, "json", None);
     assert!(result.is_ok());
     assert!(output_path.exists());
 
@@ -462,6 +556,32 @@ fn test_export_json_format() {
     let _: serde_json::Value = serde_json::from_str(&content).unwrap();
 }
 
+#[test]
+fn test_export_ndjson_stream_one_line_per_record() {
+    use kremis_core::GraphStore;
+
+    let temp = create_temp_dir();
+    let db_path = temp.path().join("large.db");
+    let output_path = temp.path().join("export.ndjson");
+
+    let graph = kremis_core::graph::generate_random(2_000, 6_000, 7);
+    let (nodes, edges) = (graph.node_count().unwrap(), graph.edge_count().unwrap());
+    save_session(&Session::with_graph(graph), &db_path).unwrap();
+
+    cmd_export(&db_path, "file", &output_path, "ndjson-stream", None).unwrap();
+
+    let content = std::fs::read_to_string(&output_path).unwrap();
+    let lines: Vec<serde_json::Value> = content
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), nodes + edges + 1);
+    assert_eq!(lines[0]["type"], "header");
+    assert_eq!(lines[0]["node_count"], nodes);
+    assert_eq!(lines[1]["type"], "node");
+    assert_eq!(lines[lines.len() - 1]["type"], "edge");
+}
+
 #[test]
 fn test_export_unknown_format() {
     let temp = create_temp_dir();
@@ -470,10 +590,33 @@ fn test_export_unknown_format() {
 
     cmd_init(&db_path, "file", false).unwrap();
 
-    let result = cmd_export(&db_path, "file", &output_path, "unknown");
+    let result = cmd_export(&db_path, "file", &output_path, "unknown", None);
     assert!(result.is_err());
 }
 
+#[test]
+fn test_export_stable_format_filters_weak_edges() {
+    let temp = create_temp_dir();
+    let db_path = temp.path().join("test.db");
+    let signals_file = create_signals_json(&temp);
+    let output_path = temp.path().join("stable.bin");
+
+    cmd_init(&db_path, "file", false).unwrap();
+    cmd_ingest(&db_path, "file", false, &signals_file, "json", None).unwrap();
+
+    // Freshly ingested edges have weight 1, below the requested minimum
+    cmd_export(&db_path, "file", &output_path, "stable", Some(2)).unwrap();
+    let data = std::fs::read(&output_path).unwrap();
+    let graph = kremis_core::import_canonical(&data).unwrap();
+    assert_eq!(graph.edges().count(), 0);
+
+    cmd_export(&db_path, "file", &output_path, "stable", Some(1)).unwrap();
+    let data = std::fs::read(&output_path).unwrap();
+    let graph = kremis_core::import_canonical(&data).unwrap();
+    assert!(graph.edges().count() > 0);
+    assert!(graph.edges().all(|(_, _, w)| w.value() >= 1));
+}
+
 // =============================================================================
 // IMPORT COMMAND TESTS
 // =============================================================================
@@ -488,11 +631,11 @@ fn test_import_canonical() {
 
     // Create and export
     cmd_init(&db_path, "file", false).unwrap();
-    cmd_ingest(&db_path, "file", false, &signals_file, "json").unwrap();
-    cmd_export(&db_path, "file", &export_path, "canonical").unwrap();
+    cmd_ingest(&db_path, "file", false, &signals_file, "json", None).unwrap();
+    cmd_export(&db_path, "file", &export_path, "canonical", None).unwrap();
 
     // Import
-    let result = cmd_import(&import_db_path, "file", &export_path);
+    let result = cmd_import(&import_db_path, "file", &export_path, "canonical");
     assert!(result.is_ok());
 
     // Verify imported data matches
@@ -502,6 +645,29 @@ fn test_import_canonical() {
     assert_eq!(original.edge_count(), imported.edge_count());
 }
 
+#[test]
+fn test_import_msgpack_roundtrip() {
+    let temp = create_temp_dir();
+    let db_path = temp.path().join("test.db");
+    let signals_file = create_signals_json(&temp);
+    let export_path = temp.path().join("export.msgpack");
+    let import_db_path = temp.path().join("imported.db");
+
+    cmd_init(&db_path, "file", false).unwrap();
+    cmd_ingest(&db_path, "file", false, &signals_file, "json", None).unwrap();
+    cmd_export(&db_path, "file", &export_path, "msgpack", None).unwrap();
+
+    cmd_import(&import_db_path, "file", &export_path, "msgpack").unwrap();
+
+    let original = load_or_create_session(&db_path, "file").unwrap();
+    let imported = load_or_create_session(&import_db_path, "file").unwrap();
+    assert_eq!(original.node_count(), imported.node_count());
+    assert_eq!(original.edge_count(), imported.edge_count());
+
+    // A msgpack file is not a canonical export.
+    assert!(cmd_import(&import_db_path, "file", &export_path, "canonical").is_err());
+}
+
 #[test]
 fn test_import_to_redb_fails() {
     let temp = create_temp_dir();
@@ -517,10 +683,56 @@ fn test_import_to_redb_fails() {
     std::fs::write(&export_path, &data).unwrap();
 
     // Import to redb should fail (not supported)
-    let result = cmd_import(&import_db_path, "redb", &export_path);
+    let result = cmd_import(&import_db_path, "redb", &export_path, "canonical");
     assert!(result.is_err());
 }
 
+// =============================================================================
+// MERGE COMMAND TESTS
+// =============================================================================
+
+#[test]
+fn test_merge_sums_shared_edge_weights() {
+    let temp = create_temp_dir();
+    let shard1 = temp.path().join("shard1.db");
+    let shard2 = temp.path().join("shard2.db");
+    let merged_path = temp.path().join("merged.db");
+
+    let mut session = Session::new();
+    session
+        .set_entity_edge(EntityId(1), EntityId(2), EdgeWeight::new(3))
+        .unwrap();
+    save_session(&session, &shard1).unwrap();
+
+    let mut session = Session::new();
+    session
+        .set_entity_edge(EntityId(2), EntityId(3), EdgeWeight::new(1))
+        .unwrap();
+    session
+        .set_entity_edge(EntityId(1), EntityId(2), EdgeWeight::new(4))
+        .unwrap();
+    save_session(&session, &shard2).unwrap();
+
+    cmd_merge(&[shard1, shard2], &merged_path, "file").unwrap();
+
+    let merged = load_or_create_session(&merged_path, "file").unwrap();
+    assert_eq!(merged.node_count(), 3);
+    assert_eq!(merged.edge_count(), 2);
+    let one = merged.lookup_entity(EntityId(1)).unwrap();
+    let two = merged.lookup_entity(EntityId(2)).unwrap();
+    assert_eq!(merged.get_edge(one, two), Some(EdgeWeight::new(7)));
+}
+
+#[test]
+fn test_merge_missing_input_fails() {
+    let temp = create_temp_dir();
+    let missing = temp.path().join("missing.db");
+    let output = temp.path().join("merged.db");
+
+    assert!(cmd_merge(&[missing], &output, "file").is_err());
+    assert!(!output.exists());
+}
+
 // =============================================================================
 // ROUNDTRIP TESTS
 // =============================================================================
@@ -548,10 +760,10 @@ fn test_export_import_roundtrip_preserves_data() {
     let original_edge_count = session.edge_count();
 
     // Export
-    cmd_export(&db1_path, "file", &export_path, "canonical").unwrap();
+    cmd_export(&db1_path, "file", &export_path, "canonical", None).unwrap();
 
     // Import to new database
-    cmd_import(&db2_path, "file", &export_path).unwrap();
+    cmd_import(&db2_path, "file", &export_path, "canonical").unwrap();
 
     // Verify
     let imported = load_or_create_session(&db2_path, "file").unwrap();
@@ -576,11 +788,73 @@ fn test_deterministic_export() {
     save_session(&session, &db_path).unwrap();
 
     // Export twice
-    cmd_export(&db_path, "file", &export1_path, "canonical").unwrap();
-    cmd_export(&db_path, "file", &export2_path, "canonical").unwrap();
+    cmd_export(&db_path, "file", &export1_path, "canonical", None).unwrap();
+    cmd_export(&db_path, "file", &export2_path, "canonical", None).unwrap();
 
     // Both exports should be identical (deterministic)
     let data1 = std::fs::read(&export1_path).unwrap();
     let data2 = std::fs::read(&export2_path).unwrap();
     assert_eq!(data1, data2, "Canonical export should be deterministic");
 }
+
+// =============================================================================
+// REPLAY COMMAND TESTS
+// =============================================================================
+
+#[test]
+fn test_replay_is_deterministic() {
+    let temp = create_temp_dir();
+    let signals_path = create_signals_json(&temp);
+    let db1 = temp.path().join("replay1.redb");
+    let db2 = temp.path().join("replay2.redb");
+    let export1_path = temp.path().join("replay1.bin");
+    let export2_path = temp.path().join("replay2.bin");
+
+    cmd_replay(&signals_path, "json", &db1, "redb").unwrap();
+    cmd_replay(&signals_path, "json", &db2, "redb").unwrap();
+
+    cmd_export(&db1, "redb", &export1_path, "canonical", None).unwrap();
+    cmd_export(&db2, "redb", &export2_path, "canonical", None).unwrap();
+
+    let data1 = std::fs::read(&export1_path).unwrap();
+    let data2 = std::fs::read(&export2_path).unwrap();
+    assert_eq!(data1, data2, "Replayed databases should export identically");
+}
+
+#[test]
+fn test_replay_rejects_existing_database() {
+    let temp = create_temp_dir();
+    let signals_path = create_signals_text(&temp);
+    let db_path = temp.path().join("test.db");
+
+    cmd_init(&db_path, "file", false).unwrap();
+    let result = cmd_replay(&signals_path, "text", &db_path, "file");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_exit_codes_follow_error_kind() {
+    use kremis::cli::{EXIT_DATA_ERROR, EXIT_FAILURE, EXIT_IO_ERROR, exit_code};
+    use kremis_core::{KremisError, NodeId};
+
+    let temp = create_temp_dir();
+    let bad_signals = temp.path().join("bad.json");
+    let long_attribute = "a".repeat(300);
+    std::fs::write(
+        &bad_signals,
+        format!(r#"[{{"entity_id": 1, "attribute": "{long_attribute}", "value": "v"}}]"#),
+    )
+    .unwrap();
+    let db_path = temp.path().join("test.db");
+    let error = cmd_ingest(&db_path, "file", false, &bad_signals, "json", None).unwrap_err();
+    assert_eq!(exit_code(error), EXIT_DATA_ERROR);
+
+    let unreachable = temp.path().join("missing").join("test.redb");
+    let error = load_or_create_session(&unreachable, "redb").unwrap_err();
+    assert_eq!(exit_code(error), EXIT_IO_ERROR);
+
+    assert_eq!(
+        exit_code(KremisError::NodeNotFound(NodeId(1))),
+        EXIT_FAILURE
+    );
+}
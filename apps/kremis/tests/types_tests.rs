@@ -4,8 +4,8 @@
 #![allow(clippy::unwrap_used, clippy::panic)]
 
 use kremis::api::{
-    EdgeJson, ExportResponse, HealthResponse, IngestRequest, IngestResponse, QueryRequest,
-    QueryResponse, StageResponse, StatusResponse,
+    EdgeJson, ExportResponse, HealthResponse, IngestRequest, IngestResponse, PageJson,
+    QueryRequest, QueryResponse, StageResponse, StatusResponse, TraversalStrategy,
 };
 
 // =============================================================================
@@ -51,6 +51,7 @@ fn test_status_response_serialization() {
         edge_count: 250,
         stable_edges: 50,
         density_millionths: 250000,
+        ingest_rate: 7,
     };
 
     let json = serde_json::to_string(&status).unwrap();
@@ -117,24 +118,54 @@ fn test_ingest_request_deserialization() {
     assert_eq!(request.value, "Alice");
 }
 
+#[test]
+fn test_ingest_request_accepts_aliased_fields() {
+    let json = r#"{"id":1,"attr":"name","val":"Alice"}"#;
+    let request: IngestRequest = serde_json::from_str(json).unwrap();
+
+    assert_eq!(request.entity_id, 1);
+    assert_eq!(request.attribute, "name");
+    assert_eq!(request.value, "Alice");
+    let out = serde_json::to_value(&request).unwrap();
+    assert_eq!(out["entity_id"], 1);
+}
+
 #[test]
 fn test_ingest_request_to_signal_valid() {
     let request = IngestRequest {
         entity_id: 1,
         attribute: "name".to_string(),
         value: "Alice".to_string(),
+        namespace: None,
+        source: None,
     };
 
     let result = request.to_signal();
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_ingest_request_source_carries_to_signal() {
+    let request: IngestRequest = serde_json::from_value(serde_json::json!({
+        "entity_id": 1,
+        "attribute": "name",
+        "value": "Alice",
+        "source": "crawler"
+    }))
+    .unwrap();
+
+    let signal = request.to_signal().unwrap();
+    assert_eq!(signal.source.as_deref(), Some("crawler"));
+}
+
 #[test]
 fn test_ingest_request_to_signal_empty_attribute() {
     let request = IngestRequest {
         entity_id: 1,
         attribute: "".to_string(),
         value: "Alice".to_string(),
+        namespace: None,
+        source: None,
     };
 
     let result = request.to_signal();
@@ -147,6 +178,8 @@ fn test_ingest_request_to_signal_empty_value() {
         entity_id: 1,
         attribute: "name".to_string(),
         value: "".to_string(),
+        namespace: None,
+        source: None,
     };
 
     let result = request.to_signal();
@@ -194,7 +227,10 @@ fn test_ingest_response_serialization() {
 
 #[test]
 fn test_query_request_lookup_serialization() {
-    let request = QueryRequest::Lookup { entity_id: 42 };
+    let request = QueryRequest::Lookup {
+        entity_id: 42,
+        namespace: None,
+    };
     let json = serde_json::to_string(&request).unwrap();
 
     assert!(json.contains("\"type\":\"lookup\""));
@@ -206,6 +242,9 @@ fn test_query_request_traverse_serialization() {
     let request = QueryRequest::Traverse {
         node_id: 1,
         depth: 3,
+        strategy: TraversalStrategy::Bfs,
+        limit: None,
+        offset: None,
     };
     let json = serde_json::to_string(&request).unwrap();
 
@@ -214,6 +253,26 @@ fn test_query_request_traverse_serialization() {
     assert!(json.contains("\"depth\":3"));
 }
 
+#[test]
+fn test_query_request_traverse_strategy_serialization() {
+    let request = QueryRequest::Traverse {
+        node_id: 1,
+        depth: 3,
+        strategy: TraversalStrategy::Dfs,
+        limit: None,
+        offset: None,
+    };
+    let json = serde_json::to_string(&request).unwrap();
+    assert!(json.contains("\"strategy\":\"dfs\""));
+
+    // Missing strategy defaults to BFS for backward compatibility
+    let legacy = r#"{"type":"traverse","node_id":1,"depth":3}"#;
+    match serde_json::from_str::<QueryRequest>(legacy).unwrap() {
+        QueryRequest::Traverse { strategy, .. } => assert_eq!(strategy, TraversalStrategy::Bfs),
+        other => panic!("unexpected variant: {other:?}"),
+    }
+}
+
 #[test]
 fn test_query_request_traverse_filtered_serialization() {
     let request = QueryRequest::TraverseFiltered {
@@ -277,19 +336,86 @@ fn test_query_request_related_serialization() {
     let request = QueryRequest::Related {
         node_id: 5,
         depth: 2,
+        limit: None,
+        offset: None,
     };
     let json = serde_json::to_string(&request).unwrap();
 
     assert!(json.contains("\"type\":\"related\""));
 }
 
+#[test]
+fn test_query_paging_fields_omitted_when_none() {
+    let unpaged = QueryRequest::Related {
+        node_id: 5,
+        depth: 2,
+        limit: None,
+        offset: None,
+    };
+    let json = serde_json::to_string(&unpaged).unwrap();
+    assert!(!json.contains("limit"));
+    assert!(!json.contains("offset"));
+
+    let paged = QueryRequest::Traverse {
+        node_id: 1,
+        depth: 3,
+        strategy: TraversalStrategy::Bfs,
+        limit: Some(10),
+        offset: Some(20),
+    };
+    let json = serde_json::to_string(&paged).unwrap();
+    assert!(json.contains("\"limit\":10"));
+    assert!(json.contains("\"offset\":20"));
+    let back: QueryRequest = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, paged);
+
+    let response = serde_json::to_value(QueryResponse::not_found()).unwrap();
+    assert!(response.get("page").is_none());
+}
+
+#[test]
+fn test_query_response_paginate_partitions_path_and_edges() {
+    let mut full = QueryResponse::with_path(vec![]);
+    full.path = vec![1, 2, 3, 4, 5];
+    full.found = true;
+    full.edges = [(1, 2), (1, 3), (2, 4), (3, 5)]
+        .into_iter()
+        .map(|(from, to)| EdgeJson {
+            from,
+            to,
+            weight: 1,
+        })
+        .collect();
+
+    let first = full.clone().paginate(None, Some(2));
+    assert_eq!(first.path, vec![1, 2]);
+    assert_eq!(first.edges.len(), 3);
+    assert_eq!(
+        first.page,
+        Some(PageJson {
+            total: 5,
+            next_offset: Some(2)
+        })
+    );
+
+    let last = full.clone().paginate(Some(4), Some(2));
+    assert_eq!(last.path, vec![5]);
+    assert!(last.edges.is_empty());
+    assert_eq!(last.page.unwrap().next_offset, None);
+
+    let past_end = full.clone().paginate(Some(9), None);
+    assert!(past_end.path.is_empty());
+
+    assert!(full.paginate(None, None).page.is_none());
+}
+
 #[test]
 fn test_query_request_deserialization() {
     let json = r#"{"type":"lookup","entity_id":42}"#;
     let request: QueryRequest = serde_json::from_str(json).unwrap();
 
     match request {
-        QueryRequest::Lookup { entity_id } => assert_eq!(entity_id, 42),
+        QueryRequest::Lookup { entity_id, .. } => assert_eq!(entity_id, 42),
         _ => panic!("Expected Lookup variant"),
     }
 }
@@ -392,6 +518,22 @@ fn test_query_response_grounding_default_on_deserialize() {
     assert_eq!(response.grounding, "unknown");
 }
 
+#[test]
+fn test_query_response_sort_edges() {
+    let edge = |from, to| EdgeJson {
+        from,
+        to,
+        weight: 1,
+    };
+    let mut response = QueryResponse::not_found();
+    response.edges = vec![edge(3, 1), edge(1, 5), edge(2, 2), edge(1, 4)];
+
+    response.sort_edges();
+
+    let order: Vec<(u64, u64)> = response.edges.iter().map(|e| (e.from, e.to)).collect();
+    assert_eq!(order, vec![(1, 4), (1, 5), (2, 2), (3, 1)]);
+}
+
 // =============================================================================
 // EDGE JSON TESTS
 // =============================================================================
@@ -468,6 +610,8 @@ fn test_ingest_request_roundtrip() {
         entity_id: 42,
         attribute: "test_attr".to_string(),
         value: "test_value".to_string(),
+        namespace: None,
+        source: None,
     };
 
     let json = serde_json::to_string(&original).unwrap();
@@ -481,10 +625,16 @@ fn test_ingest_request_roundtrip() {
 #[test]
 fn test_query_request_all_variants_roundtrip() {
     let variants = vec![
-        QueryRequest::Lookup { entity_id: 1 },
+        QueryRequest::Lookup {
+            entity_id: 1,
+            namespace: None,
+        },
         QueryRequest::Traverse {
             node_id: 2,
             depth: 3,
+            strategy: TraversalStrategy::Bfs,
+            limit: None,
+            offset: None,
         },
         QueryRequest::TraverseFiltered {
             node_id: 4,
@@ -499,6 +649,8 @@ fn test_query_request_all_variants_roundtrip() {
         QueryRequest::Related {
             node_id: 11,
             depth: 2,
+            limit: None,
+            offset: None,
         },
     ];
 
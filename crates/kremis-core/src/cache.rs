@@ -0,0 +1,283 @@
+//! # Cache Module
+//!
+//! Bounded, deterministic least-recently-used cache.
+//!
+//! Caches are the natural home for "Active Context" data: hot entries that are
+//! cheap to recompute but expensive to fetch repeatedly. The cache never
+//! changes query results, it only short-circuits them.
+//!
+//! ## Determinism
+//!
+//! Recency is tracked with a monotonic tick counter rather than wall-clock
+//! time, and all storage uses `BTreeMap`. Any `Ord + Clone` type can be a key.
+
+use std::collections::BTreeMap;
+
+/// A fixed-capacity LRU cache.
+///
+/// - `get` marks an entry as most recently used.
+/// - `insert` evicts the least recently used entry when full.
+/// - A capacity of 0 disables caching (every insert is dropped).
+pub struct LruCache<K: Ord + Clone, V> {
+    /// Maximum number of entries.
+    capacity: usize,
+    /// Key -> (value, last-access tick, hits since insert)
+    entries: BTreeMap<K, (V, u64, u64)>,
+    /// Last-access tick -> key (oldest first)
+    recency: BTreeMap<u64, K>,
+    /// Monotonic access counter.
+    tick: u64,
+    /// Called with each entry evicted for capacity.
+    on_evict: Option<EvictCallback<K, V>>,
+}
+
+/// Boxed eviction callback (`Send` so caches can live in shared server state).
+type EvictCallback<K, V> = Box<dyn FnMut(K, V) + Send>;
+
+impl<K: Ord + Clone + std::fmt::Debug, V: std::fmt::Debug> std::fmt::Debug for LruCache<K, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LruCache")
+            .field("capacity", &self.capacity)
+            .field("entries", &self.entries)
+            .field("tick", &self.tick)
+            .field("on_evict", &self.on_evict.is_some())
+            .finish()
+    }
+}
+
+/// Clones entries and recency; the eviction callback is not cloned.
+impl<K: Ord + Clone, V: Clone> Clone for LruCache<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            capacity: self.capacity,
+            entries: self.entries.clone(),
+            recency: self.recency.clone(),
+            tick: self.tick,
+            on_evict: None,
+        }
+    }
+}
+
+impl<K: Ord + Clone, V> LruCache<K, V> {
+    /// Create an empty cache holding at most `capacity` entries.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: BTreeMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+            on_evict: None,
+        }
+    }
+
+    /// Register a callback invoked with each entry evicted for capacity.
+    ///
+    /// Explicit `remove` and `clear` do not trigger it. Replaces any
+    /// previously registered callback. Useful for write-back caching.
+    pub fn on_evict(&mut self, f: impl FnMut(K, V) + Send + 'static) {
+        self.on_evict = Some(Box::new(f));
+    }
+
+    /// Maximum number of entries.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of cached entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the cache is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Check if a key is cached, without touching its recency.
+    #[must_use]
+    pub fn contains(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Get a cached value and mark it as most recently used.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let tick = self.next_tick();
+        let (_, last, hits) = self.entries.get_mut(key)?;
+        self.recency.remove(last);
+        *last = tick;
+        *hits = hits.saturating_add(1);
+        self.recency.insert(tick, key.clone());
+        self.entries.get(key).map(|(value, _, _)| value)
+    }
+
+    /// Get a cached value without touching its recency.
+    #[must_use]
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.entries.get(key).map(|(value, _, _)| value)
+    }
+
+    /// Cached entries with the number of `get` hits since each was inserted.
+    ///
+    /// Ordered by key. Does not touch recency.
+    pub fn iter_with_hits(&self) -> impl Iterator<Item = (&K, &V, u64)> {
+        self.entries
+            .iter()
+            .map(|(key, (value, _, hits))| (key, value, *hits))
+    }
+
+    /// Insert or replace a value, evicting the least recently used entry if full.
+    ///
+    /// Replacing a value resets its hit count.
+    pub fn insert(&mut self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let tick = self.next_tick();
+        if let Some((_, last, _)) = self.entries.remove(&key) {
+            self.recency.remove(&last);
+        } else if self.entries.len() >= self.capacity {
+            self.evict_oldest();
+        }
+
+        self.recency.insert(tick, key.clone());
+        self.entries.insert(key, (value, tick, 0));
+    }
+
+    /// Remove a key, returning its value if it was cached.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let (value, last, _) = self.entries.remove(key)?;
+        self.recency.remove(&last);
+        Some(value)
+    }
+
+    /// Drop every entry.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    /// Evict the least recently used entry, handing it to the callback.
+    fn evict_oldest(&mut self) {
+        let Some((_, key)) = self.recency.pop_first() else {
+            return;
+        };
+        if let Some((value, _, _)) = self.entries.remove(&key)
+            && let Some(callback) = self.on_evict.as_mut()
+        {
+            callback(key, value);
+        }
+    }
+
+    /// Advance the access counter.
+    fn next_tick(&mut self) -> u64 {
+        self.tick = self.tick.saturating_add(1);
+        self.tick
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_get() {
+        let mut cache = LruCache::new(2);
+        cache.insert(1u64, "a");
+        assert_eq!(cache.get(&1), Some(&"a"));
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut cache = LruCache::new(2);
+        cache.insert(1u64, "a");
+        cache.insert(2, "b");
+        // Touch 1 so that 2 becomes the eviction candidate
+        assert!(cache.get(&1).is_some());
+        cache.insert(3, "c");
+
+        assert!(cache.contains(&1));
+        assert!(!cache.contains(&2));
+        assert!(cache.contains(&3));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn replace_does_not_evict() {
+        let mut cache = LruCache::new(2);
+        cache.insert(1u64, "a");
+        cache.insert(2, "b");
+        cache.insert(1, "z");
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.peek(&1), Some(&"z"));
+        assert!(cache.contains(&2));
+    }
+
+    #[test]
+    fn zero_capacity_disables_cache() {
+        let mut cache = LruCache::new(0);
+        cache.insert(1u64, "a");
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn remove_and_clear() {
+        let mut cache = LruCache::new(4);
+        cache.insert(1u64, 10);
+        cache.insert(2, 20);
+
+        assert_eq!(cache.remove(&1), Some(10));
+        assert_eq!(cache.remove(&1), None);
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn on_evict_receives_each_evicted_entry() {
+        use std::sync::{Arc, Mutex};
+
+        let evicted = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&evicted);
+
+        let mut cache = LruCache::new(2);
+        cache.on_evict(move |k, v| sink.lock().expect("lock").push((k, v)));
+        cache.insert(1u64, "a");
+        cache.insert(2, "b");
+        cache.insert(3, "c");
+        cache.insert(4, "d");
+        // Explicit removal is not an eviction
+        assert_eq!(cache.remove(&3), Some("c"));
+
+        let evicted = evicted.lock().expect("lock").clone();
+        assert_eq!(evicted, vec![(1, "a"), (2, "b")]);
+    }
+
+    #[test]
+    fn iter_with_hits_counts_gets_since_insert() {
+        let mut cache = LruCache::new(4);
+        cache.insert(1u64, "a");
+        cache.insert(2, "b");
+        for _ in 0..3 {
+            assert!(cache.get(&2).is_some());
+        }
+        assert!(cache.get(&1).is_some());
+        assert!(cache.peek(&1).is_some());
+
+        let hits: Vec<(u64, u64)> = cache.iter_with_hits().map(|(&k, _, h)| (k, h)).collect();
+        assert_eq!(hits, vec![(1, 1), (2, 3)]);
+
+        cache.insert(2, "z");
+        assert_eq!(cache.iter_with_hits().nth(1).map(|(_, _, h)| h), Some(0));
+    }
+}
@@ -0,0 +1,171 @@
+//! # Components Module
+//!
+//! Incremental weakly connected components.
+//!
+//! [`ComponentTracker`] is a union-find over `NodeId`s. Adding a node or an
+//! edge is amortized near-constant time, so streaming inserts never pay for
+//! a full `connected_components` pass. Union-find cannot split a set, so
+//! removals are handled by rebuilding the tracker from the graph.
+//!
+//! ## Determinism
+//!
+//! All storage uses `BTreeMap`, union by rank breaks ties towards the
+//! smaller `NodeId`, and component labels come from a counter, so the same
+//! sequence of inserts always yields the same labels.
+
+use crate::NodeId;
+use crate::graph::Graph;
+use std::collections::BTreeMap;
+
+/// Union-find over graph nodes, labelling each weakly connected component.
+#[derive(Debug, Clone, Default)]
+pub struct ComponentTracker {
+    /// Node -> parent (roots point to themselves).
+    parent: BTreeMap<NodeId, NodeId>,
+    /// Root -> rank (upper bound on tree height).
+    rank: BTreeMap<NodeId, u8>,
+    /// Root -> component label.
+    labels: BTreeMap<NodeId, usize>,
+    /// Next unused component label.
+    next_label: usize,
+}
+
+impl ComponentTracker {
+    /// Create an empty tracker.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a tracker from every node and edge of `graph`.
+    #[must_use]
+    pub fn from_graph(graph: &Graph) -> Self {
+        let mut tracker = Self::new();
+        for node in graph.nodes() {
+            tracker.add_node(node.id);
+        }
+        for (from, to, _) in graph.edges() {
+            tracker.union(from, to);
+        }
+        tracker
+    }
+
+    /// Track `node` as a singleton component. No-op if already tracked.
+    pub fn add_node(&mut self, node: NodeId) {
+        if self.parent.contains_key(&node) {
+            return;
+        }
+        self.parent.insert(node, node);
+        self.rank.insert(node, 0);
+        self.labels.insert(node, self.next_label);
+        self.next_label = self.next_label.saturating_add(1);
+    }
+
+    /// Merge the components of `a` and `b`, tracking either if needed.
+    ///
+    /// The surviving root keeps its label.
+    pub fn union(&mut self, a: NodeId, b: NodeId) {
+        self.add_node(a);
+        self.add_node(b);
+        let (root_a, root_b) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return;
+        }
+
+        let rank_a = self.rank.get(&root_a).copied().unwrap_or_default();
+        let rank_b = self.rank.get(&root_b).copied().unwrap_or_default();
+        let (root, child) = match rank_a.cmp(&rank_b) {
+            std::cmp::Ordering::Greater => (root_a, root_b),
+            std::cmp::Ordering::Less => (root_b, root_a),
+            std::cmp::Ordering::Equal => {
+                let (root, child) = if root_a < root_b {
+                    (root_a, root_b)
+                } else {
+                    (root_b, root_a)
+                };
+                self.rank.insert(root, rank_a.saturating_add(1));
+                (root, child)
+            }
+        };
+        self.parent.insert(child, root);
+        self.rank.remove(&child);
+        self.labels.remove(&child);
+    }
+
+    /// Component label of `node`, or `None` if it is not tracked.
+    ///
+    /// Two nodes share a label exactly when they are weakly connected.
+    #[must_use]
+    pub fn component_of(&self, node: NodeId) -> Option<usize> {
+        let mut current = node;
+        loop {
+            let parent = *self.parent.get(&current)?;
+            if parent == current {
+                return self.labels.get(&current).copied();
+            }
+            current = parent;
+        }
+    }
+
+    /// Number of components.
+    #[must_use]
+    pub fn component_count(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Root of `node`'s set, compressing the path behind it.
+    fn find(&mut self, node: NodeId) -> NodeId {
+        let mut root = node;
+        while let Some(&parent) = self.parent.get(&root) {
+            if parent == root {
+                break;
+            }
+            root = parent;
+        }
+
+        let mut current = node;
+        while current != root {
+            let Some(parent) = self.parent.insert(current, root) else {
+                break;
+            };
+            current = parent;
+        }
+        root
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_merges_and_keeps_labels_stable() {
+        let mut tracker = ComponentTracker::new();
+        for id in 0..4 {
+            tracker.add_node(NodeId(id));
+        }
+        assert_eq!(tracker.component_count(), 4);
+
+        tracker.union(NodeId(0), NodeId(1));
+        tracker.union(NodeId(3), NodeId(2));
+        tracker.union(NodeId(1), NodeId(0));
+        assert_eq!(tracker.component_count(), 2);
+        assert_eq!(
+            tracker.component_of(NodeId(0)),
+            tracker.component_of(NodeId(1))
+        );
+        assert_ne!(
+            tracker.component_of(NodeId(0)),
+            tracker.component_of(NodeId(2))
+        );
+
+        tracker.union(NodeId(1), NodeId(3));
+        assert_eq!(tracker.component_count(), 1);
+        assert_eq!(tracker.component_of(NodeId(3)), Some(0));
+        assert_eq!(tracker.component_of(NodeId(9)), None);
+    }
+}
@@ -6,8 +6,9 @@
 //! - More edges confirming fact = higher confidence
 //! - Threshold for "verified" vs "speculative" output
 
-use crate::Artifact;
 use crate::graph::Graph;
+use crate::system::STABLE_THRESHOLD;
+use crate::{Artifact, EdgeWeight};
 
 /// Default threshold for considering a result "verified".
 ///
@@ -69,6 +70,18 @@ impl ConfidenceScore {
     }
 }
 
+/// Confidence (0-100) that an edge is established, from its weight alone.
+///
+/// Scales linearly from 0 for a single observation (weight 1) to 100 at
+/// `STABLE_THRESHOLD`, the weight at which an edge joins the stable layer.
+/// Heavier edges stay at 100; weights of 1 or less score 0.
+#[must_use]
+pub fn stability_confidence(weight: EdgeWeight) -> u8 {
+    let span = STABLE_THRESHOLD.saturating_sub(1).max(1);
+    let reinforced = weight.value().saturating_sub(1).clamp(0, span);
+    u8::try_from(reinforced.saturating_mul(100) / span).unwrap_or(100)
+}
+
 /// Compute confidence score for an artifact.
 ///
 /// Scoring algorithm:
@@ -100,11 +113,46 @@ pub fn compute_confidence(artifact: &Artifact, _graph: &Graph) -> ConfidenceScor
     ConfidenceScore::new(score, evidence_count, path_length)
 }
 
+/// Tunable parameters for path confidence scoring.
+///
+/// The default applies no length penalty, preserving the original scoring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ConfidenceConfig {
+    /// Points subtracted for every hop beyond the first.
+    pub hop_penalty: u8,
+    /// Lowest score the length penalty may push a result down to.
+    pub min_score: u8,
+}
+
+impl ConfidenceConfig {
+    /// Config that penalizes longer inference chains.
+    #[must_use]
+    pub fn with_length_penalty(hop_penalty: u8, min_score: u8) -> Self {
+        Self {
+            hop_penalty,
+            min_score,
+        }
+    }
+}
+
 /// Compute confidence for a path between two nodes.
 ///
 /// Higher weight paths = higher confidence.
 #[must_use]
 pub fn compute_path_confidence(path: &[crate::NodeId], graph: &Graph) -> ConfidenceScore {
+    compute_path_confidence_with_config(path, graph, &ConfidenceConfig::default())
+}
+
+/// Compute path confidence with an explicit [`ConfidenceConfig`].
+///
+/// After weight scoring, `hop_penalty` is subtracted for each hop beyond
+/// the first, floored at `min_score` (never raising an already lower score).
+#[must_use]
+pub fn compute_path_confidence_with_config(
+    path: &[crate::NodeId],
+    graph: &Graph,
+    config: &ConfidenceConfig,
+) -> ConfidenceScore {
     if path.len() < 2 {
         return if path.is_empty() {
             ConfidenceScore::zero()
@@ -134,9 +182,40 @@ pub fn compute_path_confidence(path: &[crate::NodeId], graph: &Graph) -> Confide
     // Weight 1-10 maps to 50-100
     let weight_score = ((avg_weight.clamp(0, 10)) as u8)
         .saturating_mul(5)
-        .saturating_add(50);
+        .saturating_add(50)
+        .min(100);
+
+    // Length penalty: hops beyond the first reduce trust
+    let extra_hops = path.len().saturating_sub(2).min(u8::MAX as usize) as u8;
+    let penalty = config.hop_penalty.saturating_mul(extra_hops);
+    let floor = config.min_score.min(weight_score);
+    let score = weight_score.saturating_sub(penalty).max(floor);
+
+    ConfidenceScore::new(score, edge_count, path.len())
+}
+
+/// Pluggable scoring used by [`crate::grounding::verify_hypothesis_with`].
+///
+/// Only `score` is required; `score_path` defaults to
+/// [`compute_path_confidence`].
+pub trait ConfidenceStrategy {
+    /// Score an artifact produced by a traversal or intersection.
+    fn score(&self, artifact: &Artifact, graph: &Graph) -> ConfidenceScore;
+
+    /// Score an explicit path between two nodes.
+    fn score_path(&self, path: &[crate::NodeId], graph: &Graph) -> ConfidenceScore {
+        compute_path_confidence(path, graph)
+    }
+}
+
+/// The built-in scoring: [`compute_confidence`] and [`compute_path_confidence`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DefaultConfidence;
 
-    ConfidenceScore::new(weight_score.min(100), edge_count, path.len())
+impl ConfidenceStrategy for DefaultConfidence {
+    fn score(&self, artifact: &Artifact, graph: &Graph) -> ConfidenceScore {
+        compute_confidence(artifact, graph)
+    }
 }
 
 // =============================================================================
@@ -482,4 +561,49 @@ mod tests {
         assert_eq!(score1, score2);
         assert_eq!(score2, score3);
     }
+
+    #[test]
+    fn path_confidence_length_penalty() {
+        use crate::graph::GraphStore;
+        use crate::{EdgeWeight, EntityId};
+
+        let mut graph = Graph::new();
+        let nodes: Vec<NodeId> = (0..5)
+            .map(|i| graph.insert_node(EntityId(i)).expect("insert"))
+            .collect();
+        for pair in nodes.windows(2) {
+            graph
+                .insert_edge(pair[0], pair[1], EdgeWeight::new(6))
+                .expect("insert");
+        }
+
+        let config = ConfidenceConfig::with_length_penalty(5, 40);
+        let two_hop = compute_path_confidence_with_config(&nodes[..3], &graph, &config);
+        let four_hop = compute_path_confidence_with_config(&nodes, &graph, &config);
+
+        // 50 + 6*5 = 80, minus 5 per hop beyond the first
+        assert_eq!(two_hop.score, 75);
+        assert_eq!(four_hop.score, 65);
+        assert!(four_hop.score < two_hop.score);
+
+        // Default config keeps the original behavior
+        assert_eq!(compute_path_confidence(&nodes, &graph).score, 80);
+
+        // Penalty is floored at min_score
+        let harsh = ConfidenceConfig::with_length_penalty(50, 40);
+        assert_eq!(
+            compute_path_confidence_with_config(&nodes, &graph, &harsh).score,
+            40
+        );
+    }
+
+    #[test]
+    fn stability_confidence_scales_to_stable_threshold() {
+        assert_eq!(stability_confidence(EdgeWeight::new(1)), 0);
+        assert_eq!(stability_confidence(EdgeWeight::new(-3)), 0);
+        assert_eq!(stability_confidence(EdgeWeight::new(STABLE_THRESHOLD)), 100);
+        assert_eq!(stability_confidence(EdgeWeight::new(i64::MAX)), 100);
+        let mid = stability_confidence(EdgeWeight::new(5));
+        assert!(mid > 0 && mid < 100);
+    }
 }
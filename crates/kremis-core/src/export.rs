@@ -20,7 +20,9 @@ use serde::{Deserialize, Serialize};
 pub const CANONICAL_MAGIC: [u8; 4] = *b"KREX"; // Kremis Export
 
 /// Current canonical format version.
-pub const CANONICAL_VERSION: u8 = 2;
+///
+/// Version 1 had no properties; version 2 had no edge labels.
+pub const CANONICAL_VERSION: u8 = 3;
 
 /// Maximum allowed node count in canonical imports.
 ///
@@ -78,7 +80,7 @@ impl CanonicalHeader {
                 "Invalid file format".to_string(),
             ));
         }
-        if self.version != 1 && self.version != CANONICAL_VERSION {
+        if !(1..=CANONICAL_VERSION).contains(&self.version) {
             return Err(KremisError::SerializationError(
                 "Unsupported file version".to_string(),
             ));
@@ -160,6 +162,21 @@ pub struct CanonicalProperty {
     pub value: String,
 }
 
+/// An edge label in canonical format.
+///
+/// Sorted by (from, to) for deterministic ordering.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CanonicalEdgeLabel {
+    /// Source node ID.
+    pub from: u64,
+
+    /// Target node ID.
+    pub to: u64,
+
+    /// The relationship label.
+    pub label: String,
+}
+
 // =============================================================================
 // CANONICAL GRAPH (Sorted, Deterministic)
 // =============================================================================
@@ -172,6 +189,15 @@ struct CanonicalGraphV1 {
     next_node_id: u64,
 }
 
+/// V2 canonical graph format (without edge labels) for backward compatibility.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+struct CanonicalGraphV2 {
+    nodes: Vec<CanonicalNode>,
+    edges: Vec<CanonicalEdge>,
+    next_node_id: u64,
+    properties: Vec<CanonicalProperty>,
+}
+
 /// A graph in canonical format for bit-exact serialization.
 ///
 /// > "The System MUST implement a `export_canonical()` function that serializes
@@ -189,6 +215,9 @@ pub struct CanonicalGraph {
 
     /// Properties sorted by (node_id, attribute, value).
     pub properties: Vec<CanonicalProperty>,
+
+    /// Edge labels sorted by (from, to).
+    pub edge_labels: Vec<CanonicalEdgeLabel>,
 }
 
 impl CanonicalGraph {
@@ -223,11 +252,24 @@ impl CanonicalGraph {
         }
         properties.sort();
 
+        // Labels come from a BTreeMap keyed by (from, to), already sorted
+        let edge_labels = graph
+            .edges()
+            .filter_map(|(from, to, _)| {
+                graph.edge_label(from, to).map(|label| CanonicalEdgeLabel {
+                    from: from.0,
+                    to: to.0,
+                    label: label.as_str().to_string(),
+                })
+            })
+            .collect();
+
         Self {
             nodes,
             edges,
             next_node_id: graph.next_node_id(),
             properties,
+            edge_labels,
         }
     }
 
@@ -284,6 +326,15 @@ impl CanonicalGraph {
             }
         }
 
+        // Hash edge labels (none in v1/v2 exports, so their checksums hold)
+        for label in &self.edge_labels {
+            hash ^= label.from.rotate_left(31);
+            hash ^= label.to.rotate_left(37);
+            for byte in label.label.as_bytes() {
+                hash ^= (*byte as u64).rotate_left(41);
+            }
+        }
+
         // Hash metadata
         hash ^= self.next_node_id.rotate_left(3);
 
@@ -308,7 +359,50 @@ impl CanonicalGraph {
 ///
 /// Returns `KremisError::SerializationError` if serialization fails.
 pub fn export_canonical(graph: &Graph) -> Result<Vec<u8>, KremisError> {
-    let canonical = CanonicalGraph::from_graph(graph);
+    encode_canonical(&CanonicalGraph::from_graph(graph))
+}
+
+/// Export only the stable subgraph in canonical postcard format.
+///
+/// Keeps edges with weight >= `threshold` and their labels, their endpoint
+/// nodes, and those nodes' properties. NodeIds and `next_node_id` are preserved, so the
+/// result imports with [`import_canonical`] like a full export.
+///
+/// # Errors
+///
+/// Returns `KremisError::SerializationError` if serialization fails.
+pub fn export_stable_canonical(
+    graph: &Graph,
+    threshold: EdgeWeight,
+) -> Result<Vec<u8>, KremisError> {
+    let mut canonical = CanonicalGraph::from_graph(graph);
+    canonical
+        .edges
+        .retain(|edge| edge.weight >= threshold.value());
+
+    let endpoints: std::collections::BTreeSet<u64> = canonical
+        .edges
+        .iter()
+        .flat_map(|edge| [edge.from, edge.to])
+        .collect();
+    canonical.nodes.retain(|node| endpoints.contains(&node.id));
+    canonical
+        .properties
+        .retain(|prop| endpoints.contains(&prop.node_id));
+    let kept: std::collections::BTreeSet<(u64, u64)> = canonical
+        .edges
+        .iter()
+        .map(|edge| (edge.from, edge.to))
+        .collect();
+    canonical
+        .edge_labels
+        .retain(|label| kept.contains(&(label.from, label.to)));
+
+    encode_canonical(&canonical)
+}
+
+/// Frame a canonical graph as `[header_len: u32] [header] [data]`.
+fn encode_canonical(canonical: &CanonicalGraph) -> Result<Vec<u8>, KremisError> {
     let checksum = canonical.checksum();
 
     let header = CanonicalHeader::new(
@@ -322,7 +416,7 @@ pub fn export_canonical(graph: &Graph) -> Result<Vec<u8>, KremisError> {
         .map_err(|e| KremisError::SerializationError(format!("Header: {}", e)))?;
 
     // Serialize data
-    let data_bytes = postcard::to_allocvec(&canonical)
+    let data_bytes = postcard::to_allocvec(canonical)
         .map_err(|e| KremisError::SerializationError(format!("Data: {}", e)))?;
 
     // Combine: [header_len: u32] [header] [data]
@@ -341,6 +435,21 @@ pub fn export_canonical(graph: &Graph) -> Result<Vec<u8>, KremisError> {
 /// Returns `KremisError::SerializationError` if deserialization fails
 /// or the data is corrupted.
 pub fn import_canonical(data: &[u8]) -> Result<Graph, KremisError> {
+    import_canonical_with_progress(data, |_, _| {})
+}
+
+/// Import a graph from canonical postcard format, reporting progress.
+///
+/// `progress(loaded_nodes, total_nodes)` is called once the header is
+/// validated and again after each node is inserted.
+///
+/// # Errors
+///
+/// Same as [`import_canonical`].
+pub fn import_canonical_with_progress(
+    data: &[u8],
+    mut progress: impl FnMut(usize, usize),
+) -> Result<Graph, KremisError> {
     if data.len() < 4 {
         return Err(KremisError::SerializationError(
             "Data too short".to_string(),
@@ -375,6 +484,7 @@ pub fn import_canonical(data: &[u8]) -> Result<Graph, KremisError> {
             header.edge_count, MAX_IMPORT_EDGE_COUNT
         )));
     }
+    progress(0, header.node_count as usize);
 
     // Deserialize data based on version
     let canonical: CanonicalGraph = if header.version == 1 {
@@ -386,6 +496,18 @@ pub fn import_canonical(data: &[u8]) -> Result<Graph, KremisError> {
             edges: v1.edges,
             next_node_id: v1.next_node_id,
             properties: Vec::new(),
+            edge_labels: Vec::new(),
+        }
+    } else if header.version == 2 {
+        // V2 format: no edge_labels field
+        let v2: CanonicalGraphV2 = postcard::from_bytes(&data[4 + header_len..])
+            .map_err(|e| KremisError::SerializationError(format!("Data: {}", e)))?;
+        CanonicalGraph {
+            nodes: v2.nodes,
+            edges: v2.edges,
+            next_node_id: v2.next_node_id,
+            properties: v2.properties,
+            edge_labels: Vec::new(),
         }
     } else {
         postcard::from_bytes(&data[4 + header_len..])
@@ -400,6 +522,7 @@ pub fn import_canonical(data: &[u8]) -> Result<Graph, KremisError> {
             edges: canonical.edges.clone(),
             next_node_id: canonical.next_node_id,
             properties: Vec::new(),
+            edge_labels: Vec::new(),
         };
         v1_canonical.checksum()
     } else {
@@ -424,7 +547,7 @@ pub fn import_canonical(data: &[u8]) -> Result<Graph, KremisError> {
         ));
     }
 
-    Ok(canonical.to_graph())
+    Ok(Graph::from_canonical_with_progress(&canonical, progress))
 }
 
 /// Verify that a graph matches its canonical export.
@@ -458,6 +581,96 @@ pub fn canonical_checksum(graph: &Graph) -> u64 {
     CanonicalGraph::from_graph(graph).checksum()
 }
 
+// =============================================================================
+// MESSAGEPACK EXPORT
+// =============================================================================
+
+/// Export a graph as MessagePack-encoded [`CanonicalGraph`].
+///
+/// The input is the same sorted canonical representation used by
+/// [`export_canonical`], so the bytes are deterministic. There is no header
+/// or checksum; use the canonical format when integrity must be verified.
+///
+/// # Errors
+///
+/// Returns `KremisError::SerializationError` if encoding fails.
+pub fn export_msgpack(graph: &Graph) -> Result<Vec<u8>, KremisError> {
+    rmp_serde::to_vec(&CanonicalGraph::from_graph(graph))
+        .map_err(|e| KremisError::SerializationError(format!("MessagePack: {}", e)))
+}
+
+/// Import a graph from [`export_msgpack`] output.
+///
+/// # Errors
+///
+/// Returns `KremisError::SerializationError` if decoding fails or the
+/// graph exceeds the canonical import limits.
+pub fn import_msgpack(data: &[u8]) -> Result<Graph, KremisError> {
+    let canonical: CanonicalGraph = rmp_serde::from_slice(data)
+        .map_err(|e| KremisError::SerializationError(format!("MessagePack: {}", e)))?;
+
+    if canonical.nodes.len() as u64 > MAX_IMPORT_NODE_COUNT {
+        return Err(KremisError::SerializationError(format!(
+            "Node count {} exceeds maximum allowed {}",
+            canonical.nodes.len(),
+            MAX_IMPORT_NODE_COUNT
+        )));
+    }
+    if canonical.edges.len() as u64 > MAX_IMPORT_EDGE_COUNT {
+        return Err(KremisError::SerializationError(format!(
+            "Edge count {} exceeds maximum allowed {}",
+            canonical.edges.len(),
+            MAX_IMPORT_EDGE_COUNT
+        )));
+    }
+
+    Ok(canonical.to_graph())
+}
+
+// =============================================================================
+// EXPORT MANIFEST
+// =============================================================================
+
+/// Sidecar metadata describing a canonical export, for provenance.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Manifest {
+    /// Number of nodes in the export.
+    pub node_count: u64,
+
+    /// Number of edges in the export.
+    pub edge_count: u64,
+
+    /// [`canonical_checksum`] of the exported graph (matches the header).
+    pub canonical_hash: u64,
+
+    /// Logical time of the export: the graph's next NodeId. No wall clock
+    /// is read, so the same graph always yields the same manifest.
+    pub created_logical_time: u64,
+
+    /// Free-form name of where the graph came from.
+    pub source_name: String,
+}
+
+/// Export a graph to canonical format together with its [`Manifest`].
+///
+/// # Errors
+///
+/// Returns `KremisError::SerializationError` if serialization fails.
+pub fn export_with_manifest(
+    graph: &Graph,
+    source_name: &str,
+) -> Result<(Vec<u8>, Manifest), KremisError> {
+    let data = export_canonical(graph)?;
+    let manifest = Manifest {
+        node_count: graph.node_count()? as u64,
+        edge_count: graph.edge_count()? as u64,
+        canonical_hash: canonical_checksum(graph),
+        created_logical_time: graph.next_node_id(),
+        source_name: source_name.to_string(),
+    };
+    Ok((data, manifest))
+}
+
 // =============================================================================
 // M1 FIX: CRYPTOGRAPHIC HASH SUPPORT
 // =============================================================================
@@ -1115,6 +1328,7 @@ mod tests {
             edges: v1.edges.clone(),
             next_node_id: v1.next_node_id,
             properties: Vec::new(),
+            edge_labels: Vec::new(),
         };
         let checksum = v1_as_canonical.checksum();
 
@@ -1145,6 +1359,60 @@ mod tests {
         assert!(props.is_empty());
     }
 
+    #[test]
+    fn canonical_roundtrip_preserves_edge_labels() {
+        use crate::Attribute;
+
+        let mut graph = create_test_graph();
+        graph
+            .set_edge_label(NodeId(0), NodeId(1), Attribute::new("knows"))
+            .expect("label");
+
+        let exported = export_canonical(&graph).expect("export");
+        let imported = import_canonical(&exported).expect("import");
+        assert_eq!(
+            imported.edge_label(NodeId(0), NodeId(1)),
+            Some(&Attribute::new("knows"))
+        );
+        assert_eq!(imported.edge_label(NodeId(1), NodeId(2)), None);
+        assert!(verify_canonical(&graph, &exported).expect("verify"));
+
+        // Only labels of the edges a stable export keeps come along
+        let stable = export_stable_canonical(&graph, EdgeWeight::new(20)).expect("export");
+        let imported = import_canonical(&stable).expect("import");
+        assert_eq!(imported.edge_label(NodeId(0), NodeId(1)), None);
+    }
+
+    #[test]
+    fn canonical_import_v2_backward_compat() {
+        let canonical = CanonicalGraph::from_graph(&create_test_graph());
+        let v2 = CanonicalGraphV2 {
+            nodes: canonical.nodes.clone(),
+            edges: canonical.edges.clone(),
+            next_node_id: canonical.next_node_id,
+            properties: canonical.properties.clone(),
+        };
+        let header = CanonicalHeader {
+            version: 2,
+            ..CanonicalHeader::new(
+                v2.nodes.len() as u64,
+                v2.edges.len() as u64,
+                canonical.checksum(),
+            )
+        };
+
+        let header_bytes = postcard::to_allocvec(&header).expect("header");
+        let data_bytes = postcard::to_allocvec(&v2).expect("data");
+        let mut data = Vec::new();
+        data.extend_from_slice(&(header_bytes.len() as u32).to_le_bytes());
+        data.extend_from_slice(&header_bytes);
+        data.extend_from_slice(&data_bytes);
+
+        let imported = import_canonical(&data).expect("import v2 should succeed");
+        assert_eq!(imported.edge_count().expect("count"), 3);
+        assert_eq!(imported.edge_label(NodeId(0), NodeId(1)), None);
+    }
+
     #[test]
     fn canonical_properties_included_in_checksum() {
         use crate::{Attribute, Value};
@@ -1165,4 +1433,76 @@ mod tests {
             "Properties should affect the checksum"
         );
     }
+
+    #[test]
+    fn stable_export_omits_weak_edges() {
+        let mut graph = create_test_graph();
+        // Isolated node: never part of the stable subgraph
+        graph.insert_node(EntityId(4)).expect("insert");
+
+        let exported = export_stable_canonical(&graph, EdgeWeight::new(10)).expect("export");
+        let imported = import_canonical(&exported).expect("import");
+
+        let edges: Vec<_> = imported.edges().collect();
+        assert_eq!(
+            edges,
+            vec![
+                (NodeId(0), NodeId(1), EdgeWeight::new(10)),
+                (NodeId(1), NodeId(2), EdgeWeight::new(20)),
+            ]
+        );
+        assert_eq!(imported.node_count().expect("count"), 3);
+        assert!(imported.get_node_by_entity(EntityId(4)).is_none());
+
+        // Deterministic
+        let again = export_stable_canonical(&graph, EdgeWeight::new(10)).expect("export");
+        assert_eq!(exported, again);
+    }
+
+    #[test]
+    fn manifest_matches_exported_graph() {
+        let graph = create_test_graph();
+        let (data, manifest) = export_with_manifest(&graph, "unit-test").unwrap();
+
+        let imported = import_canonical(&data).unwrap();
+        assert_eq!(manifest.node_count, 3);
+        assert_eq!(manifest.edge_count, 3);
+        assert_eq!(manifest.canonical_hash, canonical_checksum(&imported));
+        assert_eq!(manifest.created_logical_time, graph.next_node_id());
+        assert_eq!(manifest.source_name, "unit-test");
+
+        let json = serde_json::to_string(&manifest).unwrap();
+        assert_eq!(serde_json::from_str::<Manifest>(&json).unwrap(), manifest);
+    }
+
+    #[test]
+    fn msgpack_roundtrip_preserves_graph() {
+        use crate::{Attribute, Value};
+
+        let mut graph = create_test_graph();
+        graph
+            .store_property(NodeId(0), Attribute::new("name"), Value::new("Alice"))
+            .expect("store property");
+
+        let exported = export_msgpack(&graph).expect("export should succeed");
+        let imported = import_msgpack(&exported).expect("import should succeed");
+
+        assert_eq!(
+            CanonicalGraph::from_graph(&imported),
+            CanonicalGraph::from_graph(&graph)
+        );
+        assert!(import_msgpack(&exported[..exported.len() / 2]).is_err());
+    }
+
+    #[test]
+    fn msgpack_export_deterministic() {
+        let graph = create_test_graph();
+        let first = export_msgpack(&graph).expect("export should succeed");
+        let second = export_msgpack(&graph).expect("export should succeed");
+        assert_eq!(first, second);
+
+        let reimported = import_msgpack(&first).expect("import should succeed");
+        let third = export_msgpack(&reimported).expect("export should succeed");
+        assert_eq!(first, third);
+    }
 }
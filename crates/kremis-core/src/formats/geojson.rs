@@ -0,0 +1,86 @@
+//! # GeoJSON Format
+//!
+//! Renders traverse results as a GeoJSON `FeatureCollection` for spatial plugins.
+//!
+//! The CORE has no notion of geography: coordinates are supplied by the
+//! caller through a lookup closure. Coordinates are integers (e.g. fixed-point
+//! micro-degrees) to keep the CORE free of floating-point arithmetic.
+//!
+//! - Nodes with coordinates become `Point` features.
+//! - Edges whose endpoints both have coordinates become `LineString` features.
+//! - Nodes without coordinates (and their edges) are skipped.
+
+use crate::{Artifact, NodeId};
+use serde_json::{Value, json};
+
+/// Convert an artifact into a GeoJSON `FeatureCollection`.
+///
+/// `coords` maps a node to its `(x, y)` position, or `None` if unknown.
+/// Features are emitted in artifact order: points first, then lines.
+pub fn artifact_to_geojson(
+    artifact: &Artifact,
+    coords: impl Fn(NodeId) -> Option<(i64, i64)>,
+) -> Value {
+    let mut features = Vec::new();
+
+    for node in &artifact.path {
+        if let Some((x, y)) = coords(*node) {
+            features.push(json!({
+                "type": "Feature",
+                "geometry": { "type": "Point", "coordinates": [x, y] },
+                "properties": { "node_id": node.0 },
+            }));
+        }
+    }
+
+    for (from, to, weight) in artifact.subgraph.iter().flatten() {
+        if let (Some((x1, y1)), Some((x2, y2))) = (coords(*from), coords(*to)) {
+            features.push(json!({
+                "type": "Feature",
+                "geometry": { "type": "LineString", "coordinates": [[x1, y1], [x2, y2]] },
+                "properties": { "from": from.0, "to": to.0, "weight": weight.value() },
+            }));
+        }
+    }
+
+    json!({ "type": "FeatureCollection", "features": features })
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EdgeWeight;
+
+    #[test]
+    fn two_node_artifact_emits_points_and_line() {
+        let artifact = Artifact::with_subgraph(
+            vec![NodeId(0), NodeId(1)],
+            vec![(NodeId(0), NodeId(1), EdgeWeight::new(3))],
+        );
+        let geo = artifact_to_geojson(&artifact, |n| Some((n.0 as i64 * 10, 5)));
+
+        assert_eq!(geo["type"], "FeatureCollection");
+        let features = geo["features"].as_array().expect("features");
+        assert_eq!(features.len(), 3);
+        assert_eq!(features[0]["geometry"]["type"], "Point");
+        assert_eq!(features[2]["geometry"]["type"], "LineString");
+        assert_eq!(features[2]["properties"]["weight"], 3);
+    }
+
+    #[test]
+    fn nodes_without_coords_are_skipped() {
+        let artifact = Artifact::with_subgraph(
+            vec![NodeId(0), NodeId(1)],
+            vec![(NodeId(0), NodeId(1), EdgeWeight::new(1))],
+        );
+        let geo = artifact_to_geojson(&artifact, |n| (n.0 == 0).then_some((1, 2)));
+
+        let features = geo["features"].as_array().expect("features");
+        assert_eq!(features.len(), 1);
+        assert_eq!(features[0]["properties"]["node_id"], 0);
+    }
+}
@@ -7,10 +7,13 @@
 //! This module contains:
 //! - Binary persistence format (postcard + header)
 //! - JSON serialization utilities
+//! - GeoJSON rendering of artifacts (spatial plugins)
 //!
 //! Note: File I/O operations remain in the app layer (apps/kremis).
 //! This module only handles format conversion (pure transformations).
 
+mod geojson;
 mod persistence;
 
+pub use geojson::artifact_to_geojson;
 pub use persistence::*;
@@ -8,7 +8,7 @@
 //!
 //! Format: Header (5 bytes) + postcard-serialized graph data.
 //! - 4 bytes: Magic ("KREM")
-//! - 1 byte: Version
+//! - 1 byte: Version (1 has no edge labels and is still readable)
 //!
 //! ## Security (H7 Fix)
 //!
@@ -17,7 +17,8 @@
 //! - Header validation before payload parsing
 //! - Graceful error handling for corrupted data
 
-use crate::{Graph, KremisError, SerializableGraph, primitives};
+use crate::{EdgeWeight, Graph, KremisError, Node, NodeId, SerializableGraph, primitives};
+use serde::Deserialize;
 
 // =============================================================================
 // SECURITY LIMITS (H7 Fix)
@@ -63,7 +64,7 @@ impl PersistenceHeader {
                 "Invalid magic bytes".to_string(),
             ));
         }
-        if self.version != primitives::FORMAT_VERSION {
+        if !(1..=primitives::FORMAT_VERSION).contains(&self.version) {
             return Err(KremisError::SerializationError(format!(
                 "Unsupported version: {} (expected {})",
                 self.version,
@@ -103,6 +104,27 @@ impl Default for PersistenceHeader {
     }
 }
 
+/// Version 1 payload (without edge labels) for backward compatibility.
+#[derive(Deserialize)]
+struct SerializableGraphV1 {
+    nodes: Vec<Node>,
+    edges: Vec<(NodeId, NodeId, EdgeWeight)>,
+    next_node_id: u64,
+    properties: Vec<(u64, String, String)>,
+}
+
+impl From<SerializableGraphV1> for SerializableGraph {
+    fn from(v1: SerializableGraphV1) -> Self {
+        Self {
+            nodes: v1.nodes,
+            edges: v1.edges,
+            next_node_id: v1.next_node_id,
+            properties: v1.properties,
+            edge_labels: Vec::new(),
+        }
+    }
+}
+
 // =============================================================================
 // SERIALIZATION FUNCTIONS
 // =============================================================================
@@ -159,9 +181,15 @@ pub fn graph_from_bytes(bytes: &[u8]) -> Result<Graph, KremisError> {
 
     // Now safe to deserialize (size has been validated)
     let payload = &bytes[5..];
-    let serializable: SerializableGraph = postcard::from_bytes(payload).map_err(|e| {
-        KremisError::SerializationError(format!("Failed to deserialize graph data: {}", e))
-    })?;
+    let deserialize_error =
+        |e| KremisError::SerializationError(format!("Failed to deserialize graph data: {}", e));
+    let serializable: SerializableGraph = if header.version == 1 {
+        postcard::from_bytes::<SerializableGraphV1>(payload)
+            .map_err(deserialize_error)?
+            .into()
+    } else {
+        postcard::from_bytes(payload).map_err(deserialize_error)?
+    };
 
     Ok(Graph::from(serializable))
 }
@@ -216,4 +244,52 @@ mod tests {
         let result = graph_from_bytes(&bytes);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn edge_labels_roundtrip() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+        graph.insert_edge(a, b, EdgeWeight::new(3)).expect("insert");
+        graph
+            .set_edge_label(a, b, crate::Attribute::new("knows"))
+            .expect("label");
+
+        let restored =
+            graph_from_bytes(&graph_to_bytes(&graph).expect("serialize")).expect("deserialize");
+        assert_eq!(
+            restored.edge_label(a, b),
+            Some(&crate::Attribute::new("knows"))
+        );
+    }
+
+    #[test]
+    fn version_1_payload_still_loads() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+        graph.insert_edge(a, b, EdgeWeight::new(3)).expect("insert");
+
+        // A v1 payload is the current one minus the trailing edge_labels
+        let v1 = (
+            graph.nodes().cloned().collect::<Vec<_>>(),
+            graph.edges().collect::<Vec<_>>(),
+            graph.next_node_id(),
+            Vec::<(u64, String, String)>::new(),
+        );
+        let mut bytes = PersistenceHeader {
+            magic: *primitives::MAGIC_BYTES,
+            version: 1,
+        }
+        .to_bytes()
+        .to_vec();
+        bytes.extend(postcard::to_stdvec(&v1).expect("serialize"));
+
+        let restored = graph_from_bytes(&bytes).expect("deserialize v1");
+        assert_eq!(
+            restored.get_edge(a, b).expect("edge"),
+            Some(EdgeWeight::new(3))
+        );
+        assert_eq!(restored.edge_label(a, b), None);
+    }
 }
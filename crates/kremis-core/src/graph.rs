@@ -5,8 +5,11 @@
 //! This module implements the `GraphStore` trait.
 //! All data structures use `BTreeMap` for deterministic ordering.
 
+use crate::components::ComponentTracker;
 use crate::{Artifact, Attribute, EdgeWeight, EntityId, KremisError, Node, NodeId, Value};
-use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, VecDeque};
+use std::convert::Infallible;
 
 // =============================================================================
 // GRAPHSTORE TRAIT
@@ -54,13 +57,21 @@ pub trait GraphStore {
     /// Get all neighbors of a node (outgoing edges).
     fn neighbors(&self, node: NodeId) -> Result<Vec<(NodeId, EdgeWeight)>, KremisError>;
 
+    /// Get all nodes with an edge into `node` (incoming edges), sorted by NodeId.
+    fn in_neighbors(&self, node: NodeId) -> Result<Vec<(NodeId, EdgeWeight)>, KremisError>;
+
     /// Check if a node exists in the graph.
     fn contains_node(&self, id: NodeId) -> Result<bool, KremisError>;
 
     /// Traverse the graph from a starting node up to a depth limit.
+    ///
+    /// Each node is expanded at most once, so every subgraph edge appears
+    /// at most once, in BFS discovery order.
     fn traverse(&self, start: NodeId, depth: usize) -> Result<Option<Artifact>, KremisError>;
 
     /// Traverse with minimum weight filter.
+    ///
+    /// Same edge uniqueness guarantee as [`GraphStore::traverse`].
     fn traverse_filtered(
         &self,
         start: NodeId,
@@ -69,10 +80,14 @@ pub trait GraphStore {
     ) -> Result<Option<Artifact>, KremisError>;
 
     /// Find nodes connected to ALL input nodes (intersection).
+    ///
+    /// The result is always in ascending `NodeId` order, independent of the
+    /// order of `nodes` and of edge insertion order.
     fn intersect(&self, nodes: &[NodeId]) -> Result<Vec<NodeId>, KremisError>;
 
     /// Find the strongest path between two nodes.
     /// Cost = i64::MAX - weight, so higher weights = lower cost = preferred.
+    /// Costs are summed without saturation, see [`strongest_edge_cost`].
     fn strongest_path(
         &self,
         start: NodeId,
@@ -100,12 +115,32 @@ pub trait GraphStore {
     ///
     /// Returns a list of (Attribute, Value) pairs associated with this node.
     fn get_properties(&self, node: NodeId) -> Result<Vec<(Attribute, Value)>, KremisError>;
+
+    /// Attach a relationship label to an existing edge, replacing any
+    /// previous one.
+    ///
+    /// Stores that keep no labels accept and drop it.
+    fn set_edge_label(
+        &mut self,
+        from: NodeId,
+        to: NodeId,
+        label: Attribute,
+    ) -> Result<(), KremisError>;
 }
 
 // =============================================================================
 // GRAPH IMPLEMENTATION
 // =============================================================================
 
+/// A notable graph event recorded in the change log.
+///
+/// Drained with [`Graph::take_changes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphChange {
+    /// The weight of `from -> to` reached `i64::MAX` and stopped growing.
+    WeightSaturated { from: NodeId, to: NodeId },
+}
+
 /// The main Graph structure.
 ///
 /// Uses `BTreeMap` exclusively for deterministic ordering.
@@ -118,15 +153,33 @@ pub struct Graph {
     /// Adjacency list: from_node -> (to_node -> weight)
     edges: BTreeMap<NodeId, BTreeMap<NodeId, EdgeWeight>>,
 
+    /// Reverse adjacency: to_node -> {from_node}. Weights live in `edges`.
+    reverse_edges: BTreeMap<NodeId, BTreeSet<NodeId>>,
+
     /// Reverse lookup: EntityId -> NodeId
     entity_index: BTreeMap<EntityId, NodeId>,
 
+    /// Entities of nodes removed by `merge_nodes` -> the node they merged
+    /// into. Kept apart from `entity_index`, whose entries must match their
+    /// node's entity. In-memory only.
+    merged_entities: BTreeMap<EntityId, NodeId>,
+
     /// Node properties: NodeId -> Attribute -> [Values]
     /// Stores the full signal data (attribute, value) for each node.
     properties: BTreeMap<NodeId, BTreeMap<Attribute, Vec<Value>>>,
 
+    /// Optional edge labels: (from, to) -> relationship attribute.
+    /// In-memory only; labels are not part of the persisted formats.
+    edge_labels: BTreeMap<(NodeId, NodeId), Attribute>,
+
     /// Next available NodeId
     next_node_id: u64,
+
+    /// Change log awaiting `take_changes`. In-memory only.
+    changes: Vec<GraphChange>,
+
+    /// Opt-in incremental connectivity, see `track_components`.
+    components: Option<Box<ComponentTracker>>,
 }
 
 impl Graph {
@@ -136,20 +189,72 @@ impl Graph {
         Self::default()
     }
 
+    /// Drain the change log, oldest event first.
+    pub fn take_changes(&mut self) -> Vec<GraphChange> {
+        std::mem::take(&mut self.changes)
+    }
+
+    /// Log `WeightSaturated` when an increase first reaches `i64::MAX`.
+    fn note_weight_change(&mut self, from: NodeId, to: NodeId, before: i64, after: i64) {
+        if before < i64::MAX && after == i64::MAX {
+            self.changes.push(GraphChange::WeightSaturated { from, to });
+        }
+    }
+
+    /// Record `from -> to` in the reverse adjacency index.
+    fn link(&mut self, from: NodeId, to: NodeId) {
+        self.reverse_edges.entry(to).or_default().insert(from);
+        if let Some(tracker) = self.components.as_mut() {
+            tracker.union(from, to);
+        }
+    }
+
+    /// Drop `from -> to` from the reverse adjacency index.
+    fn unlink(&mut self, from: NodeId, to: NodeId) {
+        if let Some(sources) = self.reverse_edges.get_mut(&to) {
+            sources.remove(&from);
+            if sources.is_empty() {
+                self.reverse_edges.remove(&to);
+            }
+        }
+    }
+
+    /// Recompute the reverse adjacency index from `edges`.
+    fn rebuild_reverse_edges(&mut self) {
+        let mut reverse: BTreeMap<NodeId, BTreeSet<NodeId>> = BTreeMap::new();
+        for (from, to, _) in self.edges() {
+            reverse.entry(to).or_default().insert(from);
+        }
+        self.reverse_edges = reverse;
+        self.refresh_components();
+    }
+
     /// Reconstruct a graph from a canonical representation, preserving original NodeIds.
     #[must_use]
     pub fn from_canonical(canonical: &crate::export::CanonicalGraph) -> Self {
+        Self::from_canonical_with_progress(canonical, |_, _| {})
+    }
+
+    /// Like [`from_canonical`](Self::from_canonical), calling
+    /// `progress(loaded_nodes, total_nodes)` after each node is inserted.
+    #[must_use]
+    pub fn from_canonical_with_progress(
+        canonical: &crate::export::CanonicalGraph,
+        mut progress: impl FnMut(usize, usize),
+    ) -> Self {
         let mut graph = Self {
             next_node_id: canonical.next_node_id,
             ..Self::default()
         };
 
-        for cn in &canonical.nodes {
+        let total = canonical.nodes.len();
+        for (i, cn) in canonical.nodes.iter().enumerate() {
             let node_id = NodeId(cn.id);
             let entity = EntityId(cn.entity);
             let node = Node::new(node_id, entity);
             graph.nodes.insert(node_id, node);
             graph.entity_index.insert(entity, node_id);
+            progress(i.saturating_add(1), total);
         }
 
         for ce in &canonical.edges {
@@ -161,6 +266,7 @@ impl Graph {
                     .entry(from)
                     .or_default()
                     .insert(to, EdgeWeight::new(ce.weight));
+                graph.link(from, to);
             }
         }
 
@@ -175,6 +281,10 @@ impl Graph {
             }
         }
 
+        for cl in &canonical.edge_labels {
+            let _ = graph.set_edge_label(NodeId(cl.from), NodeId(cl.to), Attribute::new(&cl.label));
+        }
+
         graph
     }
 
@@ -192,12 +302,130 @@ impl Graph {
         })
     }
 
+    /// Edges for which `predicate(from, to, weight)` holds, in `edges()` order.
+    pub fn edges_where<'a, F>(
+        &'a self,
+        predicate: F,
+    ) -> impl Iterator<Item = (NodeId, NodeId, EdgeWeight)> + 'a
+    where
+        F: Fn(NodeId, NodeId, EdgeWeight) -> bool + 'a,
+    {
+        self.edges()
+            .filter(move |&(from, to, weight)| predicate(from, to, weight))
+    }
+
+    /// Edges with weight >= `min_weight`, in `edges()` order.
+    pub fn stable_edges(
+        &self,
+        min_weight: EdgeWeight,
+    ) -> impl Iterator<Item = (NodeId, NodeId, EdgeWeight)> + '_ {
+        self.edges_where(move |_, _, weight| weight >= min_weight)
+    }
+
+    /// Deterministic fingerprint of the graph: its canonical checksum.
+    ///
+    /// Equal graphs always share a fingerprint, so two replicas can compare
+    /// it to skip a full sync.
+    #[must_use]
+    pub fn canonical_hash(&self) -> u64 {
+        crate::export::canonical_checksum(self)
+    }
+
     /// Get the next node ID that would be assigned.
     #[must_use]
     pub fn next_node_id(&self) -> u64 {
         self.next_node_id
     }
 
+    /// Node for `entity`, following merges (see `merge_nodes`).
+    fn entity_node(&self, entity: EntityId) -> Option<NodeId> {
+        self.entity_index
+            .get(&entity)
+            .or_else(|| self.merged_entities.get(&entity))
+            .copied()
+    }
+
+    /// Insert a node for an entity, reusing the existing node if mapped.
+    fn insert_node_internal(&mut self, entity: EntityId) -> NodeId {
+        // Return existing node if entity already mapped (or merged away)
+        if let Some(node_id) = self.entity_node(entity) {
+            return node_id;
+        }
+
+        // Create new node
+        let node_id = NodeId(self.next_node_id);
+        self.next_node_id = self.next_node_id.saturating_add(1);
+
+        let node = Node::new(node_id, entity);
+        self.nodes.insert(node_id, node);
+        self.entity_index.insert(entity, node_id);
+        if let Some(tracker) = self.components.as_mut() {
+            tracker.add_node(node_id);
+        }
+
+        node_id
+    }
+
+    /// Insert a batch of entities, returning their NodeIds in input order.
+    ///
+    /// Existing entities (and duplicates within the batch) reuse the same
+    /// node, so the call is idempotent.
+    pub fn insert_nodes(&mut self, entities: &[EntityId]) -> Vec<NodeId> {
+        entities
+            .iter()
+            .map(|&entity| self.insert_node_internal(entity))
+            .collect()
+    }
+
+    /// Renumber all nodes to the contiguous range `0..n`, eliminating gaps.
+    ///
+    /// Nodes keep their relative order. Edges, the entity index, properties,
+    /// and edge labels are rewritten, and `next_node_id` becomes `n`.
+    /// Returns the old -> new mapping so callers can fix up external references.
+    pub fn compact_ids(&mut self) -> BTreeMap<NodeId, NodeId> {
+        let mapping: BTreeMap<NodeId, NodeId> = self
+            .nodes
+            .keys()
+            .zip(0u64..)
+            .map(|(&old, new)| (old, NodeId(new)))
+            .collect();
+        let remap = |id: &NodeId| mapping.get(id).copied().unwrap_or(*id);
+
+        self.nodes = std::mem::take(&mut self.nodes)
+            .into_values()
+            .map(|mut node| {
+                node.id = remap(&node.id);
+                (node.id, node)
+            })
+            .collect();
+        self.edges = std::mem::take(&mut self.edges)
+            .into_iter()
+            .map(|(from, targets)| {
+                let targets = targets.into_iter().map(|(to, w)| (remap(&to), w)).collect();
+                (remap(&from), targets)
+            })
+            .collect();
+        for node_id in self
+            .entity_index
+            .values_mut()
+            .chain(self.merged_entities.values_mut())
+        {
+            *node_id = remap(node_id);
+        }
+        self.properties = std::mem::take(&mut self.properties)
+            .into_iter()
+            .map(|(node, props)| (remap(&node), props))
+            .collect();
+        self.edge_labels = std::mem::take(&mut self.edge_labels)
+            .into_iter()
+            .map(|((from, to), label)| ((remap(&from), remap(&to)), label))
+            .collect();
+        self.next_node_id = mapping.len() as u64;
+        self.rebuild_reverse_edges();
+
+        mapping
+    }
+
     /// Check if the graph contains a node (internal, non-Result version).
     #[must_use]
     pub fn contains_node_internal(&self, id: NodeId) -> bool {
@@ -229,6 +457,372 @@ impl Graph {
         self.edges.get(&from)?.get(&to).copied()
     }
 
+    /// Update the weight of an existing edge.
+    ///
+    /// Unlike `insert_edge`, this never creates an edge: a missing edge
+    /// returns `KremisError::EdgeNotFound`.
+    pub fn set_edge_weight(
+        &mut self,
+        from: NodeId,
+        to: NodeId,
+        weight: EdgeWeight,
+    ) -> Result<(), KremisError> {
+        let slot = self
+            .edges
+            .get_mut(&from)
+            .and_then(|targets| targets.get_mut(&to))
+            .ok_or(KremisError::EdgeNotFound(from, to))?;
+        *slot = weight;
+        Ok(())
+    }
+
+    /// Adjust the weight of an existing edge by `delta`.
+    ///
+    /// Uses saturating arithmetic. If the resulting weight is zero or
+    /// negative the edge (and its label) is removed. Returns the new weight,
+    /// `EdgeWeight(0)` when removed, or `KremisError::EdgeNotFound`.
+    pub fn adjust_edge(
+        &mut self,
+        from: NodeId,
+        to: NodeId,
+        delta: i64,
+    ) -> Result<EdgeWeight, KremisError> {
+        let targets = self
+            .edges
+            .get_mut(&from)
+            .ok_or(KremisError::EdgeNotFound(from, to))?;
+        let current = targets
+            .get(&to)
+            .copied()
+            .ok_or(KremisError::EdgeNotFound(from, to))?;
+        let updated = current.value().saturating_add(delta);
+
+        if updated > 0 {
+            targets.insert(to, EdgeWeight::new(updated));
+            self.note_weight_change(from, to, current.value(), updated);
+            return Ok(EdgeWeight::new(updated));
+        }
+
+        self.remove_edge(from, to);
+        Ok(EdgeWeight::new(0))
+    }
+
+    /// Remove the edge `from -> to` and its label.
+    ///
+    /// Returns the removed weight, or `None` if the edge did not exist.
+    pub fn remove_edge(&mut self, from: NodeId, to: NodeId) -> Option<EdgeWeight> {
+        let targets = self.edges.get_mut(&from)?;
+        let weight = targets.remove(&to)?;
+        if targets.is_empty() {
+            self.edges.remove(&from);
+        }
+        self.unlink(from, to);
+        self.edge_labels.remove(&(from, to));
+        self.refresh_components();
+        Some(weight)
+    }
+
+    /// Permanently remove every edge weighing less than `min_weight`.
+    ///
+    /// Edge labels go with their edges; nodes are kept even when left
+    /// isolated. Unlike `traverse_filtered`, which hides weak edges per
+    /// query, this deletes them. Returns the number of edges removed.
+    pub fn prune_weak_edges(&mut self, min_weight: EdgeWeight) -> usize {
+        let weak: Vec<(NodeId, NodeId)> = self
+            .edges_where(|_, _, weight| weight < min_weight)
+            .map(|(from, to, _)| (from, to))
+            .collect();
+        self.remove_edges(&weak);
+        weak.len()
+    }
+
+    /// Remove the `n` lowest-weight edges and return them.
+    ///
+    /// Ties are broken by ascending `(from, to)`, which is also the order of
+    /// the result after sorting by weight. Removes every edge when `n`
+    /// exceeds the edge count. Nodes are kept even when left isolated.
+    pub fn forget_weakest(&mut self, n: usize) -> Vec<(NodeId, NodeId, EdgeWeight)> {
+        let mut ranked: Vec<(NodeId, NodeId, EdgeWeight)> = self.edges().collect();
+        ranked.sort_by_key(|&(from, to, weight)| (weight, from, to));
+        ranked.truncate(n);
+        let forgotten: Vec<(NodeId, NodeId)> =
+            ranked.iter().map(|&(from, to, _)| (from, to)).collect();
+        self.remove_edges(&forgotten);
+        ranked
+    }
+
+    /// Remove many edges with their labels, rebuilding the component
+    /// tracker once at the end.
+    fn remove_edges(&mut self, edges: &[(NodeId, NodeId)]) {
+        for &(from, to) in edges {
+            if let Some(targets) = self.edges.get_mut(&from) {
+                targets.remove(&to);
+            }
+            self.unlink(from, to);
+            self.edge_labels.remove(&(from, to));
+        }
+        self.edges.retain(|_, targets| !targets.is_empty());
+        self.refresh_components();
+    }
+
+    /// Remove a node with every edge touching it.
+    ///
+    /// Drops outgoing and incoming edges, edge labels, properties, and the
+    /// `entity_index` entry. Returns `false` if the node did not exist.
+    pub fn remove_node(&mut self, id: NodeId) -> bool {
+        let removed = self.detach_node(id);
+        if removed {
+            self.refresh_components();
+        }
+        removed
+    }
+
+    /// Remove every node not reachable from any of `roots`, following edges
+    /// in both directions, together with their edges.
+    ///
+    /// Roots that do not exist are ignored, so with no existing root the
+    /// graph is emptied. Returns the number of nodes removed.
+    pub fn retain_reachable_from(&mut self, roots: &[NodeId]) -> usize {
+        let mut reachable: BTreeSet<NodeId> = roots
+            .iter()
+            .copied()
+            .filter(|root| self.contains_node_internal(*root))
+            .collect();
+        let mut queue: VecDeque<NodeId> = reachable.iter().copied().collect();
+        while let Some(current) = queue.pop_front() {
+            let outgoing = self.edges.get(&current).into_iter().flat_map(|t| t.keys());
+            let incoming = self.reverse_edges.get(&current).into_iter().flatten();
+            for &other in outgoing.chain(incoming) {
+                if reachable.insert(other) {
+                    queue.push_back(other);
+                }
+            }
+        }
+
+        let unreachable: Vec<NodeId> = self
+            .nodes
+            .keys()
+            .copied()
+            .filter(|id| !reachable.contains(id))
+            .collect();
+        for &id in &unreachable {
+            self.detach_node(id);
+        }
+        if !unreachable.is_empty() {
+            self.refresh_components();
+        }
+        unreachable.len()
+    }
+
+    /// `remove_node` without the component refresh, for batch removals.
+    fn detach_node(&mut self, id: NodeId) -> bool {
+        let Some(node) = self.nodes.remove(&id) else {
+            return false;
+        };
+        self.entity_index.remove(&node.entity);
+        self.merged_entities.retain(|_, target| *target != id);
+        self.properties.remove(&id);
+        for to in self.edges.remove(&id).unwrap_or_default().into_keys() {
+            self.unlink(id, to);
+        }
+        for from in self.reverse_edges.remove(&id).unwrap_or_default() {
+            if let Some(targets) = self.edges.get_mut(&from) {
+                targets.remove(&id);
+                if targets.is_empty() {
+                    self.edges.remove(&from);
+                }
+            }
+        }
+        self.edge_labels
+            .retain(|(from, to), _| *from != id && *to != id);
+        true
+    }
+
+    /// Merge another graph into this one, matching nodes by entity.
+    ///
+    /// Entities missing here get fresh NodeIds. Weights of edges present in
+    /// both graphs are summed (saturating). Property values are appended
+    /// unless already stored; existing edge labels win over `other`'s.
+    pub fn merge(&mut self, other: &Graph) {
+        let mapping: BTreeMap<NodeId, NodeId> = other
+            .nodes
+            .iter()
+            .map(|(&id, node)| (id, self.insert_node_internal(node.entity)))
+            .collect();
+
+        for (from, to, weight) in other.edges() {
+            let (Some(&from), Some(&to)) = (mapping.get(&from), mapping.get(&to)) else {
+                continue;
+            };
+            self.add_edge_weight(from, to, weight);
+        }
+
+        for (old, attrs) in &other.properties {
+            if let Some(&node) = mapping.get(old) {
+                self.append_properties(node, attrs);
+            }
+        }
+
+        for ((from, to), label) in &other.edge_labels {
+            if let (Some(&from), Some(&to)) = (mapping.get(from), mapping.get(to)) {
+                self.edge_labels
+                    .entry((from, to))
+                    .or_insert_with(|| label.clone());
+            }
+        }
+    }
+
+    /// Fuse `drop` into `keep`, then remove `drop`.
+    ///
+    /// Every edge into or out of `drop` is redirected to `keep`; when `keep`
+    /// already has that edge the weights are summed (saturating). Edges that
+    /// would become self-loops are dropped. `drop`'s properties and edge
+    /// labels are carried over (existing values on `keep` win). `drop`'s
+    /// entity, and any entity merged into `drop` before, then resolves to
+    /// `keep`, so re-ingesting it reinforces `keep` instead of undoing the
+    /// merge. Merging a node into itself is a no-op. Returns
+    /// `KremisError::NodeNotFound` if either node is missing.
+    pub fn merge_nodes(&mut self, keep: NodeId, drop: NodeId) -> Result<(), KremisError> {
+        for id in [keep, drop] {
+            if !self.contains_node_internal(id) {
+                return Err(KremisError::NodeNotFound(id));
+            }
+        }
+        if keep == drop {
+            return Ok(());
+        }
+
+        let redirected: Vec<(NodeId, NodeId, EdgeWeight)> = self
+            .neighbors_internal(drop)
+            .map(|(to, weight)| (keep, to, weight))
+            .chain(
+                self.predecessors(drop)
+                    .into_iter()
+                    .map(|(from, weight)| (from, keep, weight)),
+            )
+            .filter(|&(from, to, _)| from != to && from != drop && to != drop)
+            .collect();
+        let labels: Vec<((NodeId, NodeId), Attribute)> = self
+            .edge_labels
+            .iter()
+            .filter(|((from, to), _)| *from == drop || *to == drop)
+            .map(|(&(from, to), label)| {
+                let from = if from == drop { keep } else { from };
+                let to = if to == drop { keep } else { to };
+                ((from, to), label.clone())
+            })
+            .collect();
+        let properties = self.properties.get(&drop).cloned().unwrap_or_default();
+        let merged: Vec<EntityId> = self
+            .nodes
+            .get(&drop)
+            .map(|node| node.entity)
+            .into_iter()
+            .chain(
+                self.merged_entities
+                    .iter()
+                    .filter(|&(_, &target)| target == drop)
+                    .map(|(&entity, _)| entity),
+            )
+            .collect();
+
+        self.remove_node(drop);
+        for entity in merged {
+            self.merged_entities.insert(entity, keep);
+        }
+        for (from, to, weight) in redirected {
+            self.add_edge_weight(from, to, weight);
+        }
+        for (edge, label) in labels {
+            if edge.0 != edge.1 {
+                self.edge_labels.entry(edge).or_insert(label);
+            }
+        }
+        self.append_properties(keep, &properties);
+        Ok(())
+    }
+
+    /// Add `weight` to `from -> to` (saturating), creating the edge if needed.
+    fn add_edge_weight(&mut self, from: NodeId, to: NodeId, weight: EdgeWeight) {
+        let entry = self
+            .edges
+            .entry(from)
+            .or_default()
+            .entry(to)
+            .or_insert(EdgeWeight::new(0));
+        let before = entry.value();
+        *entry = EdgeWeight::new(before.saturating_add(weight.value()));
+        let after = entry.value();
+        self.link(from, to);
+        self.note_weight_change(from, to, before, after);
+    }
+
+    /// Append property values to `node`, skipping values already stored.
+    fn append_properties(&mut self, node: NodeId, attrs: &BTreeMap<Attribute, Vec<Value>>) {
+        let target = self.properties.entry(node).or_default();
+        for (attr, values) in attrs {
+            let stored = target.entry(attr.clone()).or_default();
+            for value in values {
+                if !stored.contains(value) {
+                    stored.push(value.clone());
+                }
+            }
+        }
+    }
+
+    /// Get the relationship label of an edge, if one was set.
+    #[must_use]
+    pub fn edge_label(&self, from: NodeId, to: NodeId) -> Option<&Attribute> {
+        self.edge_labels.get(&(from, to))
+    }
+
+    /// Get the literal value an entity holds for `attribute`.
+    ///
+    /// The ingestor stores signal values as node properties, so no hop to a
+    /// value node is needed. When the attribute was ingested several times
+    /// the most recent value wins. Returns `None` for unknown entities or
+    /// attributes.
+    #[must_use]
+    pub fn attribute_value(&self, entity: EntityId, attribute: &Attribute) -> Option<&Value> {
+        let node = self.entity_node(entity)?;
+        self.properties.get(&node)?.get(attribute)?.last()
+    }
+
+    /// Every stored attribute with the number of nodes that carry it.
+    ///
+    /// A node counts once per attribute however many values it holds.
+    /// Sorted by descending count, then by attribute name.
+    #[must_use]
+    pub fn attribute_usage(&self) -> Vec<(Attribute, usize)> {
+        let mut counts: BTreeMap<&Attribute, usize> = BTreeMap::new();
+        for attributes in self.properties.values() {
+            for attribute in attributes.keys() {
+                let count = counts.entry(attribute).or_default();
+                *count = count.saturating_add(1);
+            }
+        }
+        let mut usage: Vec<(Attribute, usize)> = counts
+            .into_iter()
+            .map(|(attribute, count)| (attribute.clone(), count))
+            .collect();
+        usage.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        usage
+    }
+
+    /// Iterate edges as `(subject, label, object, weight)` entity triples.
+    ///
+    /// NodeIds are resolved to their entities; the label is `None` for
+    /// unlabeled edges. Order follows `edges()` (deterministic).
+    pub fn relationship_triples(
+        &self,
+    ) -> impl Iterator<Item = (EntityId, Option<Attribute>, EntityId, EdgeWeight)> + '_ {
+        self.edges().filter_map(|(from, to, weight)| {
+            let subject = self.nodes.get(&from)?.entity;
+            let object = self.nodes.get(&to)?.entity;
+            Some((subject, self.edge_label(from, to).cloned(), object, weight))
+        })
+    }
+
     /// Import a node with its original NodeId (for export/import operations).
     ///
     /// # M3 Fix
@@ -242,26 +836,115 @@ impl Graph {
             self.next_node_id = node.id.0.saturating_add(1);
         }
         self.entity_index.insert(node.entity, node.id);
+        self.merged_entities.remove(&node.entity);
+        if let Some(tracker) = self.components.as_mut() {
+            tracker.add_node(node.id);
+        }
         self.nodes.insert(node.id, node);
     }
-}
 
-impl GraphStore for Graph {
-    fn insert_node(&mut self, entity: EntityId) -> Result<NodeId, KremisError> {
-        // Return existing node if entity already mapped
-        if let Some(&node_id) = self.entity_index.get(&entity) {
-            return Ok(node_id);
+    /// Verify the structural invariants of the graph.
+    ///
+    /// Checks that:
+    /// - every `entity_index` entry points to an existing node with a matching entity
+    /// - every merged entity points to an existing node and is not indexed
+    /// - every edge endpoint exists
+    /// - the reverse adjacency index matches the forward edges
+    /// - `next_node_id` exceeds all node ids
+    /// - no adjacency map is left empty
+    ///
+    /// Returns every violation found, in deterministic order.
+    pub fn check_invariants(&self) -> Result<(), Vec<String>> {
+        let mut violations = Vec::new();
+
+        for (entity, node_id) in &self.entity_index {
+            match self.nodes.get(node_id) {
+                None => violations.push(format!(
+                    "entity_index: entity {} points to missing node {}",
+                    entity.0, node_id.0
+                )),
+                Some(node) if node.entity != *entity => violations.push(format!(
+                    "entity_index: entity {} points to node {} with entity {}",
+                    entity.0, node_id.0, node.entity.0
+                )),
+                Some(_) => {}
+            }
+        }
+        for (entity, node_id) in &self.merged_entities {
+            if !self.nodes.contains_key(node_id) {
+                violations.push(format!(
+                    "merged_entities: entity {} points to missing node {}",
+                    entity.0, node_id.0
+                ));
+            }
+            if self.entity_index.contains_key(entity) {
+                violations.push(format!(
+                    "merged_entities: entity {} is also indexed",
+                    entity.0
+                ));
+            }
         }
 
-        // Create new node
-        let node_id = NodeId(self.next_node_id);
-        self.next_node_id = self.next_node_id.saturating_add(1);
+        for (from, targets) in &self.edges {
+            if targets.is_empty() {
+                violations.push(format!("edges: empty adjacency map for node {}", from.0));
+            }
+            if !self.nodes.contains_key(from) {
+                violations.push(format!("edges: source node {} does not exist", from.0));
+            }
+            for to in targets.keys() {
+                if !self.nodes.contains_key(to) {
+                    violations.push(format!(
+                        "edges: edge {} -> {} targets missing node",
+                        from.0, to.0
+                    ));
+                }
+            }
+        }
 
-        let node = Node::new(node_id, entity);
-        self.nodes.insert(node_id, node);
-        self.entity_index.insert(entity, node_id);
+        for (to, sources) in &self.reverse_edges {
+            for from in sources {
+                if !self.contains_edge(*from, *to) {
+                    violations.push(format!(
+                        "reverse_edges: {} -> {} has no forward edge",
+                        from.0, to.0
+                    ));
+                }
+            }
+        }
+        for (from, to, _) in self.edges() {
+            if !self
+                .reverse_edges
+                .get(&to)
+                .is_some_and(|sources| sources.contains(&from))
+            {
+                violations.push(format!(
+                    "reverse_edges: missing entry for {} -> {}",
+                    from.0, to.0
+                ));
+            }
+        }
+
+        if let Some(max_id) = self.nodes.keys().next_back()
+            && self.next_node_id <= max_id.0
+        {
+            violations.push(format!(
+                "next_node_id {} does not exceed max node id {}",
+                self.next_node_id, max_id.0
+            ));
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
 
-        Ok(node_id)
+impl GraphStore for Graph {
+    fn insert_node(&mut self, entity: EntityId) -> Result<NodeId, KremisError> {
+        Ok(self.insert_node_internal(entity))
     }
 
     fn insert_edge(
@@ -273,14 +956,20 @@ impl GraphStore for Graph {
         if !self.nodes.contains_key(&from) || !self.nodes.contains_key(&to) {
             return Ok(());
         }
-        self.edges.entry(from).or_default().insert(to, weight);
+        let before = self.edges.entry(from).or_default().insert(to, weight);
+        self.link(from, to);
+        // Read-modify-write callers (weighted reinforcement) saturate here
+        self.note_weight_change(from, to, before.map_or(0, |w| w.value()), weight.value());
         Ok(())
     }
 
     fn increment_edge(&mut self, from: NodeId, to: NodeId) -> Result<(), KremisError> {
         let targets = self.edges.entry(from).or_default();
         let current = targets.get(&to).copied().unwrap_or(EdgeWeight::new(0));
-        targets.insert(to, current.increment());
+        let updated = current.increment();
+        targets.insert(to, updated);
+        self.link(from, to);
+        self.note_weight_change(from, to, current.value(), updated.value());
         Ok(())
     }
 
@@ -298,7 +987,7 @@ impl GraphStore for Graph {
     }
 
     fn get_node_by_entity(&self, entity: EntityId) -> Option<NodeId> {
-        self.entity_index.get(&entity).copied()
+        self.entity_node(entity)
     }
 
     fn get_edge(&self, from: NodeId, to: NodeId) -> Result<Option<EdgeWeight>, KremisError> {
@@ -317,90 +1006,39 @@ impl GraphStore for Graph {
             .collect())
     }
 
+    fn in_neighbors(&self, node: NodeId) -> Result<Vec<(NodeId, EdgeWeight)>, KremisError> {
+        Ok(self.predecessors(node))
+    }
+
     fn contains_node(&self, id: NodeId) -> Result<bool, KremisError> {
         Ok(self.nodes.contains_key(&id))
     }
 
     fn traverse(&self, start: NodeId, depth: usize) -> Result<Option<Artifact>, KremisError> {
-        let depth = depth.min(crate::primitives::MAX_TRAVERSAL_DEPTH);
+        self.traverse_filtered(start, depth, EdgeWeight::new(i64::MIN))
+    }
+
+    fn traverse_filtered(
+        &self,
+        start: NodeId,
+        depth: usize,
+        min_weight: EdgeWeight,
+    ) -> Result<Option<Artifact>, KremisError> {
         if !self.contains_node_internal(start) {
             return Ok(None);
         }
+        Ok(Some(self.bfs(
+            start,
+            depth,
+            |node| self.outgoing_steps(node, min_weight),
+            |_, _, _| {},
+        )))
+    }
 
-        let mut visited = BTreeSet::new();
-        let mut queue = VecDeque::new();
-        let mut path = Vec::new();
-        let mut subgraph_edges = Vec::new();
-
-        queue.push_back((start, 0usize));
-        visited.insert(start);
-
-        while let Some((current, current_depth)) = queue.pop_front() {
-            path.push(current);
-
-            if current_depth >= depth {
-                continue;
-            }
-
-            for (neighbor, weight) in self.neighbors_internal(current) {
-                subgraph_edges.push((current, neighbor, weight));
-
-                if !visited.contains(&neighbor) {
-                    visited.insert(neighbor);
-                    queue.push_back((neighbor, current_depth.saturating_add(1)));
-                }
-            }
-        }
-
-        Ok(Some(Artifact::with_subgraph(path, subgraph_edges)))
-    }
-
-    fn traverse_filtered(
-        &self,
-        start: NodeId,
-        depth: usize,
-        min_weight: EdgeWeight,
-    ) -> Result<Option<Artifact>, KremisError> {
-        let depth = depth.min(crate::primitives::MAX_TRAVERSAL_DEPTH);
-        if !self.contains_node_internal(start) {
-            return Ok(None);
-        }
-
-        let mut visited = BTreeSet::new();
-        let mut queue = VecDeque::new();
-        let mut path = Vec::new();
-        let mut subgraph_edges = Vec::new();
-
-        queue.push_back((start, 0usize));
-        visited.insert(start);
-
-        while let Some((current, current_depth)) = queue.pop_front() {
-            path.push(current);
-
-            if current_depth >= depth {
-                continue;
-            }
-
-            for (neighbor, weight) in self.neighbors_internal(current) {
-                // Filter by minimum weight
-                if weight.value() >= min_weight.value() {
-                    subgraph_edges.push((current, neighbor, weight));
-
-                    if !visited.contains(&neighbor) {
-                        visited.insert(neighbor);
-                        queue.push_back((neighbor, current_depth.saturating_add(1)));
-                    }
-                }
-            }
-        }
-
-        Ok(Some(Artifact::with_subgraph(path, subgraph_edges)))
-    }
-
-    fn intersect(&self, nodes: &[NodeId]) -> Result<Vec<NodeId>, KremisError> {
-        if nodes.is_empty() {
-            return Ok(Vec::new());
-        }
+    fn intersect(&self, nodes: &[NodeId]) -> Result<Vec<NodeId>, KremisError> {
+        if nodes.is_empty() {
+            return Ok(Vec::new());
+        }
 
         // Get neighbors of first node
         let first_neighbors: BTreeSet<_> =
@@ -435,45 +1073,37 @@ impl GraphStore for Graph {
 
         // Dijkstra with cost = i64::MAX - weight (to find maximum weight path)
         // Using BTreeMap for deterministic ordering
-        let mut dist: BTreeMap<NodeId, i64> = BTreeMap::new();
+        let mut dist: BTreeMap<NodeId, u128> = BTreeMap::new();
         let mut prev: BTreeMap<NodeId, NodeId> = BTreeMap::new();
         let mut visited = BTreeSet::new();
 
         dist.insert(start, 0);
 
-        loop {
-            // Find unvisited node with minimum distance
-            let current = dist
-                .iter()
-                .filter(|(n, _)| !visited.contains(*n))
-                .min_by_key(|(_, d)| *d)
-                .map(|(n, _)| *n);
-
-            let Some(current) = current else {
-                break;
-            };
+        // Min-heap keyed by (distance, NodeId): equal distances pop in
+        // ascending NodeId order, so ties resolve deterministically.
+        let mut frontier = BinaryHeap::from([Reverse((0u128, start))]);
 
+        while let Some(Reverse((current_dist, current))) = frontier.pop() {
             if current == end {
                 break;
             }
 
-            visited.insert(current);
-            let current_dist = dist[&current];
+            // Stale entry: the node was already settled at a lower distance
+            if !visited.insert(current) {
+                continue;
+            }
 
             for (neighbor, weight) in self.neighbors_internal(current) {
                 if visited.contains(&neighbor) {
                     continue;
                 }
 
-                // Cost = i64::MAX - weight (higher weight = lower cost = preferred)
-                // Clamp negative weights to 0 to maintain Dijkstra invariant
-                let clamped_weight = weight.value().max(0);
-                let edge_cost = i64::MAX.saturating_sub(clamped_weight);
-                let new_dist = current_dist.saturating_add(edge_cost);
+                let new_dist = current_dist.saturating_add(strongest_edge_cost(weight));
 
                 if !dist.contains_key(&neighbor) || new_dist < dist[&neighbor] {
                     dist.insert(neighbor, new_dist);
                     prev.insert(neighbor, current);
+                    frontier.push(Reverse((new_dist, neighbor)));
                 }
             }
         }
@@ -538,6 +1168,20 @@ impl GraphStore for Graph {
         }
         Ok(result)
     }
+
+    /// Returns `KremisError::EdgeNotFound` if the edge does not exist.
+    fn set_edge_label(
+        &mut self,
+        from: NodeId,
+        to: NodeId,
+        label: Attribute,
+    ) -> Result<(), KremisError> {
+        if !self.contains_edge(from, to) {
+            return Err(KremisError::EdgeNotFound(from, to));
+        }
+        self.edge_labels.insert((from, to), label);
+        Ok(())
+    }
 }
 
 // =============================================================================
@@ -610,398 +1254,3726 @@ impl Graph {
         }
     }
 
-    /// Bounded traverse that enforces MAX_TRAVERSAL_DEPTH.
-    pub fn traverse_bounded(
-        &self,
-        start: NodeId,
-        depth: usize,
-    ) -> Result<Option<Artifact>, KremisError> {
-        use crate::primitives::MAX_TRAVERSAL_DEPTH;
-        self.traverse(start, depth.min(MAX_TRAVERSAL_DEPTH))
+    /// Common neighbors of all `nodes`, ranked by combined incoming weight.
+    ///
+    /// Each result carries the saturating sum of edge weights from every
+    /// input node. Sorted by descending weight, ties broken by ascending
+    /// `NodeId`. Duplicate inputs are counted once.
+    #[must_use]
+    pub fn intersect_ranked(&self, nodes: &[NodeId]) -> Vec<(NodeId, i64)> {
+        let inputs: BTreeSet<NodeId> = nodes.iter().copied().collect();
+        let Some(&first) = inputs.first() else {
+            return Vec::new();
+        };
+
+        let mut ranked: Vec<(NodeId, i64)> = self
+            .neighbors_internal(first)
+            .filter_map(|(candidate, _)| {
+                inputs
+                    .iter()
+                    .try_fold(0i64, |total, &input| {
+                        self.get_edge_internal(input, candidate)
+                            .map(|w| total.saturating_add(w.value()))
+                    })
+                    .map(|total| (candidate, total))
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        ranked
     }
-}
 
-// =============================================================================
-// SERIALIZATION SUPPORT
-// =============================================================================
+    /// Entities sharing at least `min_shared` outgoing targets with `entity`.
+    ///
+    /// Co-occurrence query: two entities are related when they point at the
+    /// same nodes, even without a direct edge between them. A `min_shared`
+    /// of 0 is treated as 1. Results are sorted by `EntityId`.
+    pub fn related_by_shared_target(&self, entity: EntityId, min_shared: usize) -> Vec<EntityId> {
+        let Some(origin) = self.entity_node(entity) else {
+            return Vec::new();
+        };
+        let Some(targets) = self.edges.get(&origin) else {
+            return Vec::new();
+        };
+        let min_shared = min_shared.max(1);
 
-use serde::{Deserialize, Serialize};
+        let mut related: Vec<EntityId> = self
+            .edges
+            .iter()
+            .filter(|(node, _)| **node != origin)
+            .filter(|(_, other)| {
+                other.keys().filter(|t| targets.contains_key(t)).count() >= min_shared
+            })
+            .filter_map(|(node, _)| self.nodes.get(node).map(|n| n.entity))
+            .collect();
+        related.sort();
+        related
+    }
 
-/// Serializable representation of the graph for persistence.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SerializableGraph {
-    pub nodes: Vec<Node>,
-    pub edges: Vec<(NodeId, NodeId, EdgeWeight)>,
-    pub next_node_id: u64,
-    #[serde(default)]
-    pub properties: Vec<(u64, String, String)>,
-}
+    /// Best-first traversal: expand the strongest frontier edge next.
+    ///
+    /// Like `traverse`, but the frontier is a priority queue ordered by the
+    /// weight of the edge that reached each node (descending, ties by
+    /// ascending NodeId) instead of FIFO. A node is placed in the path when
+    /// first popped. Depth is bounded by `MAX_TRAVERSAL_DEPTH`.
+    #[must_use]
+    pub fn traverse_best_first(&self, start: NodeId, depth: usize) -> Option<Artifact> {
+        if !self.contains_node_internal(start) {
+            return None;
+        }
+        let depth = depth.min(crate::primitives::MAX_TRAVERSAL_DEPTH);
 
-impl From<&Graph> for SerializableGraph {
-    fn from(graph: &Graph) -> Self {
-        let mut properties = Vec::new();
-        for node in graph.nodes.values() {
-            if let Ok(props) = graph.get_properties(node.id) {
-                for (attr, val) in props {
-                    properties.push((
-                        node.id.0,
-                        attr.as_str().to_string(),
-                        val.as_str().to_string(),
+        let mut visited = BTreeSet::new();
+        let mut path = Vec::new();
+        let mut subgraph_edges = Vec::new();
+        let mut frontier = BinaryHeap::from([(i64::MAX, Reverse(start), 0usize)]);
+
+        while let Some((_, Reverse(current), current_depth)) = frontier.pop() {
+            if !visited.insert(current) {
+                continue;
+            }
+            path.push(current);
+            if current_depth >= depth {
+                continue;
+            }
+
+            for (neighbor, weight) in self.neighbors_internal(current) {
+                subgraph_edges.push((current, neighbor, weight));
+                if !visited.contains(&neighbor) {
+                    frontier.push((
+                        weight.value(),
+                        Reverse(neighbor),
+                        current_depth.saturating_add(1),
                     ));
                 }
             }
         }
-        Self {
-            nodes: graph.nodes.values().cloned().collect(),
-            edges: graph.edges().collect(),
-            next_node_id: graph.next_node_id,
-            properties,
-        }
+
+        Some(Artifact::with_subgraph(path, subgraph_edges))
     }
-}
 
-impl From<SerializableGraph> for Graph {
-    fn from(sg: SerializableGraph) -> Self {
-        let mut graph = Graph::new();
-        graph.next_node_id = sg.next_node_id;
+    /// Nodes with an edge into `node`, with that edge's weight.
+    ///
+    /// Served from the reverse adjacency index in O(in-degree). Sorted by
+    /// `NodeId`.
+    #[must_use]
+    pub fn predecessors(&self, node: NodeId) -> Vec<(NodeId, EdgeWeight)> {
+        self.reverse_edges
+            .get(&node)
+            .into_iter()
+            .flatten()
+            .filter_map(|&from| self.get_edge_internal(from, node).map(|w| (from, w)))
+            .collect()
+    }
 
-        for node in sg.nodes {
-            graph.nodes.insert(node.id, node.clone());
-            graph.entity_index.insert(node.entity, node.id);
+    /// Forward and reverse reachability around `node`, merged.
+    ///
+    /// Runs a BFS over outgoing edges and a BFS over incoming edges, each up
+    /// to `depth`. The path lists forward nodes first, then reverse-only
+    /// nodes, each in BFS order. Subgraph edges keep their real direction
+    /// and appear once. Returns `None` if the node does not exist.
+    #[must_use]
+    pub fn context(&self, node: NodeId, depth: usize) -> Option<Artifact> {
+        if !self.contains_node_internal(node) {
+            return None;
         }
+        let depth = depth.min(crate::primitives::MAX_TRAVERSAL_DEPTH);
 
-        for (from, to, weight) in sg.edges {
-            let _ = graph.insert_edge(from, to, weight);
+        let mut path = Vec::new();
+        let mut seen_nodes = BTreeSet::new();
+        let mut subgraph_edges = Vec::new();
+        let mut seen_edges = BTreeSet::new();
+
+        for forward in [true, false] {
+            let mut visited = BTreeSet::from([node]);
+            let mut queue = VecDeque::from([(node, 0usize)]);
+
+            while let Some((current, current_depth)) = queue.pop_front() {
+                if seen_nodes.insert(current) {
+                    path.push(current);
+                }
+                if current_depth >= depth {
+                    continue;
+                }
+
+                let next: Vec<(NodeId, EdgeWeight)> = if forward {
+                    self.neighbors_internal(current).collect()
+                } else {
+                    self.predecessors(current)
+                };
+                for (other, weight) in next {
+                    let edge = if forward {
+                        (current, other)
+                    } else {
+                        (other, current)
+                    };
+                    if seen_edges.insert(edge) {
+                        subgraph_edges.push((edge.0, edge.1, weight));
+                    }
+                    if visited.insert(other) {
+                        queue.push_back((other, current_depth.saturating_add(1)));
+                    }
+                }
+            }
         }
 
-        for (node_id, attr, val) in sg.properties {
-            let _ = graph.store_property(NodeId(node_id), Attribute::new(&attr), Value::new(&val));
+        Some(Artifact::with_subgraph(path, subgraph_edges))
+    }
+
+    /// BFS that follows edges in both directions.
+    ///
+    /// Each node expands its outgoing edges, then its incoming edges, as if
+    /// the graph were undirected. Subgraph edges keep their real direction
+    /// and appear once. Depth is bounded by `MAX_TRAVERSAL_DEPTH`. Returns
+    /// `None` if the start node does not exist.
+    #[must_use]
+    pub fn traverse_undirected(&self, start: NodeId, depth: usize) -> Option<Artifact> {
+        if !self.contains_node_internal(start) {
+            return None;
         }
+        let mut artifact = self.bfs(
+            start,
+            depth,
+            |node| {
+                let mut steps = self.outgoing_steps(node, EdgeWeight::new(i64::MIN));
+                steps.extend(
+                    self.predecessors(node)
+                        .into_iter()
+                        .map(|(from, weight)| (from, node, weight, from)),
+                );
+                steps
+            },
+            |_, _, _| {},
+        );
 
-        graph
+        // Each edge is seen from both ends; keep its first appearance.
+        let mut seen_edges = BTreeSet::new();
+        if let Some(edges) = artifact.subgraph.as_mut() {
+            edges.retain(|&(from, to, _)| seen_edges.insert((from, to)));
+        }
+        Some(artifact)
     }
-}
 
-// =============================================================================
-// TESTS
-// =============================================================================
+    /// BFS that expands at most `max_fanout` edges per node.
+    ///
+    /// Each node follows only its heaviest outgoing edges, ranked as in
+    /// [`top_neighbors`], so a single hub cannot flood the traversal. Only
+    /// followed edges enter the subgraph. Depth is bounded by
+    /// `MAX_TRAVERSAL_DEPTH`. Returns `None` if the start node does not exist.
+    #[must_use]
+    pub fn traverse_capped_fanout(
+        &self,
+        start: NodeId,
+        depth: usize,
+        max_fanout: usize,
+    ) -> Option<Artifact> {
+        if !self.contains_node_internal(start) {
+            return None;
+        }
+        Some(self.bfs(
+            start,
+            depth,
+            |node| {
+                top_neighbors(self, node, max_fanout)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(neighbor, weight)| (node, neighbor, weight, neighbor))
+                    .collect()
+            },
+            |_, _, _| {},
+        ))
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// BFS like `traverse` that also returns the unexpanded frontier.
+    ///
+    /// The frontier lists, sorted by `NodeId`, the nodes reached at exactly
+    /// `depth` hops; their outgoing edges were not followed, so a later
+    /// traversal from each of them continues where this one stopped. Depth is
+    /// bounded by `MAX_TRAVERSAL_DEPTH`. Returns `None` if the start node does
+    /// not exist.
+    #[must_use]
+    pub fn traverse_with_frontier(
+        &self,
+        start: NodeId,
+        depth: usize,
+    ) -> Option<(Artifact, Vec<NodeId>)> {
+        if !self.contains_node_internal(start) {
+            return None;
+        }
+        let depth = depth.min(crate::primitives::MAX_TRAVERSAL_DEPTH);
 
-    #[test]
-    fn insert_and_lookup_node() {
-        let mut graph = Graph::new();
-        let entity = EntityId(42);
+        let mut frontier = Vec::new();
+        let artifact = self.bfs(
+            start,
+            depth,
+            |node| self.outgoing_steps(node, EdgeWeight::new(i64::MIN)),
+            |node, node_depth, _| {
+                if node_depth >= depth {
+                    frontier.push(node);
+                }
+            },
+        );
 
-        let node_id = graph.insert_node(entity).expect("insert");
-        let node = graph.lookup(node_id).expect("lookup");
+        frontier.sort();
+        Some((artifact, frontier))
+    }
 
-        assert!(node.is_some());
-        assert_eq!(node.map(|n| n.entity), Some(entity));
+    /// BFS like `traverse` that visits at most `max_nodes` distinct nodes.
+    ///
+    /// Runs [`traverse_interruptible`] with the budget as its stop predicate.
+    /// Neighbors past the budget are neither visited nor linked in the
+    /// subgraph, and the returned flag is `true` when any were dropped. The
+    /// start node always counts, so a budget of 0 behaves like 1. Depth is
+    /// bounded by `MAX_TRAVERSAL_DEPTH`. Returns `None` if the start node
+    /// does not exist.
+    #[must_use]
+    pub fn traverse_with_budget(
+        &self,
+        start: NodeId,
+        depth: usize,
+        max_nodes: usize,
+    ) -> Option<(Artifact, bool)> {
+        let max_nodes = max_nodes.max(1);
+        let (mut artifact, truncated) =
+            traverse_interruptible(self, start, depth, EdgeWeight::new(i64::MIN), |visited| {
+                visited >= max_nodes
+            })
+            .ok()
+            .flatten()?;
+
+        if truncated {
+            let kept: BTreeSet<NodeId> = artifact.path.iter().copied().collect();
+            if let Some(edges) = artifact.subgraph.as_mut() {
+                edges.retain(|(_, to, _)| kept.contains(to));
+            }
+        }
+        Some((artifact, truncated))
     }
 
-    #[test]
-    fn insert_duplicate_entity_returns_same_node() {
-        let mut graph = Graph::new();
-        let entity = EntityId(42);
+    /// BFS like `traverse` that also counts the work it did.
+    ///
+    /// The [`TraverseCost`] holds plain counters rather than timings, so the
+    /// same graph always reports the same cost. Depth is bounded by
+    /// `MAX_TRAVERSAL_DEPTH`. Returns `None` if the start node does not
+    /// exist.
+    #[must_use]
+    pub fn traverse_instrumented(
+        &self,
+        start: NodeId,
+        depth: usize,
+    ) -> Option<(Artifact, TraverseCost)> {
+        if !self.contains_node_internal(start) {
+            return None;
+        }
 
-        let first = graph.insert_node(entity).expect("insert");
-        let second = graph.insert_node(entity).expect("insert");
+        let mut cost = TraverseCost::default();
+        let artifact = self.bfs(
+            start,
+            depth,
+            |node| self.outgoing_steps(node, EdgeWeight::new(i64::MIN)),
+            |_, _, queue_len| {
+                cost.nodes_visited = cost.nodes_visited.saturating_add(1);
+                cost.max_queue_size = cost.max_queue_size.max(queue_len);
+            },
+        );
+        // Plain BFS records every edge it examines.
+        cost.edges_examined = artifact.subgraph.as_ref().map_or(0, Vec::len);
 
-        assert_eq!(first, second);
-        assert_eq!(graph.node_count().expect("count"), 1);
+        Some((artifact, cost))
     }
 
-    #[test]
-    fn increment_edge_creates_and_increments() {
-        let mut graph = Graph::new();
-        let a = graph.insert_node(EntityId(1)).expect("insert");
-        let b = graph.insert_node(EntityId(2)).expect("insert");
-
-        // First increment creates edge with weight 1
-        graph.increment_edge(a, b).expect("increment");
+    /// BFS grouped by distance from `start`.
+    ///
+    /// Maps each hop count (0 is the start itself) to the nodes first reached
+    /// at that depth, sorted by `NodeId`. Depth is bounded by
+    /// `MAX_TRAVERSAL_DEPTH`. Empty if the start node does not exist.
+    #[must_use]
+    pub fn traverse_levels(&self, start: NodeId, depth: usize) -> BTreeMap<usize, Vec<NodeId>> {
+        let mut levels: BTreeMap<usize, Vec<NodeId>> = BTreeMap::new();
+        if !self.contains_node_internal(start) {
+            return levels;
+        }
+
+        self.bfs(
+            start,
+            depth,
+            |node| self.outgoing_steps(node, EdgeWeight::new(i64::MIN)),
+            |node, node_depth, _| levels.entry(node_depth).or_default().push(node),
+        );
+        for nodes in levels.values_mut() {
+            nodes.sort();
+        }
+
+        levels
+    }
+
+    /// [`breadth_first`] over the in-memory edges, which cannot fail.
+    fn bfs(
+        &self,
+        start: NodeId,
+        depth: usize,
+        mut expand: impl FnMut(NodeId) -> Vec<BfsStep>,
+        visit: impl FnMut(NodeId, usize, usize),
+    ) -> Artifact {
+        let Ok((artifact, _)) =
+            breadth_first::<Infallible>(start, depth, |node| Ok(expand(node)), visit, |_| false);
+        artifact
+    }
+
+    /// Outgoing edges of `node` at or above `min_weight`, as BFS steps.
+    fn outgoing_steps(&self, node: NodeId, min_weight: EdgeWeight) -> Vec<BfsStep> {
+        self.neighbors_internal(node)
+            .filter(|(_, weight)| weight.value() >= min_weight.value())
+            .map(|(neighbor, weight)| (node, neighbor, weight, neighbor))
+            .collect()
+    }
+
+    /// Bounded traverse that enforces MAX_TRAVERSAL_DEPTH.
+    pub fn traverse_bounded(
+        &self,
+        start: NodeId,
+        depth: usize,
+    ) -> Result<Option<Artifact>, KremisError> {
+        use crate::primitives::MAX_TRAVERSAL_DEPTH;
+        self.traverse(start, depth.min(MAX_TRAVERSAL_DEPTH))
+    }
+}
+
+// =============================================================================
+// TRAVERSE COST
+// =============================================================================
+
+/// Work done by [`Graph::traverse_instrumented`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TraverseCost {
+    /// Nodes dequeued and added to the path.
+    pub nodes_visited: usize,
+    /// Outgoing edges followed from expanded nodes.
+    pub edges_examined: usize,
+    /// Largest BFS queue length reached.
+    pub max_queue_size: usize,
+}
+
+// =============================================================================
+// PATH COMPARISON
+// =============================================================================
+
+/// Fewest-hop and strongest-weight routes between the same two nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathComparison {
+    /// Fewest-hop path (BFS, NodeId tie-break).
+    pub shortest: Vec<NodeId>,
+    /// Path chosen by `strongest_path`.
+    pub strongest: Vec<NodeId>,
+    /// Edges in `shortest`.
+    pub shortest_hops: usize,
+    /// Edges in `strongest`.
+    pub strongest_hops: usize,
+    /// Saturating sum of edge weights along `shortest`.
+    pub shortest_weight: i64,
+    /// Saturating sum of edge weights along `strongest`.
+    pub strongest_weight: i64,
+}
+
+impl PathComparison {
+    /// Check whether the two strategies picked different routes.
+    #[must_use]
+    pub fn diverges(&self) -> bool {
+        self.shortest != self.strongest
+    }
+}
+
+impl Graph {
+    /// Fewest-hop path from `start` to `end`.
+    ///
+    /// Neighbors are expanded in NodeId order, so among equally short
+    /// routes the lexicographically smallest one wins.
+    #[must_use]
+    pub fn shortest_path(&self, start: NodeId, end: NodeId) -> Option<Vec<NodeId>> {
+        if !self.contains_node_internal(start) || !self.contains_node_internal(end) {
+            return None;
+        }
+
+        let mut prev: BTreeMap<NodeId, NodeId> = BTreeMap::new();
+        let mut visited = BTreeSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(current) = queue.pop_front() {
+            if current == end {
+                let mut path = vec![end];
+                let mut node = end;
+                while let Some(&p) = prev.get(&node) {
+                    path.push(p);
+                    node = p;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for (neighbor, _) in self.neighbors_internal(current) {
+                if visited.insert(neighbor) {
+                    prev.insert(neighbor, current);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        None
+    }
+
+    /// Every simple path from `start` to `end` with at most `max_depth` edges.
+    ///
+    /// `max_depth` is capped at `MAX_TRAVERSAL_DEPTH`. Paths come out in
+    /// lexicographic NodeId order, and enumeration stops once `max_paths`
+    /// paths are found. Empty if either node is missing.
+    #[must_use]
+    pub fn all_paths(
+        &self,
+        start: NodeId,
+        end: NodeId,
+        max_depth: usize,
+        max_paths: usize,
+    ) -> Vec<Vec<NodeId>> {
+        let mut paths = Vec::new();
+        if max_paths == 0
+            || !self.contains_node_internal(start)
+            || !self.contains_node_internal(end)
+        {
+            return paths;
+        }
+        let max_depth = max_depth.min(crate::primitives::MAX_TRAVERSAL_DEPTH);
+        let mut path = vec![start];
+        let mut on_path = BTreeSet::from([start]);
+        self.collect_paths(
+            &mut path,
+            &mut on_path,
+            end,
+            max_depth,
+            max_paths,
+            &mut paths,
+        );
+        paths
+    }
+
+    /// Depth-first step of `all_paths`, extending `path` in NodeId order.
+    fn collect_paths(
+        &self,
+        path: &mut Vec<NodeId>,
+        on_path: &mut BTreeSet<NodeId>,
+        end: NodeId,
+        max_depth: usize,
+        max_paths: usize,
+        paths: &mut Vec<Vec<NodeId>>,
+    ) {
+        let Some(&current) = path.last() else {
+            return;
+        };
+        if current == end {
+            paths.push(path.clone());
+            return;
+        }
+        if path.len() > max_depth {
+            return;
+        }
+        for (neighbor, _) in self.neighbors_internal(current) {
+            if paths.len() >= max_paths {
+                return;
+            }
+            if !on_path.insert(neighbor) {
+                continue;
+            }
+            path.push(neighbor);
+            self.collect_paths(path, on_path, end, max_depth, max_paths, paths);
+            path.pop();
+            on_path.remove(&neighbor);
+        }
+    }
+
+    /// Compare the fewest-hop and strongest-weight paths between two nodes.
+    ///
+    /// Returns `None` if either node is missing or `end` is unreachable.
+    /// "Strongest" means the lowest `strongest_path` cost (see
+    /// [`strongest_edge_cost`]), not the highest total weight: every hop
+    /// costs nearly `i64::MAX`, so a longer route with a higher total weight
+    /// only wins when its weights are close to `i64::MAX`. In practice the
+    /// two paths diverge between routes of equal length.
+    #[must_use]
+    pub fn compare_paths(&self, start: NodeId, end: NodeId) -> Option<PathComparison> {
+        let shortest = self.shortest_path(start, end)?;
+        let strongest = self.strongest_path(start, end).ok().flatten()?;
+        Some(PathComparison {
+            shortest_hops: shortest.len().saturating_sub(1),
+            strongest_hops: strongest.len().saturating_sub(1),
+            shortest_weight: self.path_weight(&shortest),
+            strongest_weight: self.path_weight(&strongest),
+            shortest,
+            strongest,
+        })
+    }
+
+    /// Saturating sum of edge weights along a path (missing edges count 0).
+    fn path_weight(&self, path: &[NodeId]) -> i64 {
+        path.windows(2)
+            .filter_map(|pair| self.get_edge_internal(pair[0], pair[1]))
+            .fold(0i64, |total, w| total.saturating_add(w.value()))
+    }
+
+    /// Up to `k` loopless paths from `start` to `end`, strongest first.
+    ///
+    /// Yen's algorithm over the `strongest_path` cost model (see
+    /// [`strongest_edge_cost`]), so the first path is the one
+    /// `strongest_path` returns. Equal costs break by the lexicographic
+    /// NodeId order of the path.
+    #[must_use]
+    pub fn k_strongest_paths(&self, start: NodeId, end: NodeId, k: usize) -> Vec<Vec<NodeId>> {
+        let mut found: Vec<Vec<NodeId>> = Vec::new();
+        if k == 0 {
+            return found;
+        }
+        let Some(first) = self.cheapest_path(start, end, &BTreeSet::new(), &BTreeSet::new()) else {
+            return found;
+        };
+        found.push(first);
+
+        // Candidates ordered by (cost, path), so ties break by NodeId
+        let mut candidates: BTreeSet<(u128, Vec<NodeId>)> = BTreeSet::new();
+        while found.len() < k {
+            let Some(last) = found.last().cloned() else {
+                break;
+            };
+            for i in 0..last.len().saturating_sub(1) {
+                let root = &last[..=i];
+                // Block the next edge of every found path sharing this root
+                let blocked_edges: BTreeSet<(NodeId, NodeId)> = found
+                    .iter()
+                    .filter(|p| p.get(..=i) == Some(root))
+                    .filter_map(|p| p.get(i.saturating_add(1)).map(|&next| (last[i], next)))
+                    .collect();
+                let blocked_nodes: BTreeSet<NodeId> = root[..i].iter().copied().collect();
+
+                if let Some(spur) = self.cheapest_path(last[i], end, &blocked_edges, &blocked_nodes)
+                {
+                    let mut path = root[..i].to_vec();
+                    path.extend(spur);
+                    if !found.contains(&path) {
+                        candidates.insert((self.path_cost(&path), path));
+                    }
+                }
+            }
+            let Some((_, next)) = candidates.pop_first() else {
+                break;
+            };
+            found.push(next);
+        }
+        found
+    }
+
+    /// Unsaturated cost of a path (missing edges count as weight 0).
+    fn path_cost(&self, path: &[NodeId]) -> u128 {
+        path.windows(2)
+            .map(|pair| {
+                strongest_edge_cost(
+                    self.get_edge_internal(pair[0], pair[1])
+                        .unwrap_or(EdgeWeight::new(0)),
+                )
+            })
+            .fold(0u128, u128::saturating_add)
+    }
+
+    /// Dijkstra under the strongest-path model, avoiding blocked edges and nodes.
+    ///
+    /// Ties resolve to the smallest NodeId, since the frontier is ordered by
+    /// `(cost, node)` and neighbors are visited in NodeId order.
+    fn cheapest_path(
+        &self,
+        start: NodeId,
+        end: NodeId,
+        blocked_edges: &BTreeSet<(NodeId, NodeId)>,
+        blocked_nodes: &BTreeSet<NodeId>,
+    ) -> Option<Vec<NodeId>> {
+        if !self.contains_node_internal(start)
+            || !self.contains_node_internal(end)
+            || blocked_nodes.contains(&start)
+        {
+            return None;
+        }
+
+        let mut dist: BTreeMap<NodeId, u128> = BTreeMap::from([(start, 0)]);
+        let mut prev: BTreeMap<NodeId, NodeId> = BTreeMap::new();
+        let mut frontier: BTreeSet<(u128, NodeId)> = BTreeSet::from([(0, start)]);
+        let mut visited = BTreeSet::new();
+
+        while let Some((cost, current)) = frontier.pop_first() {
+            if current == end {
+                break;
+            }
+            if !visited.insert(current) {
+                continue;
+            }
+            for (neighbor, weight) in self.neighbors_internal(current) {
+                if visited.contains(&neighbor)
+                    || blocked_nodes.contains(&neighbor)
+                    || blocked_edges.contains(&(current, neighbor))
+                {
+                    continue;
+                }
+                let next = cost.saturating_add(strongest_edge_cost(weight));
+                if dist.get(&neighbor).is_none_or(|&d| next < d) {
+                    if let Some(old) = dist.insert(neighbor, next) {
+                        frontier.remove(&(old, neighbor));
+                    }
+                    prev.insert(neighbor, current);
+                    frontier.insert((next, neighbor));
+                }
+            }
+        }
+
+        if !dist.contains_key(&end) {
+            return None;
+        }
+        let mut path = vec![end];
+        let mut current = end;
+        while current != start {
+            current = *prev.get(&current)?;
+            path.push(current);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+// =============================================================================
+// REPAIR
+// =============================================================================
+
+/// Counts of the fixes applied by [`Graph::repair`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RepairReport {
+    /// Edges removed because an endpoint did not exist.
+    pub dangling_edges: usize,
+    /// `entity_index` entries removed for pointing at a missing or mismatched
+    /// node, and merged entities pointing at a missing node.
+    pub stale_index_entries: usize,
+    /// Empty adjacency maps removed.
+    pub empty_adjacency_maps: usize,
+    /// Whether `next_node_id` was raised above the largest node id.
+    pub next_node_id_fixed: bool,
+}
+
+impl RepairReport {
+    /// Check if the graph needed no repair.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Graph {
+    /// Fix the structural problems reported by [`Graph::check_invariants`].
+    ///
+    /// Removes edges (and their labels) with a missing endpoint, stale
+    /// `entity_index` entries, and empty adjacency maps, raises
+    /// `next_node_id` past the largest node id, and rebuilds the reverse
+    /// index. Nodes are never removed.
+    pub fn repair(&mut self) -> RepairReport {
+        let mut report = RepairReport::default();
+
+        let nodes = &self.nodes;
+        let before = self.entity_index.len();
+        let merged_before = self.merged_entities.len();
+        self.entity_index
+            .retain(|entity, id| nodes.get(id).is_some_and(|node| node.entity == *entity));
+        let index = &self.entity_index;
+        self.merged_entities
+            .retain(|entity, id| nodes.contains_key(id) && !index.contains_key(entity));
+        report.stale_index_entries = before
+            .saturating_add(merged_before)
+            .saturating_sub(self.entity_index.len())
+            .saturating_sub(self.merged_entities.len());
+
+        let mut dangling = Vec::new();
+        for (from, targets) in &mut self.edges {
+            if targets.is_empty() {
+                report.empty_adjacency_maps = report.empty_adjacency_maps.saturating_add(1);
+                continue;
+            }
+            let source_exists = nodes.contains_key(from);
+            targets.retain(|to, _| {
+                let keep = source_exists && nodes.contains_key(to);
+                if !keep {
+                    dangling.push((*from, *to));
+                }
+                keep
+            });
+        }
+        report.dangling_edges = dangling.len();
+        for edge in &dangling {
+            self.edge_labels.remove(edge);
+        }
+        self.edges.retain(|_, targets| !targets.is_empty());
+
+        if let Some(max_id) = self.nodes.keys().next_back()
+            && self.next_node_id <= max_id.0
+        {
+            self.next_node_id = max_id.0.saturating_add(1);
+            report.next_node_id_fixed = true;
+        }
+
+        self.rebuild_reverse_edges();
+        report
+    }
+}
+
+// =============================================================================
+// INTERSECTION
+// =============================================================================
+
+impl Graph {
+    /// Common neighbors of all `nodes`, with their bottleneck strength.
+    ///
+    /// The strength is the minimum weight among the edges from each input
+    /// node to that neighbor. Same neighbors as `intersect`, sorted by
+    /// NodeId; see [`sort_by_strength`] to rank them.
+    #[must_use]
+    pub fn intersect_weighted(&self, nodes: &[NodeId]) -> Vec<(NodeId, i64)> {
+        let Some((&first, rest)) = nodes.split_first() else {
+            return Vec::new();
+        };
+
+        let mut result: BTreeMap<NodeId, i64> = self
+            .neighbors_internal(first)
+            .map(|(n, w)| (n, w.value()))
+            .collect();
+        for &node in rest {
+            let weights: BTreeMap<NodeId, EdgeWeight> = self.neighbors_internal(node).collect();
+            result.retain(|neighbor, strength| match weights.get(neighbor) {
+                Some(w) => {
+                    *strength = (*strength).min(w.value());
+                    true
+                }
+                None => false,
+            });
+        }
+        result.into_iter().collect()
+    }
+
+    /// Neighbors connected to at least `min_matches` of the input `nodes`.
+    ///
+    /// Sorted by NodeId. A `min_matches` of 0 is treated as 1 (the union of
+    /// all neighbors); `nodes.len()` gives exactly `intersect`.
+    #[must_use]
+    pub fn intersect_threshold(&self, nodes: &[NodeId], min_matches: usize) -> Vec<NodeId> {
+        let min_matches = min_matches.max(1);
+        let mut matches: BTreeMap<NodeId, usize> = BTreeMap::new();
+        for &node in nodes {
+            for (neighbor, _) in self.neighbors_internal(node) {
+                let count = matches.entry(neighbor).or_default();
+                *count = count.saturating_add(1);
+            }
+        }
+        matches
+            .into_iter()
+            .filter(|&(_, count)| count >= min_matches)
+            .map(|(neighbor, _)| neighbor)
+            .collect()
+    }
+}
+
+/// Sort `(node, strength)` pairs strongest first, ties by ascending NodeId.
+pub fn sort_by_strength(pairs: &mut [(NodeId, i64)]) {
+    pairs.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+}
+
+// =============================================================================
+// CONNECTIVITY
+// =============================================================================
+
+impl Graph {
+    /// Start maintaining weakly connected components incrementally.
+    ///
+    /// Builds a [`ComponentTracker`] from the current graph; afterwards node
+    /// and edge inserts update it in near-constant time, while removals
+    /// rebuild it. Calling this again rebuilds the tracker.
+    pub fn track_components(&mut self) {
+        self.components = Some(Box::new(ComponentTracker::from_graph(self)));
+    }
+
+    /// Component label of `node` from the tracker.
+    ///
+    /// Nodes share a label exactly when they are in the same weakly
+    /// connected component. `None` if tracking is off or the node is absent.
+    #[must_use]
+    pub fn component_of(&self, node: NodeId) -> Option<usize> {
+        self.components.as_ref()?.component_of(node)
+    }
+
+    /// Rebuild the component tracker, if tracking is on.
+    fn refresh_components(&mut self) {
+        if self.components.is_some() {
+            self.track_components();
+        }
+    }
+
+    /// Neighbors of `node` ignoring edge direction, ascending and deduplicated.
+    fn undirected_neighbors(&self, node: NodeId) -> BTreeSet<NodeId> {
+        let mut neighbors: BTreeSet<NodeId> =
+            self.neighbors_internal(node).map(|(to, _)| to).collect();
+        if let Some(sources) = self.reverse_edges.get(&node) {
+            neighbors.extend(sources.iter().copied());
+        }
+        neighbors
+    }
+
+    /// Number of outgoing edges of `node` (0 if absent).
+    #[must_use]
+    pub fn out_degree(&self, node: NodeId) -> usize {
+        self.edges.get(&node).map_or(0, BTreeMap::len)
+    }
+
+    /// Number of incoming edges of `node` (0 if absent), from the reverse index.
+    #[must_use]
+    pub fn in_degree(&self, node: NodeId) -> usize {
+        self.reverse_edges.get(&node).map_or(0, BTreeSet::len)
+    }
+
+    /// Nodes with incoming edges but no outgoing ones, sorted.
+    ///
+    /// Isolated nodes are not sinks.
+    #[must_use]
+    pub fn sink_nodes(&self) -> Vec<NodeId> {
+        self.nodes
+            .keys()
+            .copied()
+            .filter(|&node| self.out_degree(node) == 0 && self.in_degree(node) > 0)
+            .collect()
+    }
+
+    /// Nodes with outgoing edges but no incoming ones, sorted.
+    ///
+    /// Isolated nodes are not sources.
+    #[must_use]
+    pub fn source_nodes(&self) -> Vec<NodeId> {
+        self.nodes
+            .keys()
+            .copied()
+            .filter(|&node| self.in_degree(node) == 0 && self.out_degree(node) > 0)
+            .collect()
+    }
+
+    /// The `k` nodes with the highest degree (in + out), with their degree.
+    ///
+    /// Sorted by descending degree, then ascending `NodeId`. Isolated nodes
+    /// are never hubs.
+    #[must_use]
+    pub fn hubs(&self, k: usize) -> Vec<(NodeId, usize)> {
+        let mut ranked: Vec<(NodeId, usize)> = self
+            .nodes
+            .keys()
+            .map(|&node| {
+                let degree = self.out_degree(node).saturating_add(self.in_degree(node));
+                (node, degree)
+            })
+            .filter(|&(_, degree)| degree > 0)
+            .collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        ranked.truncate(k);
+        ranked
+    }
+
+    /// Degree (in + out) -> number of nodes with that degree.
+    ///
+    /// A self-loop counts once in each direction. Isolated nodes appear
+    /// under degree 0.
+    #[must_use]
+    pub fn degree_histogram(&self) -> BTreeMap<usize, usize> {
+        let mut histogram = BTreeMap::new();
+        for &node in self.nodes.keys() {
+            let degree = self.out_degree(node).saturating_add(self.in_degree(node));
+            let count: &mut usize = histogram.entry(degree).or_default();
+            *count = count.saturating_add(1);
+        }
+        histogram
+    }
+
+    /// Weakly connected components, treating every edge as undirected.
+    ///
+    /// Each component is sorted by `NodeId`; components are ordered by their
+    /// smallest `NodeId`. Isolated nodes form singleton components.
+    #[must_use]
+    pub fn connected_components(&self) -> Vec<Vec<NodeId>> {
+        let mut assigned = BTreeSet::new();
+        let mut components = Vec::new();
+
+        for &root in self.nodes.keys() {
+            if !assigned.insert(root) {
+                continue;
+            }
+            let mut component = vec![root];
+            let mut queue = VecDeque::from([root]);
+            while let Some(current) = queue.pop_front() {
+                for other in self.undirected_neighbors(current) {
+                    if assigned.insert(other) {
+                        component.push(other);
+                        queue.push_back(other);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+        components
+    }
+}
+
+// =============================================================================
+// DIAMETER
+// =============================================================================
+
+/// Which edges a hop-distance query may follow.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EdgeDirection {
+    /// Only outgoing edges.
+    #[default]
+    Directed,
+    /// Outgoing and incoming edges alike.
+    Undirected,
+}
+
+impl Graph {
+    /// Largest hop distance from `node` to any node it reaches.
+    ///
+    /// `direction` chooses whether incoming edges count as hops; a node
+    /// reaching nothing has eccentricity 0. Distances are capped at
+    /// `MAX_TRAVERSAL_DEPTH` in both directions. `None` if the node is
+    /// missing or the graph has more than `MAX_DIAMETER_NODES` nodes.
+    #[must_use]
+    pub fn eccentricity(&self, node: NodeId, direction: EdgeDirection) -> Option<usize> {
+        self.farthest_hop(node, direction, crate::primitives::MAX_TRAVERSAL_DEPTH)
+    }
+
+    /// Largest eccentricity over all nodes in the given `direction`.
+    ///
+    /// `None` if the graph is empty or has more than `MAX_DIAMETER_NODES`
+    /// nodes.
+    #[must_use]
+    pub fn diameter(&self, direction: EdgeDirection) -> Option<usize> {
+        self.nodes
+            .keys()
+            .map(|&node| self.eccentricity(node, direction))
+            .try_fold(None, |max: Option<usize>, ecc| Some(max.max(Some(ecc?))))?
+    }
+
+    /// BFS hop count to the farthest node reached from `node`, up to
+    /// `max_depth`.
+    fn farthest_hop(
+        &self,
+        node: NodeId,
+        direction: EdgeDirection,
+        max_depth: usize,
+    ) -> Option<usize> {
+        if self.nodes.len() > crate::primitives::MAX_DIAMETER_NODES
+            || !self.contains_node_internal(node)
+        {
+            return None;
+        }
+
+        let mut distance = BTreeMap::from([(node, 0usize)]);
+        let mut queue = VecDeque::from([node]);
+        let mut farthest = 0;
+        while let Some(current) = queue.pop_front() {
+            let here = distance.get(&current).copied().unwrap_or_default();
+            if here >= max_depth {
+                continue;
+            }
+            let next = here.saturating_add(1);
+            let adjacent: Vec<NodeId> = match direction {
+                EdgeDirection::Undirected => {
+                    self.undirected_neighbors(current).into_iter().collect()
+                }
+                EdgeDirection::Directed => {
+                    self.neighbors_internal(current).map(|(to, _)| to).collect()
+                }
+            };
+            for neighbor in adjacent {
+                if let std::collections::btree_map::Entry::Vacant(entry) = distance.entry(neighbor)
+                {
+                    entry.insert(next);
+                    farthest = farthest.max(next);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        Some(farthest)
+    }
+}
+
+// =============================================================================
+// CYCLES
+// =============================================================================
+
+impl Graph {
+    /// Check if the graph contains a directed cycle (self-loops included).
+    #[must_use]
+    pub fn has_cycle(&self) -> bool {
+        self.find_cycle().is_some()
+    }
+
+    /// One directed cycle, as a node sequence whose last node links back to
+    /// the first. `None` if the graph is acyclic.
+    ///
+    /// Iterative depth-first search with on-stack/done coloring, so deep
+    /// graphs cannot overflow the call stack. Roots and neighbors are visited
+    /// in NodeId order, so the same graph always yields the same cycle.
+    #[must_use]
+    pub fn find_cycle(&self) -> Option<Vec<NodeId>> {
+        let mut done: BTreeSet<NodeId> = BTreeSet::new();
+
+        for &root in self.nodes.keys() {
+            if done.contains(&root) {
+                continue;
+            }
+            // (node, its neighbors, index of the next neighbor to visit)
+            let mut stack: Vec<(NodeId, Vec<NodeId>, usize)> = vec![(
+                root,
+                self.neighbors_internal(root).map(|(n, _)| n).collect(),
+                0,
+            )];
+            let mut on_stack = BTreeSet::from([root]);
+
+            while let Some((node, neighbors, next)) = stack.last_mut() {
+                let Some(&neighbor) = neighbors.get(*next) else {
+                    on_stack.remove(node);
+                    done.insert(*node);
+                    stack.pop();
+                    continue;
+                };
+                *next = next.saturating_add(1);
+
+                if on_stack.contains(&neighbor) {
+                    let start = stack.iter().position(|(n, _, _)| *n == neighbor)?;
+                    return Some(stack[start..].iter().map(|(n, _, _)| *n).collect());
+                }
+                if !done.contains(&neighbor) {
+                    on_stack.insert(neighbor);
+                    stack.push((
+                        neighbor,
+                        self.neighbors_internal(neighbor).map(|(n, _)| n).collect(),
+                        0,
+                    ));
+                }
+            }
+        }
+        None
+    }
+
+    /// Kahn's-algorithm topological order of every node.
+    ///
+    /// The zero-in-degree frontier is a `BTreeSet`, so ties resolve by
+    /// ascending NodeId and the order is reproducible.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KremisError::CycleDetected` with a node on a cycle if the
+    /// graph is not a DAG; no partial order is returned.
+    pub fn topological_order(&self) -> Result<Vec<NodeId>, KremisError> {
+        let mut in_degree: BTreeMap<NodeId, usize> = self
+            .nodes
+            .keys()
+            .map(|&node| (node, self.in_degree(node)))
+            .collect();
+        let mut frontier: BTreeSet<NodeId> = in_degree
+            .iter()
+            .filter(|(_, degree)| **degree == 0)
+            .map(|(node, _)| *node)
+            .collect();
+        let mut order = Vec::with_capacity(in_degree.len());
+
+        while let Some(node) = frontier.pop_first() {
+            order.push(node);
+            for (neighbor, _) in self.neighbors_internal(node) {
+                if let Some(degree) = in_degree.get_mut(&neighbor) {
+                    *degree = degree.saturating_sub(1);
+                    if *degree == 0 {
+                        frontier.insert(neighbor);
+                    }
+                }
+            }
+        }
+
+        if order.len() < in_degree.len() {
+            let stuck = in_degree
+                .iter()
+                .find(|(_, degree)| **degree > 0)
+                .map(|(node, _)| *node);
+            if let Some(node) = self
+                .find_cycle()
+                .and_then(|cycle| cycle.first().copied())
+                .or(stuck)
+            {
+                return Err(KremisError::CycleDetected(node));
+            }
+        }
+        Ok(order)
+    }
+}
+
+// =============================================================================
+// CENTRALITY
+// =============================================================================
+
+/// Fixed-point scale of centrality scores: `1_000_000` is a score of 1.
+pub const CENTRALITY_SCALE: u64 = 1_000_000;
+
+/// Damping factor of `centrality_scores`, as `numerator / denominator`.
+pub const CENTRALITY_DAMPING: (u64, u64) = (85, 100);
+
+impl Graph {
+    /// PageRank-style centrality in integer millionths.
+    ///
+    /// Every node starts at [`CENTRALITY_SCALE`]. Each iteration a node keeps
+    /// `1 - d` of the scale and receives `d` of each predecessor's score,
+    /// split across that predecessor's outgoing edges in proportion to their
+    /// weight. Edges with non-positive weight carry nothing, and the score of
+    /// nodes without outgoing edges is not redistributed. Integer division
+    /// rounds down, so results depend only on the graph and `iterations`.
+    #[must_use]
+    pub fn centrality_scores(&self, iterations: usize) -> BTreeMap<NodeId, u64> {
+        let (damping, denominator) = CENTRALITY_DAMPING;
+        let base =
+            CENTRALITY_SCALE.saturating_mul(denominator.saturating_sub(damping)) / denominator;
+        let mut scores: BTreeMap<NodeId, u64> = self
+            .nodes
+            .keys()
+            .map(|&id| (id, CENTRALITY_SCALE))
+            .collect();
+
+        for _ in 0..iterations {
+            let mut next: BTreeMap<NodeId, u64> = scores.keys().map(|&id| (id, base)).collect();
+            for (&from, targets) in &self.edges {
+                let Some(&score) = scores.get(&from) else {
+                    continue;
+                };
+                let total: u128 = targets
+                    .values()
+                    .filter(|w| w.value() > 0)
+                    .map(|w| w.value().unsigned_abs() as u128)
+                    .sum();
+                if total == 0 {
+                    continue;
+                }
+                for (to, weight) in targets {
+                    let Some(slot) = next.get_mut(to) else {
+                        continue;
+                    };
+                    if weight.value() <= 0 {
+                        continue;
+                    }
+                    let share = (score as u128)
+                        .saturating_mul(weight.value().unsigned_abs() as u128)
+                        .saturating_mul(damping as u128)
+                        / total.saturating_mul(denominator as u128);
+                    *slot = slot.saturating_add(u64::try_from(share).unwrap_or(u64::MAX));
+                }
+            }
+            scores = next;
+        }
+        scores
+    }
+}
+
+// =============================================================================
+// RANKED NEIGHBORS
+// =============================================================================
+
+/// The `k` heaviest outgoing edges of `node`, heaviest first.
+///
+/// Ties are broken by ascending `NodeId`. Empty if the node is missing or
+/// has no outgoing edges.
+pub fn top_neighbors<G: GraphStore + ?Sized>(
+    graph: &G,
+    node: NodeId,
+    k: usize,
+) -> Result<Vec<(NodeId, EdgeWeight)>, KremisError> {
+    let mut neighbors = graph.neighbors(node)?;
+    neighbors.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    neighbors.truncate(k);
+    Ok(neighbors)
+}
+
+// =============================================================================
+// BREADTH-FIRST CORE
+// =============================================================================
+
+/// One edge taken by [`breadth_first`]: the `(from, to, weight)` recorded in
+/// the subgraph, then the node the walk moves on to.
+type BfsStep = (NodeId, NodeId, EdgeWeight, NodeId);
+
+/// Breadth-first skeleton shared by every BFS traversal.
+///
+/// `expand` lists the steps out of a node below the depth bound; each step's
+/// edge enters the subgraph and its target is queued when first seen.
+/// `visit` sees each visited node with its depth and the queue length just
+/// before it was dequeued. `should_stop` is called with the number of nodes
+/// visited so far before each node after the start is visited; returning
+/// `true` ends the walk with the `bool` flag set. Depth is bounded by
+/// `MAX_TRAVERSAL_DEPTH`. The caller checks that `start` exists.
+fn breadth_first<E>(
+    start: NodeId,
+    depth: usize,
+    mut expand: impl FnMut(NodeId) -> Result<Vec<BfsStep>, E>,
+    mut visit: impl FnMut(NodeId, usize, usize),
+    mut should_stop: impl FnMut(usize) -> bool,
+) -> Result<(Artifact, bool), E> {
+    let depth = depth.min(crate::primitives::MAX_TRAVERSAL_DEPTH);
+
+    let mut visited = BTreeSet::from([start]);
+    let mut queue = VecDeque::from([(start, 0usize)]);
+    let mut path = Vec::new();
+    let mut subgraph_edges = Vec::new();
+    let mut interrupted = false;
+
+    while let Some((current, current_depth)) = queue.pop_front() {
+        if !path.is_empty() && should_stop(path.len()) {
+            interrupted = true;
+            break;
+        }
+        visit(current, current_depth, queue.len().saturating_add(1));
+        path.push(current);
+
+        if current_depth >= depth {
+            continue;
+        }
+
+        for (from, to, weight, next) in expand(current)? {
+            subgraph_edges.push((from, to, weight));
+            if visited.insert(next) {
+                queue.push_back((next, current_depth.saturating_add(1)));
+            }
+        }
+    }
+
+    Ok((Artifact::with_subgraph(path, subgraph_edges), interrupted))
+}
+
+// =============================================================================
+// INTERRUPTIBLE TRAVERSAL
+// =============================================================================
+
+/// Breadth-first traversal that can be stopped early by the caller.
+///
+/// Same order and edge filter as `traverse_filtered` (use
+/// `EdgeWeight::new(i64::MIN)` for no filter). `should_stop` is called with
+/// the number of nodes visited so far before each node after the start is
+/// visited, leaves included; returning `true` ends the traversal and yields the
+/// partial artifact with the `bool` flag set. The CORE never reads a clock
+/// itself: deadlines and node budgets live in the predicate.
+pub fn traverse_interruptible<G: GraphStore + ?Sized>(
+    graph: &G,
+    start: NodeId,
+    depth: usize,
+    min_weight: EdgeWeight,
+    should_stop: impl FnMut(usize) -> bool,
+) -> Result<Option<(Artifact, bool)>, KremisError> {
+    if !graph.contains_node(start)? {
+        return Ok(None);
+    }
+
+    breadth_first(
+        start,
+        depth,
+        |node| {
+            Ok(graph
+                .neighbors(node)?
+                .into_iter()
+                .filter(|(_, weight)| weight.value() >= min_weight.value())
+                .map(|(neighbor, weight)| (node, neighbor, weight, neighbor))
+                .collect())
+        },
+        |_, _, _| {},
+        should_stop,
+    )
+    .map(Some)
+}
+
+/// Depth-first traversal that can be stopped early by the caller.
+///
+/// Same order as `traverse_dfs`, walked with an explicit stack. `should_stop`
+/// is called with the number of visited nodes before each node is expanded;
+/// returning `true` ends the traversal and yields the partial artifact with
+/// the `bool` flag set.
+pub fn traverse_dfs_interruptible<G: GraphStore + ?Sized>(
+    graph: &G,
+    start: NodeId,
+    depth: usize,
+    mut should_stop: impl FnMut(usize) -> bool,
+) -> Result<Option<(Artifact, bool)>, KremisError> {
+    let depth = depth.min(crate::primitives::MAX_TRAVERSAL_DEPTH);
+    if !graph.contains_node(start)? {
+        return Ok(None);
+    }
+
+    let mut visited = BTreeSet::new();
+    let mut path = Vec::new();
+    let mut subgraph_edges = Vec::new();
+    let mut interrupted = false;
+    // (node, depth, its neighbors, next neighbor index)
+    let mut stack = Vec::new();
+    let mut next = Some((start, 0usize));
+
+    loop {
+        if let Some((node, node_depth)) = next.take() {
+            visited.insert(node);
+            path.push(node);
+            let neighbors = if node_depth < depth {
+                if should_stop(path.len()) {
+                    interrupted = true;
+                    break;
+                }
+                graph.neighbors(node)?
+            } else {
+                Vec::new()
+            };
+            stack.push((node, node_depth, neighbors, 0usize));
+        }
+        let Some((node, node_depth, neighbors, index)) = stack.last_mut() else {
+            break;
+        };
+        match neighbors.get(*index) {
+            Some(&(neighbor, weight)) => {
+                *index = index.saturating_add(1);
+                subgraph_edges.push((*node, neighbor, weight));
+                if !visited.contains(&neighbor) {
+                    next = Some((neighbor, node_depth.saturating_add(1)));
+                }
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    Ok(Some((
+        Artifact::with_subgraph(path, subgraph_edges),
+        interrupted,
+    )))
+}
+
+/// Cost of one edge under the strongest-path model: `i64::MAX - weight`,
+/// with negative weights clamped to 0 to keep Dijkstra valid.
+///
+/// Path costs are summed in `u128` without saturation, so every path has a
+/// distinct cost from its weights and hop count. Each hop adds close to
+/// `i64::MAX`, so a route with fewer hops wins unless a longer one has
+/// weights near `i64::MAX`; among routes of equal length, the one with the
+/// highest total weight wins.
+#[must_use]
+pub fn strongest_edge_cost(weight: EdgeWeight) -> u128 {
+    i64::MAX
+        .saturating_sub(weight.value().max(0))
+        .unsigned_abs()
+        .into()
+}
+
+/// Strongest path search that can be stopped early by the caller.
+///
+/// Same result as `GraphStore::strongest_path` when not stopped.
+/// `should_stop` is called with the number of settled nodes before each
+/// node is settled; returning `true` abandons the search with no path and
+/// the `bool` flag set.
+pub fn strongest_path_interruptible<G: GraphStore + ?Sized>(
+    graph: &G,
+    start: NodeId,
+    end: NodeId,
+    mut should_stop: impl FnMut(usize) -> bool,
+) -> Result<(Option<Vec<NodeId>>, bool), KremisError> {
+    if !graph.contains_node(start)? || !graph.contains_node(end)? {
+        return Ok((None, false));
+    }
+    if start == end {
+        return Ok((Some(vec![start]), false));
+    }
+
+    let mut dist: BTreeMap<NodeId, u128> = BTreeMap::from([(start, 0)]);
+    let mut prev: BTreeMap<NodeId, NodeId> = BTreeMap::new();
+    let mut visited = BTreeSet::new();
+    let mut frontier = BinaryHeap::from([Reverse((0u128, start))]);
+
+    while let Some(Reverse((current_dist, current))) = frontier.pop() {
+        if current == end {
+            break;
+        }
+        if visited.contains(&current) {
+            continue;
+        }
+        if should_stop(visited.len().saturating_add(1)) {
+            return Ok((None, true));
+        }
+        visited.insert(current);
+
+        for (neighbor, weight) in graph.neighbors(current)? {
+            if visited.contains(&neighbor) {
+                continue;
+            }
+            let new_dist = current_dist.saturating_add(strongest_edge_cost(weight));
+            if dist.get(&neighbor).is_none_or(|&d| new_dist < d) {
+                dist.insert(neighbor, new_dist);
+                prev.insert(neighbor, current);
+                frontier.push(Reverse((new_dist, neighbor)));
+            }
+        }
+    }
+
+    let mut path = vec![end];
+    let mut current = end;
+    while current != start {
+        match prev.get(&current) {
+            Some(&p) => current = p,
+            None => return Ok((None, false)),
+        }
+        path.push(current);
+    }
+    path.reverse();
+    Ok((Some(path), false))
+}
+
+/// Breadth-first traversal that hands each edge to `on_edge` as it is found.
+///
+/// Same order and edge filter as `traverse_interruptible`, but nothing is
+/// collected: callers can forward edges without buffering the result.
+/// Returning `false` from `on_edge` stops the traversal. Returns `false` if
+/// `start` does not exist.
+pub fn traverse_streaming<G: GraphStore + ?Sized>(
+    graph: &G,
+    start: NodeId,
+    depth: usize,
+    min_weight: EdgeWeight,
+    mut on_edge: impl FnMut(NodeId, NodeId, EdgeWeight) -> bool,
+) -> Result<bool, KremisError> {
+    let depth = depth.min(crate::primitives::MAX_TRAVERSAL_DEPTH);
+    if !graph.contains_node(start)? {
+        return Ok(false);
+    }
+
+    let mut visited = BTreeSet::from([start]);
+    let mut queue = VecDeque::from([(start, 0usize)]);
+
+    while let Some((current, current_depth)) = queue.pop_front() {
+        if current_depth >= depth {
+            continue;
+        }
+        for (neighbor, weight) in graph.neighbors(current)? {
+            if weight.value() < min_weight.value() {
+                continue;
+            }
+            if !on_edge(current, neighbor, weight) {
+                return Ok(true);
+            }
+            if visited.insert(neighbor) {
+                queue.push_back((neighbor, current_depth.saturating_add(1)));
+            }
+        }
+    }
+    Ok(true)
+}
+
+// =============================================================================
+// DIFF
+// =============================================================================
+
+/// Structural differences between two graphs, see [`diff`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GraphDiff {
+    /// Nodes only in the new graph.
+    pub added_nodes: Vec<NodeId>,
+    /// Nodes only in the old graph.
+    pub removed_nodes: Vec<NodeId>,
+    /// Edges only in the new graph, with their weight.
+    pub added_edges: Vec<(NodeId, NodeId, EdgeWeight)>,
+    /// Edges only in the old graph, with their weight.
+    pub removed_edges: Vec<(NodeId, NodeId, EdgeWeight)>,
+    /// Edges in both graphs with different weights: `(from, to, old, new)`.
+    pub changed_edges: Vec<(NodeId, NodeId, EdgeWeight, EdgeWeight)>,
+}
+
+impl GraphDiff {
+    /// Check if the graphs had no structural differences.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added_nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+            && self.changed_edges.is_empty()
+    }
+}
+
+/// Compare two graphs node by node and edge by edge.
+///
+/// Nodes and edges are matched by `NodeId`, so this suits snapshots of one
+/// graph or graphs built by the same deterministic pipeline. Every list is
+/// in ascending `NodeId` order (edges by `from`, then `to`).
+#[must_use]
+pub fn diff(old: &Graph, new: &Graph) -> GraphDiff {
+    let mut result = GraphDiff::default();
+
+    for id in new.nodes.keys() {
+        if !old.nodes.contains_key(id) {
+            result.added_nodes.push(*id);
+        }
+    }
+    for id in old.nodes.keys() {
+        if !new.nodes.contains_key(id) {
+            result.removed_nodes.push(*id);
+        }
+    }
+
+    for (from, to, weight) in new.edges() {
+        match old.get_edge_internal(from, to) {
+            None => result.added_edges.push((from, to, weight)),
+            Some(before) if before != weight => {
+                result.changed_edges.push((from, to, before, weight));
+            }
+            Some(_) => {}
+        }
+    }
+    for (from, to, weight) in old.edges() {
+        if !new.contains_edge(from, to) {
+            result.removed_edges.push((from, to, weight));
+        }
+    }
+
+    result
+}
+
+// =============================================================================
+// DETERMINISTIC GENERATION
+// =============================================================================
+
+/// SplitMix64 step: a small seedable PRNG. No OS entropy is ever consulted.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Build a pseudo-random graph for property tests and benchmarks.
+///
+/// The same `(node_count, edge_count, seed)` always yields an identical graph.
+/// Nodes map entities `0..node_count`; edges have no self-loops or duplicates
+/// and carry weights in `1..=PROMOTION_THRESHOLD`. `edge_count` is clamped to
+/// the maximum number of distinct directed edges, `n * (n - 1)`.
+#[must_use]
+pub fn generate_random(node_count: usize, edge_count: usize, seed: u64) -> Graph {
+    use crate::primitives::PROMOTION_THRESHOLD;
+
+    let mut graph = Graph::new();
+    for i in 0..node_count {
+        let node_id = NodeId(i as u64);
+        let entity = EntityId(i as u64);
+        graph.nodes.insert(node_id, Node::new(node_id, entity));
+        graph.entity_index.insert(entity, node_id);
+    }
+    graph.next_node_id = node_count as u64;
+
+    let n = node_count as u64;
+    let target = edge_count.min(node_count.saturating_mul(node_count.saturating_sub(1)));
+    let mut state = seed;
+    let mut added = 0usize;
+
+    // Random placement with a bounded number of attempts.
+    let max_attempts = target.saturating_mul(16);
+    let mut attempts = 0usize;
+    while added < target && attempts < max_attempts {
+        attempts = attempts.saturating_add(1);
+        let from = NodeId(splitmix64(&mut state) % n);
+        let to = NodeId(splitmix64(&mut state) % n);
+        if from == to || graph.contains_edge(from, to) {
+            continue;
+        }
+        let weight = (splitmix64(&mut state) % PROMOTION_THRESHOLD as u64) as i64 + 1;
+        graph
+            .edges
+            .entry(from)
+            .or_default()
+            .insert(to, EdgeWeight::new(weight));
+        graph.link(from, to);
+        added = added.saturating_add(1);
+    }
+
+    // Dense requests: fill whatever is left in ascending (from, to) order.
+    'fill: for from in 0..n {
+        for to in 0..n {
+            if added >= target {
+                break 'fill;
+            }
+            let (from, to) = (NodeId(from), NodeId(to));
+            if from != to && !graph.contains_edge(from, to) {
+                graph
+                    .edges
+                    .entry(from)
+                    .or_default()
+                    .insert(to, EdgeWeight::new(1));
+                graph.link(from, to);
+                added = added.saturating_add(1);
+            }
+        }
+    }
+
+    graph
+}
+
+// =============================================================================
+// SERIALIZATION SUPPORT
+// =============================================================================
+
+use serde::{Deserialize, Serialize};
+
+/// Serializable representation of the graph for persistence.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerializableGraph {
+    pub nodes: Vec<Node>,
+    pub edges: Vec<(NodeId, NodeId, EdgeWeight)>,
+    pub next_node_id: u64,
+    #[serde(default)]
+    pub properties: Vec<(u64, String, String)>,
+    #[serde(default)]
+    pub edge_labels: Vec<(u64, u64, String)>,
+}
+
+impl From<&Graph> for SerializableGraph {
+    fn from(graph: &Graph) -> Self {
+        let mut properties = Vec::new();
+        for node in graph.nodes.values() {
+            if let Ok(props) = graph.get_properties(node.id) {
+                for (attr, val) in props {
+                    properties.push((
+                        node.id.0,
+                        attr.as_str().to_string(),
+                        val.as_str().to_string(),
+                    ));
+                }
+            }
+        }
+        Self {
+            nodes: graph.nodes.values().cloned().collect(),
+            edges: graph.edges().collect(),
+            next_node_id: graph.next_node_id,
+            properties,
+            edge_labels: graph
+                .edge_labels
+                .iter()
+                .map(|(&(from, to), label)| (from.0, to.0, label.as_str().to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl From<SerializableGraph> for Graph {
+    fn from(sg: SerializableGraph) -> Self {
+        let mut graph = Graph::new();
+        graph.next_node_id = sg.next_node_id;
+
+        for node in sg.nodes {
+            graph.nodes.insert(node.id, node.clone());
+            graph.entity_index.insert(node.entity, node.id);
+        }
+
+        for (from, to, weight) in sg.edges {
+            let _ = graph.insert_edge(from, to, weight);
+        }
+
+        for (node_id, attr, val) in sg.properties {
+            let _ = graph.store_property(NodeId(node_id), Attribute::new(&attr), Value::new(&val));
+        }
+
+        for (from, to, label) in sg.edge_labels {
+            let _ = graph.set_edge_label(NodeId(from), NodeId(to), Attribute::new(&label));
+        }
+
+        graph
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_lookup_node() {
+        let mut graph = Graph::new();
+        let entity = EntityId(42);
+
+        let node_id = graph.insert_node(entity).expect("insert");
+        let node = graph.lookup(node_id).expect("lookup");
+
+        assert!(node.is_some());
+        assert_eq!(node.map(|n| n.entity), Some(entity));
+    }
+
+    #[test]
+    fn insert_duplicate_entity_returns_same_node() {
+        let mut graph = Graph::new();
+        let entity = EntityId(42);
+
+        let first = graph.insert_node(entity).expect("insert");
+        let second = graph.insert_node(entity).expect("insert");
+
+        assert_eq!(first, second);
+        assert_eq!(graph.node_count().expect("count"), 1);
+    }
+
+    #[test]
+    fn increment_edge_creates_and_increments() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+
+        // First increment creates edge with weight 1
+        graph.increment_edge(a, b).expect("increment");
         assert_eq!(graph.get_edge(a, b).expect("get"), Some(EdgeWeight::new(1)));
 
-        // Second increment increases to 2
-        graph.increment_edge(a, b).expect("increment");
-        assert_eq!(graph.get_edge(a, b).expect("get"), Some(EdgeWeight::new(2)));
+        // Second increment increases to 2
+        graph.increment_edge(a, b).expect("increment");
+        assert_eq!(graph.get_edge(a, b).expect("get"), Some(EdgeWeight::new(2)));
+    }
+
+    #[test]
+    fn decrement_edge_reduces_weight_by_one() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+
+        graph.insert_edge(a, b, EdgeWeight::new(5)).expect("insert");
+        graph.decrement_edge(a, b).expect("decrement");
+        assert_eq!(graph.get_edge(a, b).expect("get"), Some(EdgeWeight::new(4)));
+    }
+
+    #[test]
+    fn decrement_edge_floors_at_zero() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+
+        graph.insert_edge(a, b, EdgeWeight::new(1)).expect("insert");
+        graph.decrement_edge(a, b).expect("decrement to 0");
+        assert_eq!(graph.get_edge(a, b).expect("get"), Some(EdgeWeight::new(0)));
+
+        // Further decrements stay at 0
+        graph.decrement_edge(a, b).expect("decrement at 0");
+        assert_eq!(graph.get_edge(a, b).expect("get"), Some(EdgeWeight::new(0)));
+    }
+
+    #[test]
+    fn decrement_edge_nonexistent_returns_error() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+
+        let result = graph.decrement_edge(a, b);
+        assert!(matches!(result, Err(KremisError::EdgeNotFound(_, _))));
+    }
+
+    #[test]
+    fn decrement_edge_then_increment_roundtrip() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+
+        graph.insert_edge(a, b, EdgeWeight::new(3)).expect("insert");
+        graph.decrement_edge(a, b).expect("decrement");
+        graph.increment_edge(a, b).expect("increment");
+        assert_eq!(graph.get_edge(a, b).expect("get"), Some(EdgeWeight::new(3)));
+    }
+
+    #[test]
+    fn neighbors_in_deterministic_order() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+        let c = graph.insert_node(EntityId(3)).expect("insert");
+
+        // Insert edges in non-sorted order
+        graph.insert_edge(a, c, EdgeWeight::new(1)).expect("insert");
+        graph.insert_edge(a, b, EdgeWeight::new(2)).expect("insert");
+
+        let neighbors: Vec<_> = graph
+            .neighbors(a)
+            .expect("neighbors")
+            .into_iter()
+            .map(|(n, _)| n)
+            .collect();
+
+        // Should be sorted by NodeId
+        assert_eq!(neighbors, vec![b, c]);
+    }
+
+    #[test]
+    fn traverse_respects_depth() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+        let c = graph.insert_node(EntityId(3)).expect("insert");
+
+        graph.insert_edge(a, b, EdgeWeight::new(1)).expect("insert");
+        graph.insert_edge(b, c, EdgeWeight::new(1)).expect("insert");
+
+        // Depth 1: should reach a and b
+        let artifact = graph.traverse(a, 1).expect("traverse");
+        assert!(artifact.is_some());
+
+        let path = artifact.as_ref().map(|a| &a.path);
+        assert!(path.is_some());
+        assert!(path.map(|p| p.contains(&a)).unwrap_or(false));
+        assert!(path.map(|p| p.contains(&b)).unwrap_or(false));
+    }
+
+    #[test]
+    fn traverse_missing_node_returns_none() {
+        let graph = Graph::new();
+        let result = graph.traverse(NodeId(999), 5).expect("traverse");
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn strongest_path_finds_route() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+        let c = graph.insert_node(EntityId(3)).expect("insert");
+
+        graph
+            .insert_edge(a, b, EdgeWeight::new(10))
+            .expect("insert");
+        graph
+            .insert_edge(b, c, EdgeWeight::new(10))
+            .expect("insert");
+
+        let path = graph.strongest_path(a, c).expect("path");
+        assert_eq!(path, Some(vec![a, b, c]));
+    }
+
+    #[test]
+    fn intersect_finds_common_neighbors() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+        let common = graph.insert_node(EntityId(100)).expect("insert");
+
+        graph
+            .insert_edge(a, common, EdgeWeight::new(1))
+            .expect("insert");
+        graph
+            .insert_edge(b, common, EdgeWeight::new(1))
+            .expect("insert");
+
+        let result = graph.intersect(&[a, b]).expect("intersect");
+        assert_eq!(result, vec![common]);
+    }
+
+    #[test]
+    fn serialization_roundtrip() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+        graph.insert_edge(a, b, EdgeWeight::new(5)).expect("insert");
+
+        let serializable = SerializableGraph::from(&graph);
+        let restored = Graph::from(serializable);
+
+        assert_eq!(
+            graph.node_count().expect("count"),
+            restored.node_count().expect("count")
+        );
+        assert_eq!(
+            graph.edge_count().expect("count"),
+            restored.edge_count().expect("count")
+        );
+        assert_eq!(
+            restored.get_edge(a, b).expect("get"),
+            Some(EdgeWeight::new(5))
+        );
+    }
+
+    #[test]
+    fn store_and_get_properties() {
+        let mut graph = Graph::new();
+        let node = graph.insert_node(EntityId(1)).expect("insert");
+
+        graph
+            .store_property(node, Attribute::new("name"), Value::new("Alice"))
+            .expect("store");
+        graph
+            .store_property(node, Attribute::new("age"), Value::new("30"))
+            .expect("store");
+
+        let props = graph.get_properties(node).expect("get");
+        assert_eq!(props.len(), 2);
+        assert!(props.contains(&(Attribute::new("name"), Value::new("Alice"))));
+        assert!(props.contains(&(Attribute::new("age"), Value::new("30"))));
+    }
+
+    #[test]
+    fn store_multiple_values_same_attribute() {
+        let mut graph = Graph::new();
+        let node = graph.insert_node(EntityId(1)).expect("insert");
+
+        graph
+            .store_property(node, Attribute::new("knows"), Value::new("Bob"))
+            .expect("store");
+        graph
+            .store_property(node, Attribute::new("knows"), Value::new("Charlie"))
+            .expect("store");
+
+        let props = graph.get_properties(node).expect("get");
+        assert_eq!(props.len(), 2);
+        assert!(props.contains(&(Attribute::new("knows"), Value::new("Bob"))));
+        assert!(props.contains(&(Attribute::new("knows"), Value::new("Charlie"))));
+    }
+
+    #[test]
+    fn store_property_nonexistent_node_fails() {
+        let mut graph = Graph::new();
+        let result = graph.store_property(NodeId(999), Attribute::new("name"), Value::new("Test"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn get_properties_nonexistent_node_fails() {
+        let graph = Graph::new();
+        let result = graph.get_properties(NodeId(999));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn get_properties_empty_returns_empty_vec() {
+        let mut graph = Graph::new();
+        let node = graph.insert_node(EntityId(1)).expect("insert");
+
+        let props = graph.get_properties(node).expect("get");
+        assert!(props.is_empty());
+    }
+
+    #[test]
+    fn insert_edge_ignores_dangling_nodes() {
+        let mut graph = Graph::new();
+        let node1 = graph.insert_node(EntityId(1)).expect("insert");
+        let dangling = NodeId(999);
+
+        // Edge from existing to non-existing: silently ignored
+        graph
+            .insert_edge(node1, dangling, EdgeWeight::new(5))
+            .expect("insert edge");
+        assert_eq!(graph.edge_count().expect("count"), 0);
+
+        // Edge from non-existing to existing: silently ignored
+        graph
+            .insert_edge(dangling, node1, EdgeWeight::new(5))
+            .expect("insert edge");
+        assert_eq!(graph.edge_count().expect("count"), 0);
+    }
+
+    #[test]
+    fn serializable_graph_roundtrip_with_properties() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+        graph.insert_edge(a, b, EdgeWeight::new(5)).expect("insert");
+
+        graph
+            .store_property(a, Attribute::new("name"), Value::new("Alice"))
+            .expect("store");
+        graph
+            .store_property(a, Attribute::new("role"), Value::new("admin"))
+            .expect("store");
+        graph
+            .store_property(b, Attribute::new("name"), Value::new("Bob"))
+            .expect("store");
+
+        let serializable = SerializableGraph::from(&graph);
+        assert_eq!(serializable.properties.len(), 3);
+
+        let restored = Graph::from(serializable);
+
+        assert_eq!(
+            graph.node_count().expect("count"),
+            restored.node_count().expect("count")
+        );
+        assert_eq!(
+            graph.edge_count().expect("count"),
+            restored.edge_count().expect("count")
+        );
+
+        let props_a = restored.get_properties(a).expect("get");
+        assert_eq!(props_a.len(), 2);
+        assert!(props_a.contains(&(Attribute::new("name"), Value::new("Alice"))));
+        assert!(props_a.contains(&(Attribute::new("role"), Value::new("admin"))));
+
+        let props_b = restored.get_properties(b).expect("get");
+        assert_eq!(props_b.len(), 1);
+        assert!(props_b.contains(&(Attribute::new("name"), Value::new("Bob"))));
+    }
+
+    #[test]
+    fn generate_random_is_deterministic() {
+        let a = generate_random(50, 200, 42);
+        let b = generate_random(50, 200, 42);
+
+        assert_eq!(a.node_count().expect("count"), 50);
+        assert_eq!(a.edge_count().expect("count"), 200);
+        assert_eq!(a.edges().collect::<Vec<_>>(), b.edges().collect::<Vec<_>>());
+        assert!(a.edges().all(|(from, to, _)| from != to));
+
+        let c = generate_random(50, 200, 43);
+        assert_ne!(a.edges().collect::<Vec<_>>(), c.edges().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn generate_random_clamps_to_complete_graph() {
+        let graph = generate_random(4, 1000, 7);
+        assert_eq!(graph.edge_count().expect("count"), 12);
+        assert_eq!(generate_random(1, 10, 7).edge_count().expect("count"), 0);
+        assert_eq!(generate_random(0, 10, 7).node_count().expect("count"), 0);
+    }
+
+    #[test]
+    fn check_invariants_accepts_well_formed_graph() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+        graph.increment_edge(a, b).expect("increment");
+
+        assert_eq!(graph.check_invariants(), Ok(()));
+        assert_eq!(generate_random(20, 60, 1).check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn check_invariants_reports_all_violations() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        graph.insert_node(EntityId(2)).expect("insert");
+
+        graph.entity_index.insert(EntityId(3), NodeId(99));
+        graph.entity_index.insert(EntityId(4), a);
+        graph
+            .edges
+            .entry(a)
+            .or_default()
+            .insert(NodeId(50), EdgeWeight::new(1));
+        graph.edges.entry(NodeId(1)).or_default();
+        graph.next_node_id = 1;
+
+        let violations = graph.check_invariants().expect_err("broken graph");
+        assert_eq!(violations.len(), 6);
+        assert!(
+            violations
+                .iter()
+                .any(|v| v.contains("reverse_edges: missing entry for 0 -> 50"))
+        );
+        assert!(violations.iter().any(|v| v.contains("missing node 99")));
+        assert!(
+            violations
+                .iter()
+                .any(|v| v.contains("entity 4 points to node 0"))
+        );
+        assert!(violations.iter().any(|v| v.contains("0 -> 50")));
+        assert!(
+            violations
+                .iter()
+                .any(|v| v.contains("empty adjacency map for node 1"))
+        );
+        assert!(violations.iter().any(|v| v.contains("next_node_id 1")));
+    }
+
+    #[test]
+    fn repair_fixes_broken_graph() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+        graph.insert_edge(a, b, EdgeWeight::new(3)).expect("insert");
+
+        graph.entity_index.insert(EntityId(3), NodeId(99));
+        graph.entity_index.insert(EntityId(4), a);
+        graph
+            .edges
+            .entry(a)
+            .or_default()
+            .insert(NodeId(50), EdgeWeight::new(1));
+        graph
+            .edges
+            .entry(NodeId(60))
+            .or_default()
+            .insert(b, EdgeWeight::new(1));
+        graph.edges.entry(b).or_default();
+        graph.next_node_id = 1;
+        assert!(graph.check_invariants().is_err());
+
+        let report = graph.repair();
+        assert_eq!(
+            report,
+            RepairReport {
+                dangling_edges: 2,
+                stale_index_entries: 2,
+                empty_adjacency_maps: 1,
+                next_node_id_fixed: true,
+            }
+        );
+        assert!(graph.check_invariants().is_ok());
+        assert_eq!(graph.get_edge_internal(a, b), Some(EdgeWeight::new(3)));
+        assert_eq!(graph.predecessors(b), vec![(a, EdgeWeight::new(3))]);
+        assert!(graph.repair().is_clean());
+    }
+
+    #[test]
+    fn set_edge_weight_updates_existing_only() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+        graph.increment_edge(a, b).expect("increment");
+
+        graph
+            .set_edge_weight(a, b, EdgeWeight::new(42))
+            .expect("set");
+        assert_eq!(
+            graph.get_edge(a, b).expect("get"),
+            Some(EdgeWeight::new(42))
+        );
+
+        let result = graph.set_edge_weight(b, a, EdgeWeight::new(7));
+        assert!(matches!(result, Err(KremisError::EdgeNotFound(from, to)) if from == b && to == a));
+        assert!(!graph.contains_edge(b, a));
+    }
+
+    #[test]
+    fn related_by_shared_target_finds_co_occurrence() {
+        let mut graph = Graph::new();
+        let alice = graph.insert_node(EntityId(1)).expect("insert");
+        let bob = graph.insert_node(EntityId(2)).expect("insert");
+        let carol = graph.insert_node(EntityId(3)).expect("insert");
+        let rust = graph.insert_node(EntityId(10)).expect("insert");
+        let graphs = graph.insert_node(EntityId(11)).expect("insert");
+
+        for (from, to) in [
+            (alice, rust),
+            (alice, graphs),
+            (bob, rust),
+            (bob, graphs),
+            (carol, rust),
+        ] {
+            graph.increment_edge(from, to).expect("increment");
+        }
+
+        assert_eq!(
+            graph.related_by_shared_target(EntityId(1), 2),
+            vec![EntityId(2)]
+        );
+        assert_eq!(
+            graph.related_by_shared_target(EntityId(1), 1),
+            vec![EntityId(2), EntityId(3)]
+        );
+        assert!(graph.related_by_shared_target(EntityId(10), 1).is_empty());
+        assert!(graph.related_by_shared_target(EntityId(99), 1).is_empty());
+    }
+
+    #[test]
+    fn stable_edges_keep_threshold_and_order() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3)]);
+        for (from, to, w) in [
+            (ids[0], ids[1], 3),
+            (ids[0], ids[2], 10),
+            (ids[1], ids[2], 12),
+        ] {
+            graph
+                .insert_edge(from, to, EdgeWeight::new(w))
+                .expect("insert");
+        }
+
+        let stable: Vec<_> = graph.stable_edges(EdgeWeight::new(10)).collect();
+        assert_eq!(
+            stable,
+            vec![
+                (ids[0], ids[2], EdgeWeight::new(10)),
+                (ids[1], ids[2], EdgeWeight::new(12)),
+            ]
+        );
+        assert_eq!(graph.edges_where(|from, _, _| from == ids[1]).count(), 1);
+    }
+
+    #[test]
+    fn relationship_triples_resolve_entities_and_labels() {
+        let mut graph = Graph::new();
+        let alice = graph.insert_node(EntityId(10)).expect("insert");
+        let bob = graph.insert_node(EntityId(20)).expect("insert");
+        let rust = graph.insert_node(EntityId(30)).expect("insert");
+        graph
+            .insert_edge(alice, bob, EdgeWeight::new(3))
+            .expect("insert");
+        graph
+            .insert_edge(alice, rust, EdgeWeight::new(1))
+            .expect("insert");
+        graph
+            .set_edge_label(alice, bob, Attribute::new("knows"))
+            .expect("label");
+
+        let triples: Vec<_> = graph.relationship_triples().collect();
+        assert_eq!(
+            triples,
+            vec![
+                (
+                    EntityId(10),
+                    Some(Attribute::new("knows")),
+                    EntityId(20),
+                    EdgeWeight::new(3)
+                ),
+                (EntityId(10), None, EntityId(30), EdgeWeight::new(1)),
+            ]
+        );
+
+        assert!(matches!(
+            graph.set_edge_label(bob, alice, Attribute::new("knows")),
+            Err(KremisError::EdgeNotFound(_, _))
+        ));
+    }
+
+    #[test]
+    fn insert_nodes_maps_duplicates_to_same_id() {
+        let mut graph = Graph::new();
+        let existing = graph.insert_node(EntityId(7)).expect("insert");
+
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(7), EntityId(2), EntityId(1)]);
+
+        assert_eq!(ids.len(), 4);
+        assert_eq!(ids[1], existing);
+        assert_eq!(ids[0], ids[3]);
+        assert_ne!(ids[0], ids[2]);
+        assert_eq!(graph.node_count().expect("count"), 3);
+        assert_eq!(graph.insert_nodes(&[EntityId(2)]), vec![ids[2]]);
+    }
+
+    #[test]
+    fn adjust_edge_applies_delta_and_removes_at_zero() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+        graph.insert_edge(a, b, EdgeWeight::new(3)).expect("insert");
+
+        assert_eq!(
+            graph.adjust_edge(a, b, 4).expect("adjust"),
+            EdgeWeight::new(7)
+        );
+        assert_eq!(graph.get_edge_internal(a, b), Some(EdgeWeight::new(7)));
+
+        assert_eq!(
+            graph.adjust_edge(a, b, -2).expect("adjust"),
+            EdgeWeight::new(5)
+        );
+        assert_eq!(
+            graph.adjust_edge(a, b, i64::MIN).expect("adjust"),
+            EdgeWeight::new(0)
+        );
+        assert!(!graph.contains_edge(a, b));
+        assert!(graph.check_invariants().is_ok());
+
+        assert!(matches!(
+            graph.adjust_edge(a, b, 1),
+            Err(KremisError::EdgeNotFound(_, _))
+        ));
+    }
+
+    #[test]
+    fn intersect_is_sorted_regardless_of_input_order() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+        let commons: Vec<NodeId> = (10..15)
+            .map(|e| graph.insert_node(EntityId(e)).expect("insert"))
+            .collect();
+
+        // Insert edges in descending target order
+        for &common in commons.iter().rev() {
+            for source in [b, a] {
+                graph
+                    .insert_edge(source, common, EdgeWeight::new(1))
+                    .expect("insert");
+            }
+        }
+
+        let forward = graph.intersect(&[a, b]).expect("intersect");
+        let reversed = graph.intersect(&[b, a]).expect("intersect");
+        assert_eq!(forward, commons);
+        assert_eq!(reversed, commons);
+    }
+
+    #[test]
+    fn compact_ids_removes_gaps_and_preserves_edges() {
+        // Sparse ids 0, 3, 7 as left behind by node removals
+        let sparse = SerializableGraph {
+            nodes: vec![
+                Node::new(NodeId(0), EntityId(10)),
+                Node::new(NodeId(3), EntityId(30)),
+                Node::new(NodeId(7), EntityId(70)),
+            ],
+            edges: vec![
+                (NodeId(0), NodeId(7), EdgeWeight::new(4)),
+                (NodeId(7), NodeId(3), EdgeWeight::new(2)),
+            ],
+            next_node_id: 8,
+            properties: vec![(7, "name".to_string(), "seven".to_string())],
+            edge_labels: Vec::new(),
+        };
+        let mut graph = Graph::from(sparse);
+        graph
+            .set_edge_label(NodeId(0), NodeId(7), Attribute::new("knows"))
+            .expect("label");
+
+        let mapping = graph.compact_ids();
+
+        assert_eq!(mapping.get(&NodeId(3)), Some(&NodeId(1)));
+        assert_eq!(mapping.get(&NodeId(7)), Some(&NodeId(2)));
+        let ids: Vec<NodeId> = graph.nodes().map(|n| n.id).collect();
+        assert_eq!(ids, vec![NodeId(0), NodeId(1), NodeId(2)]);
+        assert_eq!(graph.next_node_id(), 3);
+
+        assert_eq!(
+            graph.get_edge_internal(NodeId(0), NodeId(2)),
+            Some(EdgeWeight::new(4))
+        );
+        assert_eq!(
+            graph.get_edge_internal(NodeId(2), NodeId(1)),
+            Some(EdgeWeight::new(2))
+        );
+        assert_eq!(
+            graph.edge_label(NodeId(0), NodeId(2)),
+            Some(&Attribute::new("knows"))
+        );
+        assert_eq!(graph.get_node_by_entity(EntityId(70)), Some(NodeId(2)));
+        assert_eq!(
+            graph.get_properties(NodeId(2)).expect("props"),
+            vec![(Attribute::new("name"), Value::new("seven"))]
+        );
+        assert!(graph.check_invariants().is_ok());
+    }
+
+    #[test]
+    fn intersect_ranked_orders_by_combined_weight() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+        let weak = graph.insert_node(EntityId(10)).expect("insert");
+        let strong = graph.insert_node(EntityId(11)).expect("insert");
+        let only_a = graph.insert_node(EntityId(12)).expect("insert");
+
+        for (from, to, w) in [
+            (a, weak, 1),
+            (b, weak, 2),
+            (a, strong, 5),
+            (b, strong, 4),
+            (a, only_a, 9),
+        ] {
+            graph
+                .insert_edge(from, to, EdgeWeight::new(w))
+                .expect("insert");
+        }
+
+        let ranked = graph.intersect_ranked(&[b, a]);
+        assert_eq!(ranked, vec![(strong, 9), (weak, 3)]);
+        assert!(graph.intersect_ranked(&[]).is_empty());
+    }
+
+    #[test]
+    fn traverse_interruptible_matches_traverse_when_not_stopped() {
+        let graph = generate_random(12, 30, 5);
+        let full = graph.traverse(NodeId(0), 4).expect("traverse");
+        let (artifact, interrupted) =
+            traverse_interruptible(&graph, NodeId(0), 4, EdgeWeight::new(i64::MIN), |_| false)
+                .expect("traverse")
+                .expect("start exists");
+        assert!(!interrupted);
+        assert_eq!(Some(artifact), full);
+    }
+
+    #[test]
+    fn interruptible_dfs_and_path_match_when_not_stopped() {
+        let graph = generate_random(12, 30, 5);
+        for depth in [0, 1, 4] {
+            let (artifact, interrupted) =
+                traverse_dfs_interruptible(&graph, NodeId(0), depth, |_| false)
+                    .expect("traverse")
+                    .expect("start exists");
+            assert!(!interrupted);
+            assert_eq!(Some(artifact), graph.traverse_dfs(NodeId(0), depth));
+        }
+        for end in 0..12 {
+            let (path, interrupted) =
+                strongest_path_interruptible(&graph, NodeId(0), NodeId(end), |_| false)
+                    .expect("path");
+            assert!(!interrupted);
+            assert_eq!(
+                path,
+                graph.strongest_path(NodeId(0), NodeId(end)).expect("path")
+            );
+        }
+    }
+
+    #[test]
+    fn interruptible_dfs_and_path_stop_early() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3), EntityId(4)]);
+        for pair in ids.windows(2) {
+            graph
+                .insert_edge(pair[0], pair[1], EdgeWeight::new(1))
+                .expect("insert");
+        }
+
+        let (artifact, interrupted) =
+            traverse_dfs_interruptible(&graph, ids[0], 10, |visited| visited >= 2)
+                .expect("traverse")
+                .expect("start exists");
+        assert!(interrupted);
+        assert_eq!(artifact.path, vec![ids[0], ids[1]]);
+
+        let result = strongest_path_interruptible(&graph, ids[0], ids[3], |settled| settled >= 2)
+            .expect("path");
+        assert_eq!(result, (None, true));
+    }
+
+    #[test]
+    fn traverse_interruptible_stops_with_partial_path() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3), EntityId(4)]);
+        for pair in ids.windows(2) {
+            graph
+                .insert_edge(pair[0], pair[1], EdgeWeight::new(1))
+                .expect("insert");
+        }
+
+        // Node budget of two visited nodes
+        let (artifact, interrupted) =
+            traverse_interruptible(&graph, ids[0], 10, EdgeWeight::new(0), |visited| {
+                visited >= 2
+            })
+            .expect("traverse")
+            .expect("start exists");
+        assert!(interrupted);
+        assert_eq!(artifact.path, vec![ids[0], ids[1]]);
+    }
+
+    #[test]
+    fn attribute_value_returns_latest_literal() {
+        use crate::ingestor::Ingestor;
+        use crate::{Signal, Value};
+
+        let mut graph = Graph::new();
+        let name = Attribute::new("name");
+        let signals = [
+            Signal::new(EntityId(1), name.clone(), Value::new("Alice")),
+            Signal::new(EntityId(2), name.clone(), Value::new("Bob")),
+        ];
+        Ingestor::ingest_sequence(&mut graph, &signals).expect("ingest");
+
+        assert_eq!(
+            graph.attribute_value(EntityId(1), &name),
+            Some(&Value::new("Alice"))
+        );
+        assert_eq!(
+            graph.attribute_value(EntityId(1), &Attribute::new("age")),
+            None
+        );
+        assert_eq!(graph.attribute_value(EntityId(9), &name), None);
+
+        Ingestor::ingest_signal(
+            &mut graph,
+            &Signal::new(EntityId(1), name.clone(), Value::new("Alicia")),
+        )
+        .expect("ingest");
+        assert_eq!(
+            graph.attribute_value(EntityId(1), &name),
+            Some(&Value::new("Alicia"))
+        );
+    }
+
+    #[test]
+    fn context_includes_predecessors_and_successors() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3), EntityId(4)]);
+        let (upstream, center, downstream, root) = (ids[0], ids[1], ids[2], ids[3]);
+        graph
+            .insert_edge(upstream, center, EdgeWeight::new(2))
+            .expect("insert");
+        graph
+            .insert_edge(center, downstream, EdgeWeight::new(3))
+            .expect("insert");
+        graph
+            .insert_edge(downstream, center, EdgeWeight::new(1))
+            .expect("insert");
+        graph
+            .insert_edge(root, upstream, EdgeWeight::new(1))
+            .expect("insert");
+
+        assert_eq!(
+            graph.predecessors(center),
+            vec![
+                (upstream, EdgeWeight::new(2)),
+                (downstream, EdgeWeight::new(1)),
+            ]
+        );
+
+        let artifact = graph.context(center, 1).expect("node exists");
+        assert_eq!(artifact.path, vec![center, downstream, upstream]);
+        let edges = artifact.subgraph.expect("subgraph");
+        assert_eq!(
+            edges,
+            vec![
+                (center, downstream, EdgeWeight::new(3)),
+                (upstream, center, EdgeWeight::new(2)),
+                (downstream, center, EdgeWeight::new(1)),
+            ]
+        );
+
+        // Depth 2 reaches the predecessor's predecessor
+        let artifact = graph.context(center, 2).expect("node exists");
+        assert!(artifact.path.contains(&root));
+
+        assert!(graph.context(NodeId(99), 1).is_none());
+    }
+
+    #[test]
+    fn remove_node_drops_incident_edges() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3)]);
+        graph
+            .insert_edge(ids[0], ids[1], EdgeWeight::new(1))
+            .expect("insert");
+        graph
+            .insert_edge(ids[1], ids[2], EdgeWeight::new(1))
+            .expect("insert");
+        graph
+            .insert_edge(ids[0], ids[2], EdgeWeight::new(1))
+            .expect("insert");
+
+        assert!(graph.remove_node(ids[1]));
+        assert!(!graph.remove_node(ids[1]));
+
+        assert_eq!(graph.node_count().expect("count"), 2);
+        assert_eq!(graph.edge_count().expect("count"), 1);
+        assert_eq!(graph.get_node_by_entity(EntityId(2)), None);
+        assert!(graph.contains_edge(ids[0], ids[2]));
+    }
+
+    #[test]
+    fn remove_edge_returns_weight_once() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2)]);
+        graph
+            .insert_edge(ids[0], ids[1], EdgeWeight::new(4))
+            .expect("insert");
+
+        assert_eq!(graph.remove_edge(ids[0], ids[1]), Some(EdgeWeight::new(4)));
+        assert_eq!(graph.remove_edge(ids[0], ids[1]), None);
+        assert_eq!(graph.edge_count().expect("count"), 0);
+        assert_eq!(graph.node_count().expect("count"), 2);
+        assert!(graph.predecessors(ids[1]).is_empty());
+        assert!(graph.check_invariants().is_ok());
+    }
+
+    #[test]
+    fn remove_middle_node_breaks_strongest_path() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3)]);
+        graph
+            .insert_edge(ids[0], ids[1], EdgeWeight::new(5))
+            .expect("insert");
+        graph
+            .insert_edge(ids[1], ids[2], EdgeWeight::new(5))
+            .expect("insert");
+        assert!(
+            graph
+                .strongest_path(ids[0], ids[2])
+                .expect("path")
+                .is_some()
+        );
+
+        assert!(graph.remove_node(ids[1]));
+        assert_eq!(graph.strongest_path(ids[0], ids[2]).expect("path"), None);
+        assert_eq!(graph.edge_count().expect("count"), 0);
+        assert_eq!(graph.node_count().expect("count"), 2);
+    }
+
+    #[test]
+    fn degrees_on_star_graph() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(0), EntityId(1), EntityId(2), EntityId(3)]);
+        let center = ids[0];
+        for &leaf in &ids[1..] {
+            graph
+                .insert_edge(center, leaf, EdgeWeight::new(1))
+                .expect("insert");
+        }
+
+        assert_eq!(graph.out_degree(center), 3);
+        assert_eq!(graph.in_degree(center), 0);
+        for &leaf in &ids[1..] {
+            assert_eq!(graph.out_degree(leaf), 0);
+            assert_eq!(graph.in_degree(leaf), 1);
+        }
+        assert_eq!(graph.out_degree(NodeId(99)), 0);
+        assert_eq!(graph.degree_histogram(), BTreeMap::from([(1, 3), (3, 1)]));
+    }
+
+    #[test]
+    fn centrality_ranks_hub_above_leaves() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(0), EntityId(1), EntityId(2), EntityId(3)]);
+        let hub = ids[0];
+        // Leaves point at the hub; the hub points back at one leaf
+        for &leaf in &ids[1..] {
+            graph
+                .insert_edge(leaf, hub, EdgeWeight::new(1))
+                .expect("insert");
+        }
+        graph
+            .insert_edge(hub, ids[1], EdgeWeight::new(1))
+            .expect("insert");
+
+        let scores = graph.centrality_scores(20);
+        assert_eq!(scores.len(), 4);
+        for &leaf in &ids[1..] {
+            assert!(scores[&hub] > scores[&leaf]);
+        }
+        assert_eq!(graph.centrality_scores(20), scores);
+        assert!(
+            graph
+                .centrality_scores(0)
+                .values()
+                .all(|&s| s == CENTRALITY_SCALE)
+        );
+    }
+
+    #[test]
+    fn connected_components_splits_islands() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[
+            EntityId(1),
+            EntityId(2),
+            EntityId(3),
+            EntityId(4),
+            EntityId(5),
+        ]);
+        // Island A: 0 -> 2 <- 4 (direction ignored); island B: 3 -> 1
+        graph
+            .insert_edge(ids[0], ids[2], EdgeWeight::new(1))
+            .expect("insert");
+        graph
+            .insert_edge(ids[4], ids[2], EdgeWeight::new(1))
+            .expect("insert");
+        graph
+            .insert_edge(ids[3], ids[1], EdgeWeight::new(1))
+            .expect("insert");
+
+        assert_eq!(
+            graph.connected_components(),
+            vec![vec![ids[0], ids[2], ids[4]], vec![ids[1], ids[3]]]
+        );
+    }
+
+    #[test]
+    fn connected_components_single_when_fully_connected() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3)]);
+        for &from in &ids {
+            for &to in &ids {
+                if from != to {
+                    graph
+                        .insert_edge(from, to, EdgeWeight::new(1))
+                        .expect("insert");
+                }
+            }
+        }
+
+        assert_eq!(graph.connected_components(), vec![ids.clone()]);
+        assert!(Graph::new().connected_components().is_empty());
+    }
+
+    #[test]
+    fn all_paths_finds_both_diamond_routes() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3), EntityId(4)]);
+        let (top, left, right, bottom) = (ids[0], ids[1], ids[2], ids[3]);
+        for (from, to) in [(top, left), (top, right), (left, bottom), (right, bottom)] {
+            graph
+                .insert_edge(from, to, EdgeWeight::new(1))
+                .expect("insert");
+        }
+        // A back edge must not produce cycles
+        graph
+            .insert_edge(bottom, top, EdgeWeight::new(1))
+            .expect("insert");
+
+        assert_eq!(
+            graph.all_paths(top, bottom, 5, 10),
+            vec![vec![top, left, bottom], vec![top, right, bottom]]
+        );
+        assert_eq!(
+            graph.all_paths(top, bottom, 5, 1),
+            vec![vec![top, left, bottom]]
+        );
+        assert!(graph.all_paths(top, bottom, 1, 10).is_empty());
+        assert!(graph.all_paths(top, NodeId(99), 5, 10).is_empty());
+    }
+
+    #[test]
+    fn k_strongest_paths_ranks_parallel_routes() {
+        let mut graph = Graph::new();
+        // The weak route's middle node gets the smaller NodeId
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3), EntityId(4)]);
+        let (start, weak, strong, end) = (ids[0], ids[1], ids[2], ids[3]);
+        for (from, to, w) in [
+            (start, weak, 2),
+            (weak, end, 2),
+            (start, strong, 5),
+            (strong, end, 5),
+        ] {
+            graph
+                .insert_edge(from, to, EdgeWeight::new(w))
+                .expect("insert");
+        }
+
+        assert_eq!(
+            graph.k_strongest_paths(start, end, 5),
+            vec![vec![start, strong, end], vec![start, weak, end]]
+        );
+        assert_eq!(
+            graph.k_strongest_paths(start, end, 1),
+            vec![vec![start, strong, end]]
+        );
+        assert!(graph.k_strongest_paths(start, end, 0).is_empty());
+        assert!(graph.k_strongest_paths(end, start, 3).is_empty());
+    }
+
+    #[test]
+    fn first_k_strongest_path_is_strongest_path() {
+        let mut graph = Graph::new();
+        // Total weight 101 through `light` beats 18 through `heavy`, even
+        // though `heavy` has the larger first edge and the smaller NodeId
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3), EntityId(4)]);
+        let (start, heavy, light, end) = (ids[0], ids[1], ids[2], ids[3]);
+        for (from, to, w) in [
+            (start, light, 1),
+            (light, end, 100),
+            (start, heavy, 9),
+            (heavy, end, 9),
+        ] {
+            graph
+                .insert_edge(from, to, EdgeWeight::new(w))
+                .expect("insert");
+        }
+
+        let strongest = graph.strongest_path(start, end).expect("path");
+        assert_eq!(strongest, Some(vec![start, light, end]));
+        assert_eq!(
+            graph.k_strongest_paths(start, end, 1).first(),
+            strongest.as_ref()
+        );
+
+        for seed in 0..20 {
+            let graph = generate_random(10, 30, seed);
+            for (start, end) in [(NodeId(0), NodeId(9)), (NodeId(3), NodeId(1))] {
+                let strongest = graph.strongest_path(start, end).expect("path");
+                assert_eq!(
+                    graph.k_strongest_paths(start, end, 1).into_iter().next(),
+                    strongest
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn find_cycle_on_chain_and_ring() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3)]);
+        for pair in ids.windows(2) {
+            graph
+                .insert_edge(pair[0], pair[1], EdgeWeight::new(1))
+                .expect("insert");
+        }
+        assert_eq!(graph.find_cycle(), None);
+        assert!(!graph.has_cycle());
+
+        graph
+            .insert_edge(ids[2], ids[0], EdgeWeight::new(1))
+            .expect("insert");
+        let cycle = graph.find_cycle().expect("ring");
+        assert_eq!(cycle, ids);
+        assert!(graph.get_edge_internal(cycle[2], cycle[0]).is_some());
+        assert!(graph.has_cycle());
+    }
+
+    #[test]
+    fn topological_order_respects_edges_and_rejects_cycles() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3), EntityId(4)]);
+        let edges = [
+            (ids[3], ids[1]),
+            (ids[1], ids[0]),
+            (ids[3], ids[2]),
+            (ids[2], ids[0]),
+        ];
+        for (from, to) in edges {
+            graph
+                .insert_edge(from, to, EdgeWeight::new(1))
+                .expect("insert");
+        }
+
+        let order = graph.topological_order().expect("dag");
+        assert_eq!(order, vec![ids[3], ids[1], ids[2], ids[0]]);
+        let position = |n: NodeId| order.iter().position(|&o| o == n);
+        for (from, to) in edges {
+            assert!(position(from) < position(to));
+        }
+
+        graph
+            .insert_edge(ids[0], ids[3], EdgeWeight::new(1))
+            .expect("insert");
+        assert!(matches!(
+            graph.topological_order(),
+            Err(KremisError::CycleDetected(_))
+        ));
+    }
+
+    #[test]
+    fn diameter_of_chain() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3), EntityId(4)]);
+        for pair in ids.windows(2) {
+            graph
+                .insert_edge(pair[0], pair[1], EdgeWeight::new(1))
+                .expect("insert");
+        }
+
+        assert_eq!(graph.diameter(EdgeDirection::Directed), Some(3));
+        assert_eq!(graph.eccentricity(ids[0], EdgeDirection::Directed), Some(3));
+        assert_eq!(graph.eccentricity(ids[3], EdgeDirection::Directed), Some(0));
+        assert_eq!(
+            graph.eccentricity(NodeId(99), EdgeDirection::Directed),
+            None
+        );
+        assert_eq!(Graph::new().diameter(EdgeDirection::Directed), None);
+        assert_eq!(graph.diameter(EdgeDirection::Undirected), Some(3));
+        assert_eq!(
+            graph.eccentricity(ids[3], EdgeDirection::Undirected),
+            Some(3)
+        );
+        assert_eq!(Graph::new().diameter(EdgeDirection::Undirected), None);
+    }
+
+    #[test]
+    fn undirected_diameter_of_star() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(0), EntityId(1), EntityId(2), EntityId(3)]);
+        for &leaf in &ids[1..] {
+            graph
+                .insert_edge(ids[0], leaf, EdgeWeight::new(1))
+                .expect("insert");
+        }
+
+        // Directed, leaves reach nothing; undirected, leaf -> hub -> leaf
+        assert_eq!(graph.diameter(EdgeDirection::Directed), Some(1));
+        assert_eq!(graph.diameter(EdgeDirection::Undirected), Some(2));
+        assert_eq!(
+            graph.eccentricity(ids[0], EdgeDirection::Undirected),
+            Some(1)
+        );
+        assert_eq!(
+            graph.eccentricity(ids[1], EdgeDirection::Undirected),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn eccentricity_is_capped_in_both_directions() {
+        use crate::primitives::MAX_TRAVERSAL_DEPTH;
+
+        let mut graph = Graph::new();
+        let entities: Vec<EntityId> = (0..).take(MAX_TRAVERSAL_DEPTH + 5).map(EntityId).collect();
+        let ids = graph.insert_nodes(&entities);
+        for pair in ids.windows(2) {
+            graph
+                .insert_edge(pair[0], pair[1], EdgeWeight::new(1))
+                .expect("insert");
+        }
+
+        for direction in [EdgeDirection::Directed, EdgeDirection::Undirected] {
+            assert_eq!(
+                graph.eccentricity(ids[0], direction),
+                Some(MAX_TRAVERSAL_DEPTH)
+            );
+            assert_eq!(graph.diameter(direction), Some(MAX_TRAVERSAL_DEPTH));
+        }
+    }
+
+    #[test]
+    fn strongest_path_on_long_chain() {
+        let mut graph = Graph::new();
+        let entities: Vec<EntityId> = (0..1000).map(EntityId).collect();
+        let ids = graph.insert_nodes(&entities);
+        for pair in ids.windows(2) {
+            graph
+                .insert_edge(pair[0], pair[1], EdgeWeight::new(1))
+                .expect("insert");
+        }
+
+        let path = graph
+            .strongest_path(ids[0], ids[999])
+            .expect("search")
+            .expect("path");
+        assert_eq!(path, ids);
+    }
+
+    #[test]
+    fn intersect_weighted_reports_bottleneck() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3), EntityId(4)]);
+        let (a, b, shared, other) = (ids[0], ids[1], ids[2], ids[3]);
+        for (from, to, w) in [(a, shared, 5), (b, shared, 8), (a, other, 9), (b, other, 9)] {
+            graph
+                .insert_edge(from, to, EdgeWeight::new(w))
+                .expect("insert");
+        }
+
+        let mut weighted = graph.intersect_weighted(&[a, b]);
+        assert_eq!(weighted, vec![(shared, 5), (other, 9)]);
+        assert_eq!(
+            weighted.iter().map(|(n, _)| *n).collect::<Vec<_>>(),
+            graph.intersect(&[a, b]).expect("intersect")
+        );
+
+        sort_by_strength(&mut weighted);
+        assert_eq!(weighted, vec![(other, 9), (shared, 5)]);
+    }
+
+    #[test]
+    fn canonical_hash_tracks_edge_changes() {
+        let build = || {
+            let mut graph = Graph::new();
+            let ids = graph.insert_nodes(&[EntityId(1), EntityId(2)]);
+            graph
+                .insert_edge(ids[0], ids[1], EdgeWeight::new(3))
+                .expect("insert");
+            (graph, ids)
+        };
+        let (mut graph, ids) = build();
+        let (twin, _) = build();
+        assert_eq!(graph.canonical_hash(), twin.canonical_hash());
+
+        graph
+            .insert_edge(ids[0], ids[1], EdgeWeight::new(4))
+            .expect("insert");
+        assert_ne!(graph.canonical_hash(), twin.canonical_hash());
+    }
+
+    #[test]
+    fn intersect_threshold_between_union_and_intersect() {
+        let mut graph = Graph::new();
+        let entities: Vec<EntityId> = (1..=7).map(EntityId).collect();
+        let ids = graph.insert_nodes(&entities);
+        let (inputs, targets) = ids.split_at(3);
+        // targets[0] <- all three, targets[1] <- two, targets[2] <- one
+        for (from, to) in [
+            (inputs[0], targets[0]),
+            (inputs[1], targets[0]),
+            (inputs[2], targets[0]),
+            (inputs[0], targets[1]),
+            (inputs[1], targets[1]),
+            (inputs[2], targets[2]),
+        ] {
+            graph
+                .insert_edge(from, to, EdgeWeight::new(1))
+                .expect("insert");
+        }
+
+        assert_eq!(
+            graph.intersect_threshold(inputs, 1),
+            vec![targets[0], targets[1], targets[2]]
+        );
+        assert_eq!(
+            graph.intersect_threshold(inputs, 2),
+            vec![targets[0], targets[1]]
+        );
+        assert_eq!(
+            graph.intersect_threshold(inputs, 3),
+            graph.intersect(inputs).expect("intersect")
+        );
+        assert_eq!(graph.intersect_threshold(inputs, 3), vec![targets[0]]);
+    }
+
+    #[test]
+    fn traverse_streaming_matches_interruptible_edges() {
+        let graph = generate_random(12, 30, 7);
+        let (artifact, _) =
+            traverse_interruptible(&graph, NodeId(0), 3, EdgeWeight::new(2), |_| false)
+                .expect("traverse")
+                .expect("start");
+
+        let mut streamed = Vec::new();
+        let found = traverse_streaming(&graph, NodeId(0), 3, EdgeWeight::new(2), |from, to, w| {
+            streamed.push((from, to, w));
+            true
+        })
+        .expect("traverse");
+        assert!(found);
+        assert_eq!(Some(streamed), artifact.subgraph);
+
+        let mut seen = 0;
+        traverse_streaming(&graph, NodeId(0), 3, EdgeWeight::new(2), |_, _, _| {
+            seen += 1;
+            false
+        })
+        .expect("traverse");
+        assert_eq!(seen, 1);
+        assert!(
+            !traverse_streaming(&graph, NodeId(99), 3, EdgeWeight::new(0), |_, _, _| true)
+                .expect("traverse")
+        );
+    }
+
+    #[test]
+    fn traverse_undirected_follows_incoming_edges() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3)]);
+        let (a, b, c) = (ids[0], ids[1], ids[2]);
+        graph.insert_edge(a, b, EdgeWeight::new(4)).expect("insert");
+        graph.insert_edge(c, a, EdgeWeight::new(2)).expect("insert");
+
+        // Directed traversal from b sees nothing: b has no outgoing edges
+        let directed = graph.traverse(b, 3).expect("traverse").expect("found");
+        assert_eq!(directed.path, vec![b]);
+
+        let artifact = graph.traverse_undirected(b, 3).expect("found");
+        assert_eq!(artifact.path, vec![b, a, c]);
+        assert_eq!(
+            artifact.subgraph,
+            Some(vec![(a, b, EdgeWeight::new(4)), (c, a, EdgeWeight::new(2))])
+        );
+        assert!(graph.traverse_undirected(NodeId(99), 1).is_none());
+    }
+
+    #[test]
+    fn traverse_capped_fanout_expands_only_heaviest_neighbors() {
+        let mut graph = Graph::new();
+        let entities: Vec<EntityId> = (0..=100).map(EntityId).collect();
+        let ids = graph.insert_nodes(&entities);
+        let hub = ids[0];
+        for (i, &leaf) in ids[1..].iter().enumerate() {
+            let weight = i64::try_from(i).expect("fits");
+            graph
+                .insert_edge(hub, leaf, EdgeWeight::new(weight))
+                .expect("insert");
+        }
+
+        let artifact = graph.traverse_capped_fanout(hub, 3, 5).expect("found");
+        let top5: Vec<NodeId> = ids[96..].iter().rev().copied().collect();
+        assert_eq!(artifact.path.len(), 6);
+        assert_eq!(artifact.path[1..], top5[..]);
+        let subgraph = artifact.subgraph.expect("subgraph");
+        assert_eq!(subgraph.len(), 5);
+        assert!(
+            subgraph
+                .iter()
+                .all(|(from, to, _)| *from == hub && top5.contains(to))
+        );
+    }
+
+    #[test]
+    fn traverse_levels_groups_nodes_by_hop_count() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3)]);
+        let (a, b, c) = (ids[0], ids[1], ids[2]);
+        graph.insert_edge(a, b, EdgeWeight::new(1)).expect("insert");
+        graph.insert_edge(b, c, EdgeWeight::new(1)).expect("insert");
+
+        let levels = graph.traverse_levels(a, 5);
+        assert_eq!(
+            levels,
+            BTreeMap::from([(0, vec![a]), (1, vec![b]), (2, vec![c])])
+        );
+        assert_eq!(graph.traverse_levels(a, 1).len(), 2);
+        assert!(graph.traverse_levels(NodeId(99), 2).is_empty());
+    }
+
+    #[test]
+    fn weight_saturation_is_logged_once_at_the_boundary() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2)]);
+        let (a, b) = (ids[0], ids[1]);
+        graph
+            .insert_edge(a, b, EdgeWeight::new(i64::MAX - 2))
+            .expect("insert");
+
+        graph.increment_edge(a, b).expect("increment");
+        assert!(graph.take_changes().is_empty());
+        for _ in 0..3 {
+            graph.increment_edge(a, b).expect("increment");
+        }
+        assert_eq!(
+            graph.get_edge(a, b).expect("get"),
+            Some(EdgeWeight::new(i64::MAX))
+        );
+        assert_eq!(
+            graph.take_changes(),
+            vec![GraphChange::WeightSaturated { from: a, to: b }]
+        );
+
+        // Dropping below the boundary re-arms the event
+        graph.adjust_edge(a, b, -5).expect("adjust");
+        graph.adjust_edge(a, b, 100).expect("adjust");
+        assert_eq!(graph.take_changes().len(), 1);
+        assert!(graph.take_changes().is_empty());
+    }
+
+    #[test]
+    fn traverse_emits_each_diamond_edge_once() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3), EntityId(4)]);
+        let (a, b, c, d) = (ids[0], ids[1], ids[2], ids[3]);
+        for (from, to) in [(a, b), (a, c), (b, d), (c, d), (d, a)] {
+            graph
+                .insert_edge(from, to, EdgeWeight::new(3))
+                .expect("insert");
+        }
+
+        let traversed = graph.traverse(a, 5).expect("traverse").expect("found");
+        let filtered = graph
+            .traverse_filtered(a, 5, EdgeWeight::new(1))
+            .expect("traverse")
+            .expect("found");
+        for artifact in [traversed, filtered] {
+            let edges = artifact.subgraph.expect("subgraph");
+            let unique: BTreeSet<(NodeId, NodeId)> =
+                edges.iter().map(|&(from, to, _)| (from, to)).collect();
+            assert_eq!(edges.len(), 5);
+            assert_eq!(unique.len(), edges.len());
+        }
     }
 
     #[test]
-    fn decrement_edge_reduces_weight_by_one() {
+    fn component_tracker_agrees_with_connected_components() {
         let mut graph = Graph::new();
-        let a = graph.insert_node(EntityId(1)).expect("insert");
-        let b = graph.insert_node(EntityId(2)).expect("insert");
+        graph.track_components();
+        let entities: Vec<EntityId> = (0..8).map(EntityId).collect();
+        let ids = graph.insert_nodes(&entities);
+        let edges = [(0, 1), (2, 3), (4, 3), (1, 5), (6, 6), (5, 0)];
+
+        // Group nodes by tracker label; the groups must equal the components
+        let agrees = |graph: &Graph| {
+            let mut groups: BTreeMap<Option<usize>, Vec<NodeId>> = BTreeMap::new();
+            for node in graph.nodes() {
+                groups
+                    .entry(graph.component_of(node.id))
+                    .or_default()
+                    .push(node.id);
+            }
+            let mut groups: Vec<Vec<NodeId>> = groups.into_values().collect();
+            groups.sort();
+            groups == graph.connected_components()
+        };
 
-        graph.insert_edge(a, b, EdgeWeight::new(5)).expect("insert");
-        graph.decrement_edge(a, b).expect("decrement");
-        assert_eq!(graph.get_edge(a, b).expect("get"), Some(EdgeWeight::new(4)));
+        for (from, to) in edges {
+            graph
+                .insert_edge(ids[from], ids[to], EdgeWeight::new(1))
+                .expect("insert");
+            assert!(agrees(&graph));
+        }
+        let late = graph.insert_node(EntityId(100)).expect("insert");
+        graph.increment_edge(late, ids[7]).expect("increment");
+        assert!(agrees(&graph));
+        assert_eq!(graph.component_of(late), graph.component_of(ids[7]));
+
+        // Removal splits {2, 3, 4}; the tracker is rebuilt
+        assert!(graph.remove_edge(ids[4], ids[3]).is_some());
+        assert!(agrees(&graph));
+        assert_ne!(graph.component_of(ids[4]), graph.component_of(ids[3]));
+        assert_eq!(Graph::new().component_of(ids[0]), None);
     }
 
     #[test]
-    fn decrement_edge_floors_at_zero() {
+    fn merge_nodes_redirects_and_sums_edges() {
         let mut graph = Graph::new();
-        let a = graph.insert_node(EntityId(1)).expect("insert");
-        let b = graph.insert_node(EntityId(2)).expect("insert");
+        let ids = graph.insert_nodes(&[
+            EntityId(1),
+            EntityId(2),
+            EntityId(3),
+            EntityId(4),
+            EntityId(5),
+        ]);
+        let (a, keep, drop, c, shared) = (ids[0], ids[1], ids[2], ids[3], ids[4]);
+        for (from, to, w) in [
+            (a, drop, 2),
+            (drop, c, 3),
+            (keep, shared, 4),
+            (drop, shared, 6),
+            (keep, drop, 1),
+        ] {
+            graph
+                .insert_edge(from, to, EdgeWeight::new(w))
+                .expect("insert");
+        }
 
-        graph.insert_edge(a, b, EdgeWeight::new(1)).expect("insert");
-        graph.decrement_edge(a, b).expect("decrement to 0");
-        assert_eq!(graph.get_edge(a, b).expect("get"), Some(EdgeWeight::new(0)));
+        graph.merge_nodes(keep, drop).expect("merge");
 
-        // Further decrements stay at 0
-        graph.decrement_edge(a, b).expect("decrement at 0");
-        assert_eq!(graph.get_edge(a, b).expect("get"), Some(EdgeWeight::new(0)));
+        assert!(!graph.contains_node_internal(drop));
+        assert_eq!(graph.get_edge_internal(a, keep), Some(EdgeWeight::new(2)));
+        assert_eq!(graph.get_edge_internal(keep, c), Some(EdgeWeight::new(3)));
+        assert_eq!(
+            graph.get_edge_internal(keep, shared),
+            Some(EdgeWeight::new(10))
+        );
+        // keep -> drop would be a self-loop
+        assert_eq!(graph.get_edge_internal(keep, keep), None);
+        assert_eq!(graph.get_node_by_entity(EntityId(3)), Some(keep));
+        assert!(graph.check_invariants().is_ok());
+        assert!(matches!(
+            graph.merge_nodes(keep, drop),
+            Err(KremisError::NodeNotFound(id)) if id == drop
+        ));
     }
 
     #[test]
-    fn decrement_edge_nonexistent_returns_error() {
+    fn merged_entity_reingests_into_kept_node() {
+        use crate::Signal;
+        use crate::ingestor::Ingestor;
+
         let mut graph = Graph::new();
-        let a = graph.insert_node(EntityId(1)).expect("insert");
-        let b = graph.insert_node(EntityId(2)).expect("insert");
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3)]);
+        let (keep, drop, other) = (ids[0], ids[1], ids[2]);
+        graph.merge_nodes(keep, drop).expect("merge");
+
+        let count = graph.node_count().expect("count");
+        let signal = Signal::new(EntityId(2), Attribute::new("name"), Value::new("Bob"));
+        let node = Ingestor::ingest_signal(&mut graph, &signal).expect("ingest");
+        assert_eq!(node, keep);
+        assert_eq!(graph.node_count().expect("count"), count);
+        assert_eq!(
+            graph.attribute_value(EntityId(2), &Attribute::new("name")),
+            Some(&Value::new("Bob"))
+        );
 
-        let result = graph.decrement_edge(a, b);
-        assert!(matches!(result, Err(KremisError::EdgeNotFound(_, _))));
+        // Chained merges carry earlier merged entities along
+        graph.merge_nodes(other, keep).expect("merge");
+        assert_eq!(graph.get_node_by_entity(EntityId(1)), Some(other));
+        assert_eq!(graph.get_node_by_entity(EntityId(2)), Some(other));
+        assert!(graph.check_invariants().is_ok());
+
+        // Removing the surviving node forgets every entity merged into it
+        graph.remove_node(other);
+        assert_eq!(graph.get_node_by_entity(EntityId(2)), None);
+        assert_ne!(graph.insert_node(EntityId(2)).expect("insert"), other);
+        assert!(graph.check_invariants().is_ok());
     }
 
     #[test]
-    fn decrement_edge_then_increment_roundtrip() {
+    fn prune_weak_edges_removes_only_edges_below_threshold() {
         let mut graph = Graph::new();
-        let a = graph.insert_node(EntityId(1)).expect("insert");
-        let b = graph.insert_node(EntityId(2)).expect("insert");
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3), EntityId(4)]);
+        for (to, w) in [(ids[1], 1), (ids[2], 5), (ids[3], 10)] {
+            graph
+                .insert_edge(ids[0], to, EdgeWeight::new(w))
+                .expect("insert");
+        }
 
-        graph.insert_edge(a, b, EdgeWeight::new(3)).expect("insert");
-        graph.decrement_edge(a, b).expect("decrement");
-        graph.increment_edge(a, b).expect("increment");
-        assert_eq!(graph.get_edge(a, b).expect("get"), Some(EdgeWeight::new(3)));
+        assert_eq!(graph.prune_weak_edges(EdgeWeight::new(5)), 1);
+        assert_eq!(graph.get_edge_internal(ids[0], ids[1]), None);
+        assert_eq!(
+            graph.get_edge_internal(ids[0], ids[2]),
+            Some(EdgeWeight::new(5))
+        );
+        assert_eq!(
+            graph.get_edge_internal(ids[0], ids[3]),
+            Some(EdgeWeight::new(10))
+        );
+        // The isolated node stays
+        assert!(graph.contains_node_internal(ids[1]));
+        assert!(graph.check_invariants().is_ok());
+        assert_eq!(graph.prune_weak_edges(EdgeWeight::new(5)), 0);
     }
 
     #[test]
-    fn neighbors_in_deterministic_order() {
-        let mut graph = Graph::new();
-        let a = graph.insert_node(EntityId(1)).expect("insert");
-        let b = graph.insert_node(EntityId(2)).expect("insert");
-        let c = graph.insert_node(EntityId(3)).expect("insert");
+    fn diff_reports_node_and_edge_changes() {
+        let mut old = Graph::new();
+        let ids = old.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3)]);
+        for (from, to, w) in [(0, 1, 2), (1, 2, 3), (2, 0, 4)] {
+            old.insert_edge(ids[from], ids[to], EdgeWeight::new(w))
+                .expect("insert");
+        }
+        assert!(diff(&old, &old).is_empty());
 
-        // Insert edges in non-sorted order
-        graph.insert_edge(a, c, EdgeWeight::new(1)).expect("insert");
-        graph.insert_edge(a, b, EdgeWeight::new(2)).expect("insert");
+        let mut new = old.clone();
+        let added = new.insert_node(EntityId(4)).expect("insert");
+        new.insert_edge(ids[1], ids[2], EdgeWeight::new(9))
+            .expect("insert");
+        assert!(new.remove_edge(ids[2], ids[0]).is_some());
 
-        let neighbors: Vec<_> = graph
-            .neighbors(a)
-            .expect("neighbors")
-            .into_iter()
-            .map(|(n, _)| n)
-            .collect();
+        let changes = diff(&old, &new);
+        assert_eq!(changes.added_nodes, vec![added]);
+        assert!(changes.removed_nodes.is_empty());
+        assert!(changes.added_edges.is_empty());
+        assert_eq!(
+            changes.removed_edges,
+            vec![(ids[2], ids[0], EdgeWeight::new(4))]
+        );
+        assert_eq!(
+            changes.changed_edges,
+            vec![(ids[1], ids[2], EdgeWeight::new(3), EdgeWeight::new(9))]
+        );
 
-        // Should be sorted by NodeId
-        assert_eq!(neighbors, vec![b, c]);
+        // The reverse diff swaps additions and removals
+        let back = diff(&new, &old);
+        assert_eq!(back.removed_nodes, vec![added]);
+        assert_eq!(back.added_edges, changes.removed_edges);
     }
 
     #[test]
-    fn traverse_respects_depth() {
+    fn forget_weakest_removes_lowest_weights_in_order() {
         let mut graph = Graph::new();
-        let a = graph.insert_node(EntityId(1)).expect("insert");
-        let b = graph.insert_node(EntityId(2)).expect("insert");
-        let c = graph.insert_node(EntityId(3)).expect("insert");
-
-        graph.insert_edge(a, b, EdgeWeight::new(1)).expect("insert");
-        graph.insert_edge(b, c, EdgeWeight::new(1)).expect("insert");
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3)]);
+        let (a, b, c) = (ids[0], ids[1], ids[2]);
+        for (from, to, w) in [(a, b, 4), (a, c, 2), (b, a, 7), (b, c, 2), (c, a, 9)] {
+            graph
+                .insert_edge(from, to, EdgeWeight::new(w))
+                .expect("insert");
+        }
 
-        // Depth 1: should reach a and b
-        let artifact = graph.traverse(a, 1).expect("traverse");
-        assert!(artifact.is_some());
+        assert_eq!(
+            graph.forget_weakest(2),
+            vec![(a, c, EdgeWeight::new(2)), (b, c, EdgeWeight::new(2))]
+        );
+        assert_eq!(graph.edge_count().expect("count"), 3);
+        assert!(!graph.contains_edge(a, c) && !graph.contains_edge(b, c));
+        assert!(graph.check_invariants().is_ok());
 
-        let path = artifact.as_ref().map(|a| &a.path);
-        assert!(path.is_some());
-        assert!(path.map(|p| p.contains(&a)).unwrap_or(false));
-        assert!(path.map(|p| p.contains(&b)).unwrap_or(false));
+        assert_eq!(graph.forget_weakest(10).len(), 3);
+        assert_eq!(graph.edge_count().expect("count"), 0);
+        assert_eq!(graph.node_count().expect("count"), 3);
     }
 
     #[test]
-    fn traverse_missing_node_returns_none() {
-        let graph = Graph::new();
-        let result = graph.traverse(NodeId(999), 5).expect("traverse");
-        assert!(result.is_none());
+    fn chain_head_is_source_and_tail_is_sink() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3), EntityId(4)]);
+        for pair in ids[..3].windows(2) {
+            graph
+                .insert_edge(pair[0], pair[1], EdgeWeight::new(1))
+                .expect("insert");
+        }
+
+        // ids[3] is isolated and belongs to neither list.
+        assert_eq!(graph.source_nodes(), vec![ids[0]]);
+        assert_eq!(graph.sink_nodes(), vec![ids[2]]);
     }
 
     #[test]
-    fn strongest_path_finds_route() {
+    fn traverse_with_frontier_reports_depth_boundary() {
+        // 0 -> 1 -> 3 -> 5
+        // 0 -> 2 -> 4
         let mut graph = Graph::new();
-        let a = graph.insert_node(EntityId(1)).expect("insert");
-        let b = graph.insert_node(EntityId(2)).expect("insert");
-        let c = graph.insert_node(EntityId(3)).expect("insert");
-
-        graph
-            .insert_edge(a, b, EdgeWeight::new(10))
-            .expect("insert");
-        graph
-            .insert_edge(b, c, EdgeWeight::new(10))
-            .expect("insert");
+        let ids = graph.insert_nodes(&(0..6).map(EntityId).collect::<Vec<_>>());
+        for (from, to) in [(0, 1), (0, 2), (1, 3), (2, 4), (3, 5)] {
+            graph
+                .insert_edge(ids[from], ids[to], EdgeWeight::new(1))
+                .expect("insert");
+        }
 
-        let path = graph.strongest_path(a, c).expect("path");
-        assert_eq!(path, Some(vec![a, b, c]));
+        let (artifact, frontier) = graph
+            .traverse_with_frontier(ids[0], 2)
+            .expect("start exists");
+        assert_eq!(frontier, vec![ids[3], ids[4]]);
+        assert_eq!(artifact.path.len(), 5);
+        assert!(!artifact.path.contains(&ids[5]));
+
+        let (_, frontier) = graph
+            .traverse_with_frontier(ids[3], 5)
+            .expect("start exists");
+        assert!(frontier.is_empty());
+        assert!(graph.traverse_with_frontier(NodeId(99), 1).is_none());
     }
 
     #[test]
-    fn intersect_finds_common_neighbors() {
+    fn hubs_rank_by_degree_then_id() {
         let mut graph = Graph::new();
-        let a = graph.insert_node(EntityId(1)).expect("insert");
-        let b = graph.insert_node(EntityId(2)).expect("insert");
-        let common = graph.insert_node(EntityId(100)).expect("insert");
-
-        graph
-            .insert_edge(a, common, EdgeWeight::new(1))
-            .expect("insert");
-        graph
-            .insert_edge(b, common, EdgeWeight::new(1))
-            .expect("insert");
+        let ids = graph.insert_nodes(&(0..5).map(EntityId).collect::<Vec<_>>());
+        for (from, to) in [(0, 1), (0, 2), (3, 0), (1, 2)] {
+            graph
+                .insert_edge(ids[from], ids[to], EdgeWeight::new(1))
+                .expect("insert");
+        }
 
-        let result = graph.intersect(&[a, b]).expect("intersect");
-        assert_eq!(result, vec![common]);
+        assert_eq!(graph.hubs(2), vec![(ids[0], 3), (ids[1], 2)]);
+        // ids[4] is isolated.
+        assert_eq!(graph.hubs(10).len(), 4);
     }
 
     #[test]
-    fn serialization_roundtrip() {
+    fn traverse_with_budget_truncates_wide_star() {
         let mut graph = Graph::new();
-        let a = graph.insert_node(EntityId(1)).expect("insert");
-        let b = graph.insert_node(EntityId(2)).expect("insert");
-        graph.insert_edge(a, b, EdgeWeight::new(5)).expect("insert");
+        let ids = graph.insert_nodes(&(0..11).map(EntityId).collect::<Vec<_>>());
+        for &leaf in &ids[1..] {
+            graph
+                .insert_edge(ids[0], leaf, EdgeWeight::new(1))
+                .expect("insert");
+        }
 
-        let serializable = SerializableGraph::from(&graph);
-        let restored = Graph::from(serializable);
+        let (artifact, truncated) = graph
+            .traverse_with_budget(ids[0], 2, 3)
+            .expect("start exists");
+        assert!(truncated);
+        assert_eq!(artifact.path, vec![ids[0], ids[1], ids[2]]);
+        assert_eq!(artifact.subgraph.as_ref().map(Vec::len), Some(2));
+
+        let (full, truncated) = graph
+            .traverse_with_budget(ids[0], 2, 11)
+            .expect("start exists");
+        assert!(!truncated);
+        assert_eq!(Some(full), graph.traverse(ids[0], 2).expect("traverse"));
+    }
 
+    #[test]
+    fn traverse_instrumented_counts_work() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&(0..6).map(EntityId).collect::<Vec<_>>());
+        for (from, to) in [(0, 1), (0, 2), (0, 3), (1, 4), (2, 4), (4, 5)] {
+            graph
+                .insert_edge(ids[from], ids[to], EdgeWeight::new(1))
+                .expect("insert");
+        }
+
+        // Expanding 0 queues 1, 2, 3; expanding 1 queues 4 (queue 2, 3, 4);
+        // 2 re-reaches 4; 3 has no edges; 4 sits at depth 2 and is not
+        // expanded. Five nodes, five edges, peak queue of three.
+        let (artifact, cost) = graph
+            .traverse_instrumented(ids[0], 2)
+            .expect("start exists");
         assert_eq!(
-            graph.node_count().expect("count"),
-            restored.node_count().expect("count")
-        );
-        assert_eq!(
-            graph.edge_count().expect("count"),
-            restored.edge_count().expect("count")
-        );
-        assert_eq!(
-            restored.get_edge(a, b).expect("get"),
-            Some(EdgeWeight::new(5))
+            cost,
+            TraverseCost {
+                nodes_visited: 5,
+                edges_examined: 5,
+                max_queue_size: 3,
+            }
         );
+        assert_eq!(Some(artifact), graph.traverse(ids[0], 2).expect("traverse"));
+        assert!(graph.traverse_instrumented(NodeId(99), 2).is_none());
     }
 
     #[test]
-    fn store_and_get_properties() {
+    fn retain_reachable_from_drops_disconnected_cluster() {
         let mut graph = Graph::new();
-        let node = graph.insert_node(EntityId(1)).expect("insert");
+        let ids = graph.insert_nodes(&(0..6).map(EntityId).collect::<Vec<_>>());
+        // Main cluster 0 -> 1 <- 2, reached from 0 only via an incoming edge
+        // at 1; separate cluster 3 -> 4; node 5 isolated.
+        for (from, to) in [(0, 1), (2, 1), (3, 4)] {
+            graph
+                .insert_edge(ids[from], ids[to], EdgeWeight::new(1))
+                .expect("insert");
+        }
 
-        graph
-            .store_property(node, Attribute::new("name"), Value::new("Alice"))
-            .expect("store");
-        graph
-            .store_property(node, Attribute::new("age"), Value::new("30"))
-            .expect("store");
+        let removed = graph.retain_reachable_from(&[ids[0], NodeId(99)]);
+        assert_eq!(removed, 3);
+        let remaining: Vec<NodeId> = graph.nodes().map(|n| n.id).collect();
+        assert_eq!(remaining, vec![ids[0], ids[1], ids[2]]);
+        assert_eq!(graph.edges().count(), 2);
+        assert_eq!(graph.get_node_by_entity(EntityId(3)), None);
 
-        let props = graph.get_properties(node).expect("get");
-        assert_eq!(props.len(), 2);
-        assert!(props.contains(&(Attribute::new("name"), Value::new("Alice"))));
-        assert!(props.contains(&(Attribute::new("age"), Value::new("30"))));
+        assert_eq!(graph.retain_reachable_from(&[ids[2]]), 0);
+        assert_eq!(graph.retain_reachable_from(&[]), 3);
+        assert_eq!(graph.nodes().count(), 0);
     }
 
     #[test]
-    fn store_multiple_values_same_attribute() {
+    fn top_neighbors_ranks_by_weight_then_id() {
         let mut graph = Graph::new();
-        let node = graph.insert_node(EntityId(1)).expect("insert");
-
-        graph
-            .store_property(node, Attribute::new("knows"), Value::new("Bob"))
-            .expect("store");
-        graph
-            .store_property(node, Attribute::new("knows"), Value::new("Charlie"))
-            .expect("store");
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3), EntityId(4)]);
+        for (to, w) in [(ids[1], 2), (ids[2], 7), (ids[3], 2)] {
+            graph
+                .insert_edge(ids[0], to, EdgeWeight::new(w))
+                .expect("insert");
+        }
 
-        let props = graph.get_properties(node).expect("get");
-        assert_eq!(props.len(), 2);
-        assert!(props.contains(&(Attribute::new("knows"), Value::new("Bob"))));
-        assert!(props.contains(&(Attribute::new("knows"), Value::new("Charlie"))));
+        assert_eq!(
+            top_neighbors(&graph, ids[0], 2).expect("rank"),
+            vec![(ids[2], EdgeWeight::new(7)), (ids[1], EdgeWeight::new(2))]
+        );
+        assert_eq!(top_neighbors(&graph, ids[0], 10).expect("rank").len(), 3);
+        assert!(top_neighbors(&graph, ids[3], 10).expect("rank").is_empty());
     }
 
     #[test]
-    fn store_property_nonexistent_node_fails() {
+    fn compare_paths_reports_both_routes() {
         let mut graph = Graph::new();
-        let result = graph.store_property(NodeId(999), Attribute::new("name"), Value::new("Test"));
-        assert!(result.is_err());
-    }
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3), EntityId(4)]);
+        let (start, light, heavy, end) = (ids[0], ids[1], ids[2], ids[3]);
+        for (from, to, w) in [
+            (start, light, 1),
+            (light, end, 1),
+            (start, heavy, 9),
+            (heavy, end, 9),
+        ] {
+            graph
+                .insert_edge(from, to, EdgeWeight::new(w))
+                .expect("insert");
+        }
 
-    #[test]
-    fn get_properties_nonexistent_node_fails() {
-        let graph = Graph::new();
-        let result = graph.get_properties(NodeId(999));
-        assert!(result.is_err());
+        let cmp = graph.compare_paths(start, end).expect("reachable");
+        assert_eq!(cmp.shortest, vec![start, light, end]);
+        assert_eq!(cmp.strongest, vec![start, heavy, end]);
+        assert_eq!((cmp.shortest_hops, cmp.strongest_hops), (2, 2));
+        assert_eq!((cmp.shortest_weight, cmp.strongest_weight), (2, 18));
+        assert!(cmp.diverges());
+
+        // A direct hop is both shortest and strongest
+        graph
+            .insert_edge(start, end, EdgeWeight::new(1))
+            .expect("insert");
+        let cmp = graph.compare_paths(start, end).expect("reachable");
+        assert_eq!(cmp.shortest, vec![start, end]);
+        assert!(!cmp.diverges());
+        assert_eq!(cmp.strongest_weight, 1);
+
+        assert!(graph.compare_paths(end, start).is_none());
     }
 
     #[test]
-    fn get_properties_empty_returns_empty_vec() {
+    fn compare_paths_prefers_fewer_hops_over_higher_total_weight() {
         let mut graph = Graph::new();
-        let node = graph.insert_node(EntityId(1)).expect("insert");
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3), EntityId(4)]);
+        let (start, a, b, end) = (ids[0], ids[1], ids[2], ids[3]);
+        // The three-hop route carries 150 against the direct edge's 1
+        for (from, to, w) in [(start, a, 50), (a, b, 50), (b, end, 50), (start, end, 1)] {
+            graph
+                .insert_edge(from, to, EdgeWeight::new(w))
+                .expect("insert");
+        }
 
-        let props = graph.get_properties(node).expect("get");
-        assert!(props.is_empty());
+        let cmp = graph.compare_paths(start, end).expect("reachable");
+        assert_eq!(cmp.shortest, vec![start, end]);
+        assert_eq!(cmp.strongest, vec![start, end]);
+        assert_eq!((cmp.shortest_hops, cmp.strongest_hops), (1, 1));
+        assert_eq!((cmp.shortest_weight, cmp.strongest_weight), (1, 1));
+        assert!(!cmp.diverges());
+
+        // Weights at i64::MAX cost nothing per hop, so the long route wins
+        for (from, to) in [(start, a), (a, b), (b, end)] {
+            graph
+                .insert_edge(from, to, EdgeWeight::new(i64::MAX))
+                .expect("insert");
+        }
+        let cmp = graph.compare_paths(start, end).expect("reachable");
+        assert_eq!(cmp.shortest, vec![start, end]);
+        assert_eq!(cmp.strongest, vec![start, a, b, end]);
+        assert_eq!((cmp.shortest_hops, cmp.strongest_hops), (1, 3));
+        assert_eq!((cmp.shortest_weight, cmp.strongest_weight), (1, i64::MAX));
+        assert!(cmp.diverges());
     }
 
     #[test]
-    fn insert_edge_ignores_dangling_nodes() {
+    fn traverse_best_first_expands_strongest_edges_first() {
         let mut graph = Graph::new();
-        let node1 = graph.insert_node(EntityId(1)).expect("insert");
-        let dangling = NodeId(999);
+        let ids = graph.insert_nodes(&[
+            EntityId(0),
+            EntityId(1),
+            EntityId(2),
+            EntityId(3),
+            EntityId(4),
+        ]);
+        let (root, weak, strong, weak_child, strong_child) =
+            (ids[0], ids[1], ids[2], ids[3], ids[4]);
+        for (from, to, w) in [
+            (root, weak, 1),
+            (root, strong, 10),
+            (weak, weak_child, 1),
+            (strong, strong_child, 5),
+        ] {
+            graph
+                .insert_edge(from, to, EdgeWeight::new(w))
+                .expect("insert");
+        }
 
-        // Edge from existing to non-existing: silently ignored
-        graph
-            .insert_edge(node1, dangling, EdgeWeight::new(5))
-            .expect("insert edge");
-        assert_eq!(graph.edge_count().expect("count"), 0);
+        let bfs = graph.traverse(root, 2).expect("traverse").expect("exists");
+        assert_eq!(bfs.path, vec![root, weak, strong, weak_child, strong_child]);
 
-        // Edge from non-existing to existing: silently ignored
-        graph
-            .insert_edge(dangling, node1, EdgeWeight::new(5))
-            .expect("insert edge");
-        assert_eq!(graph.edge_count().expect("count"), 0);
+        // The strong branch is explored all the way before the weak one
+        let best = graph.traverse_best_first(root, 2).expect("exists");
+        assert_eq!(
+            best.path,
+            vec![root, strong, strong_child, weak, weak_child]
+        );
+
+        let shallow = graph.traverse_best_first(root, 1).expect("exists");
+        assert_eq!(shallow.path, vec![root, strong, weak]);
+        assert!(graph.traverse_best_first(NodeId(99), 1).is_none());
     }
 
     #[test]
-    fn serializable_graph_roundtrip_with_properties() {
-        let mut graph = Graph::new();
-        let a = graph.insert_node(EntityId(1)).expect("insert");
-        let b = graph.insert_node(EntityId(2)).expect("insert");
-        graph.insert_edge(a, b, EdgeWeight::new(5)).expect("insert");
-
-        graph
-            .store_property(a, Attribute::new("name"), Value::new("Alice"))
-            .expect("store");
-        graph
-            .store_property(a, Attribute::new("role"), Value::new("admin"))
+    fn merge_sums_shared_edges_by_entity() {
+        let mut left = Graph::new();
+        let l = left.insert_nodes(&[EntityId(1), EntityId(2)]);
+        left.insert_edge(l[0], l[1], EdgeWeight::new(3))
+            .expect("insert");
+        left.store_property(l[0], Attribute::new("name"), Value::new("A"))
             .expect("store");
-        graph
-            .store_property(b, Attribute::new("name"), Value::new("Bob"))
+
+        // Different NodeId assignment for the same entities
+        let mut right = Graph::new();
+        let r = right.insert_nodes(&[EntityId(3), EntityId(2), EntityId(1)]);
+        right
+            .insert_edge(r[2], r[1], EdgeWeight::new(4))
+            .expect("insert");
+        right
+            .insert_edge(r[1], r[0], EdgeWeight::new(1))
+            .expect("insert");
+        right
+            .store_property(r[2], Attribute::new("name"), Value::new("A"))
             .expect("store");
 
-        let serializable = SerializableGraph::from(&graph);
-        assert_eq!(serializable.properties.len(), 3);
+        left.merge(&right);
 
-        let restored = Graph::from(serializable);
+        assert_eq!(left.node_count().expect("count"), 3);
+        assert_eq!(left.get_edge_internal(l[0], l[1]), Some(EdgeWeight::new(7)));
+        let three = left.get_node_by_entity(EntityId(3)).expect("merged node");
+        assert_eq!(
+            left.get_edge_internal(l[1], three),
+            Some(EdgeWeight::new(1))
+        );
+        assert_eq!(left.get_properties(l[0]).expect("props").len(), 1);
+    }
+
+    #[test]
+    fn in_neighbors_follow_edge_changes() {
+        let mut graph = Graph::new();
+        let ids = graph.insert_nodes(&[EntityId(1), EntityId(2), EntityId(3)]);
+        let (a, b, c) = (ids[0], ids[1], ids[2]);
+        graph.insert_edge(c, b, EdgeWeight::new(2)).expect("insert");
+        graph.increment_edge(a, b).expect("increment");
 
         assert_eq!(
-            graph.node_count().expect("count"),
-            restored.node_count().expect("count")
+            graph.in_neighbors(b).expect("in_neighbors"),
+            vec![(a, EdgeWeight::new(1)), (c, EdgeWeight::new(2))]
         );
+        assert!(graph.in_neighbors(a).expect("in_neighbors").is_empty());
+
+        graph.adjust_edge(c, b, -2).expect("adjust");
         assert_eq!(
-            graph.edge_count().expect("count"),
-            restored.edge_count().expect("count")
+            graph.in_neighbors(b).expect("in_neighbors"),
+            vec![(a, EdgeWeight::new(1))]
         );
 
-        let props_a = restored.get_properties(a).expect("get");
-        assert_eq!(props_a.len(), 2);
-        assert!(props_a.contains(&(Attribute::new("name"), Value::new("Alice"))));
-        assert!(props_a.contains(&(Attribute::new("role"), Value::new("admin"))));
+        graph.insert_edge(b, c, EdgeWeight::new(1)).expect("insert");
+        assert!(graph.remove_node(a));
+        assert!(graph.in_neighbors(b).expect("in_neighbors").is_empty());
+        assert_eq!(graph.check_invariants(), Ok(()));
 
-        let props_b = restored.get_properties(b).expect("get");
-        assert_eq!(props_b.len(), 1);
-        assert!(props_b.contains(&(Attribute::new("name"), Value::new("Bob"))));
+        graph.compact_ids();
+        assert_eq!(graph.check_invariants(), Ok(()));
     }
 }
@@ -7,10 +7,14 @@
 //! - Result annotated with evidence path
 //! - Unverifiable claims explicitly marked
 
-use crate::confidence::{ConfidenceScore, compute_confidence, compute_path_confidence};
+use crate::confidence::{
+    ConfidenceScore, ConfidenceStrategy, DefaultConfidence, VERIFIED_THRESHOLD,
+    compute_path_confidence,
+};
 use crate::graph::{Graph, GraphStore};
 use crate::query::{Query, QueryType};
 use crate::{Artifact, NodeId};
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Result of hypothesis verification.
 ///
@@ -61,6 +65,34 @@ impl GroundedResult {
 /// the result with verification status.
 #[must_use]
 pub fn verify_hypothesis(graph: &Graph, query: Query) -> GroundedResult {
+    verify_hypothesis_with_threshold(graph, query, VERIFIED_THRESHOLD)
+}
+
+/// Like [`verify_hypothesis`], but a result only counts as verified when
+/// its confidence reaches `min_confidence` instead of `VERIFIED_THRESHOLD`.
+///
+/// Confidence is computed exactly as before; results without an artifact
+/// are never verified.
+#[must_use]
+pub fn verify_hypothesis_with_threshold(
+    graph: &Graph,
+    query: Query,
+    min_confidence: u8,
+) -> GroundedResult {
+    let mut result = verify_hypothesis_with(graph, query, &DefaultConfidence);
+    result.verified = result.artifact.is_some() && result.confidence.score >= min_confidence;
+    result
+}
+
+/// Like [`verify_hypothesis`], scoring results with `strategy`.
+///
+/// Entity lookups are exact hits and always score 100.
+#[must_use]
+pub fn verify_hypothesis_with(
+    graph: &Graph,
+    query: Query,
+    strategy: &dyn ConfidenceStrategy,
+) -> GroundedResult {
     match query.query_type {
         QueryType::Lookup(entity) => {
             if let Some(node_id) = graph.get_node_by_entity(entity) {
@@ -74,7 +106,7 @@ pub fn verify_hypothesis(graph: &Graph, query: Query) -> GroundedResult {
 
         QueryType::Traverse { start, depth } => match graph.traverse(start, depth) {
             Ok(Some(artifact)) => {
-                let confidence = compute_confidence(&artifact, graph);
+                let confidence = strategy.score(&artifact, graph);
                 GroundedResult::with_artifact(artifact, confidence)
             }
             _ => GroundedResult::unverified(),
@@ -86,7 +118,7 @@ pub fn verify_hypothesis(graph: &Graph, query: Query) -> GroundedResult {
             min_weight,
         } => match graph.traverse_filtered(start, depth, min_weight) {
             Ok(Some(artifact)) => {
-                let confidence = compute_confidence(&artifact, graph);
+                let confidence = strategy.score(&artifact, graph);
                 GroundedResult::with_artifact(artifact, confidence)
             }
             _ => GroundedResult::unverified(),
@@ -94,7 +126,7 @@ pub fn verify_hypothesis(graph: &Graph, query: Query) -> GroundedResult {
 
         QueryType::StrongestPath { start, end } => match graph.strongest_path(start, end) {
             Ok(Some(path)) => {
-                let confidence = compute_path_confidence(&path, graph);
+                let confidence = strategy.score_path(&path, graph);
                 let artifact = Artifact::with_path(path);
                 GroundedResult::with_artifact(artifact, confidence)
             }
@@ -104,7 +136,7 @@ pub fn verify_hypothesis(graph: &Graph, query: Query) -> GroundedResult {
         QueryType::Intersect(ref nodes) => match graph.intersect(nodes) {
             Ok(common) if !common.is_empty() => {
                 let artifact = Artifact::with_path(common);
-                let confidence = compute_confidence(&artifact, graph);
+                let confidence = strategy.score(&artifact, graph);
                 GroundedResult::with_artifact(artifact, confidence)
             }
             _ => GroundedResult::unverified(),
@@ -112,7 +144,7 @@ pub fn verify_hypothesis(graph: &Graph, query: Query) -> GroundedResult {
 
         QueryType::TraverseDfs { start, depth } => {
             if let Some(artifact) = graph.traverse_dfs(start, depth) {
-                let confidence = compute_confidence(&artifact, graph);
+                let confidence = strategy.score(&artifact, graph);
                 GroundedResult::with_artifact(artifact, confidence)
             } else {
                 GroundedResult::unverified()
@@ -121,6 +153,108 @@ pub fn verify_hypothesis(graph: &Graph, query: Query) -> GroundedResult {
     }
 }
 
+/// Verify each query in order with [`verify_hypothesis`].
+#[must_use]
+pub fn verify_hypotheses(graph: &Graph, queries: Vec<Query>) -> Vec<GroundedResult> {
+    queries
+        .into_iter()
+        .map(|query| verify_hypothesis(graph, query))
+        .collect()
+}
+
+/// Combine several results into one score for a compound claim.
+///
+/// The score is the integer mean of the individual scores (unverified
+/// results count as 0), `evidence_count` is the sum, and `path_length` is
+/// the number of distinct nodes across all evidence paths. Use
+/// [`aggregate_evidence`] for the nodes themselves. Zero for no results.
+#[must_use]
+pub fn aggregate_confidence(results: &[GroundedResult]) -> ConfidenceScore {
+    if results.is_empty() {
+        return ConfidenceScore::zero();
+    }
+    let total: usize = results
+        .iter()
+        .map(|result| usize::from(result.confidence.score))
+        .sum();
+    let mean = u8::try_from(total / results.len()).unwrap_or(100);
+    let evidence_count = results.iter().fold(0usize, |acc, result| {
+        acc.saturating_add(result.confidence.evidence_count)
+    });
+    ConfidenceScore::new(mean, evidence_count, aggregate_evidence(results).len())
+}
+
+/// Union of the evidence paths of `results`, sorted by `NodeId`.
+#[must_use]
+pub fn aggregate_evidence(results: &[GroundedResult]) -> Vec<NodeId> {
+    results
+        .iter()
+        .flat_map(|result| result.evidence_path.iter().copied())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Rank nodes reachable from `start` by the confidence of their best path.
+///
+/// Expands outward up to `depth` hops, keeping for each reached node the
+/// path with the highest `compute_path_confidence` score (shorter path on
+/// ties). Returns `(node, confidence)` pairs sorted by descending score,
+/// then ascending `NodeId`. The start node itself is not included.
+#[must_use]
+pub fn ranked_context(
+    graph: &Graph,
+    start: NodeId,
+    depth: usize,
+) -> Vec<(NodeId, ConfidenceScore)> {
+    if !graph.contains_node_internal(start) {
+        return Vec::new();
+    }
+
+    // Node -> (best confidence, path that achieved it)
+    let mut best: BTreeMap<NodeId, (ConfidenceScore, Vec<NodeId>)> = BTreeMap::new();
+    best.insert(start, (ConfidenceScore::max(), vec![start]));
+    let mut frontier: BTreeSet<NodeId> = BTreeSet::from([start]);
+
+    for _ in 0..depth {
+        let mut next = BTreeSet::new();
+        for node in frontier {
+            let Some((_, path)) = best.get(&node).cloned() else {
+                continue;
+            };
+            for (neighbor, _) in graph.neighbors_internal(node) {
+                if path.contains(&neighbor) {
+                    continue;
+                }
+                let mut candidate = path.clone();
+                candidate.push(neighbor);
+                let confidence = compute_path_confidence(&candidate, graph);
+
+                let improves = best.get(&neighbor).is_none_or(|(current, current_path)| {
+                    (confidence.score, std::cmp::Reverse(candidate.len()))
+                        > (current.score, std::cmp::Reverse(current_path.len()))
+                });
+                if improves {
+                    best.insert(neighbor, (confidence, candidate));
+                    next.insert(neighbor);
+                }
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+        frontier = next;
+    }
+
+    best.remove(&start);
+    let mut ranked: Vec<(NodeId, ConfidenceScore)> = best
+        .into_iter()
+        .map(|(node, (confidence, _))| (node, confidence))
+        .collect();
+    ranked.sort_by(|a, b| b.1.score.cmp(&a.1.score).then(a.0.cmp(&b.0)));
+    ranked
+}
+
 // =============================================================================
 // TESTS
 // =============================================================================
@@ -169,6 +303,50 @@ mod tests {
         assert!(result.confidence.score >= 50);
     }
 
+    #[test]
+    fn threshold_decides_verification() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+        graph.insert_edge(a, b, EdgeWeight::new(5)).expect("insert");
+
+        let default = verify_hypothesis(&graph, Query::traverse(a, 1));
+        let score = default.confidence.score;
+        assert!((50..80).contains(&score), "score {score}");
+
+        let strict = verify_hypothesis_with_threshold(&graph, Query::traverse(a, 1), 80);
+        let lenient = verify_hypothesis_with_threshold(&graph, Query::traverse(a, 1), 50);
+        assert!(!strict.verified);
+        assert!(lenient.verified);
+        assert_eq!(strict.confidence, lenient.confidence);
+
+        let missing = verify_hypothesis_with_threshold(&graph, Query::lookup(EntityId(9)), 0);
+        assert!(!missing.verified);
+    }
+
+    #[test]
+    fn aggregate_lands_between_verified_and_unverified() {
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+        graph.insert_edge(a, b, EdgeWeight::new(5)).expect("insert");
+
+        let results = verify_hypotheses(
+            &graph,
+            vec![Query::traverse(a, 1), Query::lookup(EntityId(9))],
+        );
+        assert_eq!(results.len(), 2);
+        assert!(results[0].artifact.is_some());
+        assert!(!results[1].verified);
+
+        let aggregate = aggregate_confidence(&results);
+        assert!(aggregate.score > results[1].confidence.score);
+        assert!(aggregate.score < results[0].confidence.score);
+        assert_eq!(aggregate_evidence(&results), vec![a, b]);
+        assert_eq!(aggregate.path_length, 2);
+        assert_eq!(aggregate_confidence(&[]), ConfidenceScore::zero());
+    }
+
     #[test]
     fn verify_strongest_path() {
         let mut graph = Graph::new();
@@ -210,4 +388,52 @@ mod tests {
         let path = result.artifact.as_ref().map(|a| &a.path);
         assert_eq!(path, Some(&vec![common]));
     }
+
+    #[test]
+    fn ranked_context_orders_by_confidence() {
+        let mut graph = Graph::new();
+        let start = graph.insert_node(EntityId(1)).expect("insert");
+        let weak = graph.insert_node(EntityId(2)).expect("insert");
+        let strong = graph.insert_node(EntityId(3)).expect("insert");
+        graph
+            .insert_edge(start, weak, EdgeWeight::new(1))
+            .expect("insert");
+        graph
+            .insert_edge(start, strong, EdgeWeight::new(9))
+            .expect("insert");
+
+        let ranked = ranked_context(&graph, start, 2);
+        let order: Vec<NodeId> = ranked.iter().map(|(node, _)| *node).collect();
+        assert_eq!(order, vec![strong, weak]);
+        assert!(ranked[0].1.score > ranked[1].1.score);
+    }
+
+    #[test]
+    fn ranked_context_missing_start_is_empty() {
+        let graph = Graph::new();
+        assert!(ranked_context(&graph, NodeId(7), 3).is_empty());
+    }
+
+    #[test]
+    fn custom_strategy_overrides_default_scoring() {
+        struct AlwaysCertain;
+        impl ConfidenceStrategy for AlwaysCertain {
+            fn score(&self, artifact: &Artifact, _graph: &Graph) -> ConfidenceScore {
+                ConfidenceScore::new(100, 0, artifact.path.len())
+            }
+        }
+
+        let mut graph = Graph::new();
+        let a = graph.insert_node(EntityId(1)).expect("insert");
+        let b = graph.insert_node(EntityId(2)).expect("insert");
+        graph.insert_edge(a, b, EdgeWeight::new(1)).expect("insert");
+
+        let default = verify_hypothesis(&graph, Query::traverse(a, 1));
+        let custom = verify_hypothesis_with(&graph, Query::traverse(a, 1), &AlwaysCertain);
+
+        assert!(default.confidence.score < 100);
+        assert_eq!(custom.confidence.score, 100);
+        assert!(custom.verified);
+        assert_eq!(custom.evidence_path, default.evidence_path);
+    }
 }
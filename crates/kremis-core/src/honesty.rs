@@ -0,0 +1,342 @@
+//! # Honesty Module
+//!
+//! Structured answers that keep what the graph proves apart from what is
+//! guessed or simply not known.
+//!
+//! - **Facts**: directly backed by edges in the graph.
+//! - **Inferences**: derived claims, carrying a confidence score.
+//! - **Unknowns**: questions the graph cannot answer.
+//!
+//! Every fact and inference names the nodes that support it, so a consumer
+//! can always trace a claim back to the graph.
+
+use crate::NodeId;
+use crate::confidence::VERIFIED_THRESHOLD;
+use serde::{Deserialize, Serialize};
+
+/// Confidence below which an inference is considered low confidence.
+pub const LOW_CONFIDENCE_THRESHOLD: u8 = 40;
+
+/// A statement directly backed by the graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fact {
+    /// Human-readable statement.
+    pub statement: String,
+    /// Nodes supporting the statement.
+    pub evidence_path: Vec<NodeId>,
+}
+
+impl Fact {
+    /// Create a fact supported by `evidence_path`.
+    #[must_use]
+    pub fn new(statement: impl Into<String>, evidence_path: Vec<NodeId>) -> Self {
+        Self {
+            statement: statement.into(),
+            evidence_path,
+        }
+    }
+}
+
+/// A derived statement with a confidence score (0-100).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Inference {
+    /// Human-readable statement.
+    pub statement: String,
+    /// Confidence score (0-100).
+    pub confidence: u8,
+    /// Nodes supporting the statement.
+    pub evidence_path: Vec<NodeId>,
+}
+
+impl Inference {
+    /// Create an inference. Confidence is clamped to 100.
+    #[must_use]
+    pub fn new(statement: impl Into<String>, confidence: u8, evidence_path: Vec<NodeId>) -> Self {
+        Self {
+            statement: statement.into(),
+            confidence: confidence.min(100),
+            evidence_path,
+        }
+    }
+
+    /// Check if the confidence reaches the verified threshold.
+    #[must_use]
+    pub fn is_high_confidence(&self) -> bool {
+        self.confidence >= VERIFIED_THRESHOLD
+    }
+
+    /// Check if the confidence is below [`LOW_CONFIDENCE_THRESHOLD`].
+    #[must_use]
+    pub fn is_low_confidence(&self) -> bool {
+        self.confidence < LOW_CONFIDENCE_THRESHOLD
+    }
+}
+
+/// Something the graph cannot answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Unknown {
+    /// Human-readable statement.
+    pub statement: String,
+}
+
+impl Unknown {
+    /// Create an unknown.
+    #[must_use]
+    pub fn new(statement: impl Into<String>) -> Self {
+        Self {
+            statement: statement.into(),
+        }
+    }
+}
+
+/// An answer split into facts, inferences, and unknowns.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HonestResponse {
+    /// Statements backed by the graph.
+    pub facts: Vec<Fact>,
+    /// Derived statements with confidence.
+    pub inferences: Vec<Inference>,
+    /// Questions left unanswered.
+    pub unknowns: Vec<Unknown>,
+}
+
+impl HonestResponse {
+    /// Create an empty response.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a fact.
+    #[must_use]
+    pub fn with_fact(mut self, fact: Fact) -> Self {
+        self.facts.push(fact);
+        self
+    }
+
+    /// Add an inference.
+    #[must_use]
+    pub fn with_inference(mut self, inference: Inference) -> Self {
+        self.inferences.push(inference);
+        self
+    }
+
+    /// Add an unknown.
+    #[must_use]
+    pub fn with_unknown(mut self, unknown: Unknown) -> Self {
+        self.unknowns.push(unknown);
+        self
+    }
+
+    /// Check if the response says nothing at all.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.facts.is_empty() && self.inferences.is_empty() && self.unknowns.is_empty()
+    }
+
+    /// Append everything from `other`, keeping duplicates.
+    pub fn merge(&mut self, other: HonestResponse) {
+        self.facts.extend(other.facts);
+        self.inferences.extend(other.inferences);
+        self.unknowns.extend(other.unknowns);
+    }
+
+    /// Append everything from `other`, then drop exact duplicates in all
+    /// three categories, keeping the first occurrence.
+    pub fn merge_dedup(&mut self, other: HonestResponse) {
+        self.merge(other);
+        dedup_in_order(&mut self.facts);
+        dedup_in_order(&mut self.inferences);
+        dedup_in_order(&mut self.unknowns);
+    }
+
+    /// Inferences at or above the verified threshold, in order.
+    #[must_use]
+    pub fn high_confidence_inferences(&self) -> Vec<&Inference> {
+        self.inferences
+            .iter()
+            .filter(|inference| inference.is_high_confidence())
+            .collect()
+    }
+
+    /// Copy of this response keeping only inferences with confidence of at
+    /// least `min`. Facts and unknowns are kept as-is.
+    #[must_use]
+    pub fn filter_by_confidence(&self, min: u8) -> HonestResponse {
+        HonestResponse {
+            facts: self.facts.clone(),
+            inferences: self
+                .inferences
+                .iter()
+                .filter(|inference| inference.confidence >= min)
+                .cloned()
+                .collect(),
+            unknowns: self.unknowns.clone(),
+        }
+    }
+
+    /// Sort inferences by descending confidence, then by statement.
+    pub fn sort_inferences_by_confidence(&mut self) {
+        self.inferences.sort_by(|a, b| {
+            b.confidence
+                .cmp(&a.confidence)
+                .then_with(|| a.statement.cmp(&b.statement))
+        });
+    }
+
+    /// Serialize to compact JSON.
+    ///
+    /// Evidence paths are arrays of plain `u64` node IDs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Serialize to indented JSON, for display.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json_pretty(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+impl FromIterator<HonestResponse> for HonestResponse {
+    /// Concatenate responses in order, as repeated [`HonestResponse::merge`].
+    fn from_iter<I: IntoIterator<Item = HonestResponse>>(iter: I) -> Self {
+        let mut merged = HonestResponse::new();
+        for response in iter {
+            merged.merge(response);
+        }
+        merged
+    }
+}
+
+/// Remove later copies of equal items, preserving order.
+fn dedup_in_order<T: PartialEq>(items: &mut Vec<T>) {
+    let mut kept: Vec<T> = Vec::with_capacity(items.len());
+    for item in items.drain(..) {
+        if !kept.contains(&item) {
+            kept.push(item);
+        }
+    }
+    *items = kept;
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inference_confidence_bands() {
+        let high = Inference::new("a", 90, vec![]);
+        let mid = Inference::new("b", 50, vec![]);
+        let low = Inference::new("c", 10, vec![]);
+
+        assert!(high.is_high_confidence() && !high.is_low_confidence());
+        assert!(!mid.is_high_confidence() && !mid.is_low_confidence());
+        assert!(low.is_low_confidence() && !low.is_high_confidence());
+        assert_eq!(Inference::new("d", 250, vec![]).confidence, 100);
+    }
+
+    #[test]
+    fn response_roundtrips_through_json() {
+        let response = HonestResponse::new()
+            .with_fact(Fact::new("1 -> 2", vec![NodeId(1), NodeId(2)]))
+            .with_inference(Inference::new("1 ~ 3", 60, vec![NodeId(1), NodeId(3)]))
+            .with_unknown(Unknown::new("4 unreachable"));
+
+        let json = serde_json::to_value(&response).expect("serialize");
+        assert_eq!(json["facts"][0]["evidence_path"], serde_json::json!([1, 2]));
+
+        let back: HonestResponse = serde_json::from_value(json).expect("deserialize");
+        assert_eq!(back, response);
+        assert!(!back.is_empty());
+        assert!(HonestResponse::new().is_empty());
+    }
+
+    #[test]
+    fn to_json_roundtrips_with_plain_node_ids() {
+        let response = HonestResponse::new()
+            .with_fact(Fact::new("1 -> 2", vec![NodeId(1), NodeId(2)]))
+            .with_inference(Inference::new("2 ~ 5", 75, vec![NodeId(2), NodeId(5)]))
+            .with_unknown(Unknown::new("7 unreachable"));
+
+        let json = response.to_json().expect("to_json");
+        assert!(json.contains(r#""evidence_path":[1,2]"#));
+        let back: HonestResponse = serde_json::from_str(&json).expect("from_str");
+        assert_eq!(back, response);
+
+        let pretty = response.to_json_pretty().expect("to_json_pretty");
+        assert!(pretty.contains('\n'));
+        let back: HonestResponse = serde_json::from_str(&pretty).expect("from_str");
+        assert_eq!(back, response);
+    }
+
+    #[test]
+    fn filter_and_sort_inferences_by_confidence() {
+        let mut response = HonestResponse::new()
+            .with_fact(Fact::new("1 -> 2", vec![NodeId(1), NodeId(2)]))
+            .with_inference(Inference::new("weak", 30, vec![NodeId(3)]))
+            .with_inference(Inference::new("b strong", 90, vec![NodeId(4)]))
+            .with_inference(Inference::new("a strong", 90, vec![NodeId(5)]))
+            .with_inference(Inference::new("medium", 70, vec![NodeId(6)]))
+            .with_unknown(Unknown::new("7 unreachable"));
+
+        let filtered = response.filter_by_confidence(70);
+        assert_eq!(filtered.inferences.len(), 3);
+        assert!(filtered.inferences.iter().all(|i| i.statement != "weak"));
+        assert_eq!(filtered.facts, response.facts);
+        assert_eq!(filtered.unknowns, response.unknowns);
+
+        let high: Vec<&str> = response
+            .high_confidence_inferences()
+            .iter()
+            .map(|i| i.statement.as_str())
+            .collect();
+        assert_eq!(high, vec!["b strong", "a strong", "medium"]);
+
+        response.sort_inferences_by_confidence();
+        let order: Vec<&str> = response
+            .inferences
+            .iter()
+            .map(|i| i.statement.as_str())
+            .collect();
+        assert_eq!(order, vec!["a strong", "b strong", "medium", "weak"]);
+    }
+
+    #[test]
+    fn merge_keeps_duplicates_and_merge_dedup_collapses_them() {
+        let shared = Fact::new("1 -> 2", vec![NodeId(1), NodeId(2)]);
+        let first = HonestResponse::new()
+            .with_fact(shared.clone())
+            .with_unknown(Unknown::new("3 unreachable"));
+        let second = HonestResponse::new()
+            .with_fact(shared.clone())
+            .with_fact(Fact::new("1 -> 2", vec![NodeId(1), NodeId(4), NodeId(2)]))
+            .with_inference(Inference::new("2 ~ 5", 60, vec![NodeId(2), NodeId(5)]));
+
+        let mut plain = first.clone();
+        plain.merge(second.clone());
+        assert_eq!(plain.facts.len(), 3);
+        assert_eq!(plain.facts[0], plain.facts[1]);
+
+        let mut deduped = first.clone();
+        deduped.merge_dedup(second.clone());
+        assert_eq!(deduped.facts.len(), 2);
+        assert_eq!(deduped.facts[0], shared);
+        assert_eq!(deduped.inferences.len(), 1);
+        assert_eq!(deduped.unknowns.len(), 1);
+
+        let collected: HonestResponse = vec![first, second].into_iter().collect();
+        assert_eq!(collected, plain);
+    }
+}
@@ -11,7 +11,7 @@ use crate::graph::GraphStore;
 use crate::primitives::{
     ASSOCIATION_WINDOW, MAX_ATTRIBUTE_LENGTH, MAX_SEQUENCE_LENGTH, MAX_VALUE_LENGTH,
 };
-use crate::{KremisError, NodeId, Signal};
+use crate::{EdgeWeight, KremisError, NodeId, Signal};
 
 /// The Ingestor handles signal validation and graph ingestion.
 ///
@@ -83,7 +83,8 @@ impl Ingestor {
     ///
     /// Works with both in-memory Graph and persistent RedbGraph.
     /// Edges are formed between adjacent signals
-    /// within the ASSOCIATION_WINDOW (= 1).
+    /// within the ASSOCIATION_WINDOW (= 1), each labeled with the attribute
+    /// of the signal it leads into (the latest ingest wins).
     ///
     /// Returns the list of NodeIds created/updated.
     ///
@@ -94,6 +95,19 @@ impl Ingestor {
     pub fn ingest_sequence<G: GraphStore>(
         graph: &mut G,
         signals: &[Signal],
+    ) -> Result<Vec<NodeId>, KremisError> {
+        Self::ingest_sequence_weighted(graph, signals, |_| 1)
+    }
+
+    /// Ingest a sequence, reinforcing each edge by a per-signal amount.
+    ///
+    /// The edge into a signal's node grows by `reinforcement(signal)`
+    /// (saturating) instead of 1. Amounts of zero or below leave the edge
+    /// untouched. Otherwise identical to [`Ingestor::ingest_sequence`].
+    pub fn ingest_sequence_weighted<G: GraphStore>(
+        graph: &mut G,
+        signals: &[Signal],
+        reinforcement: impl Fn(&Signal) -> i64,
     ) -> Result<Vec<NodeId>, KremisError> {
         if signals.is_empty() {
             return Ok(Vec::new());
@@ -116,10 +130,19 @@ impl Ingestor {
             let current_node = Self::ingest_signal(graph, current_signal)?;
             node_ids.push(current_node);
 
-            // Create edges from all previous signals in window to current
+            // Create edges from all previous signals in window to current,
+            // labeled with the attribute of the signal they lead into
+            let amount = reinforcement(current_signal);
             for prev_signal in window.iter().take(window.len() - 1) {
                 if let Some(prev_node) = graph.get_node_by_entity(prev_signal.entity) {
-                    graph.increment_edge(prev_node, current_node)?;
+                    Self::reinforce(graph, prev_node, current_node, amount)?;
+                    if amount > 0 {
+                        graph.set_edge_label(
+                            prev_node,
+                            current_node,
+                            current_signal.attribute.clone(),
+                        )?;
+                    }
                 }
             }
         }
@@ -127,6 +150,23 @@ impl Ingestor {
         Ok(node_ids)
     }
 
+    /// Grow the edge `from -> to` by `amount`, creating it if needed.
+    pub(crate) fn reinforce<G: GraphStore>(
+        graph: &mut G,
+        from: NodeId,
+        to: NodeId,
+        amount: i64,
+    ) -> Result<(), KremisError> {
+        match amount {
+            ..=0 => Ok(()),
+            1 => graph.increment_edge(from, to),
+            _ => {
+                let current = graph.get_edge(from, to)?.map_or(0, |w| w.value());
+                graph.insert_edge(from, to, EdgeWeight::new(current.saturating_add(amount)))
+            }
+        }
+    }
+
     /// Check if a signal would be a duplicate.
     ///
     /// A signal is a duplicate if:
@@ -170,6 +210,21 @@ mod tests {
         assert!(Ingestor::validate(&signal).is_ok());
     }
 
+    #[test]
+    fn namespaced_signals_map_to_distinct_nodes() {
+        let mut graph = Graph::new();
+        let a = Signal::with_namespace(1, EntityId(7), Attribute::new("name"), Value::new("A"))
+            .expect("in range");
+        let b = Signal::with_namespace(2, EntityId(7), Attribute::new("name"), Value::new("B"))
+            .expect("in range");
+
+        let node_a = Ingestor::ingest_signal(&mut graph, &a).expect("ingest");
+        let node_b = Ingestor::ingest_signal(&mut graph, &b).expect("ingest");
+
+        assert_ne!(node_a, node_b);
+        assert_eq!(graph.node_count().expect("count"), 2);
+    }
+
     #[test]
     fn ingest_signal_creates_node() {
         let mut graph = Graph::new();
@@ -181,6 +236,24 @@ mod tests {
         assert_eq!(graph.node_count().expect("count"), 1);
     }
 
+    #[test]
+    fn ingest_sequence_labels_edges_with_target_attribute() {
+        let mut graph = Graph::new();
+        let signals = vec![
+            make_signal(1, "name", "Alice"),
+            make_signal(2, "knows", "Bob"),
+        ];
+
+        let nodes = Ingestor::ingest_sequence(&mut graph, &signals).expect("ingest");
+
+        assert_eq!(
+            graph.edge_label(nodes[0], nodes[1]),
+            Some(&Attribute::new("knows"))
+        );
+        let triples: Vec<_> = graph.relationship_triples().collect();
+        assert_eq!(triples[0].1, Some(Attribute::new("knows")));
+    }
+
     #[test]
     fn ingest_sequence_creates_edges() {
         let mut graph = Graph::new();
@@ -199,6 +272,32 @@ mod tests {
         assert!(graph.get_edge(nodes[1], nodes[2]).expect("get").is_some());
     }
 
+    #[test]
+    fn weighted_reinforcement_logs_saturation() {
+        use crate::graph::GraphChange;
+
+        let mut graph = Graph::new();
+        let signals = vec![make_signal(1, "type", "word"), make_signal(2, "link", "x")];
+        let heavy = |_: &Signal| i64::MAX / 2 + 1;
+
+        let nodes =
+            Ingestor::ingest_sequence_weighted(&mut graph, &signals, heavy).expect("ingest");
+        assert!(graph.take_changes().is_empty());
+
+        Ingestor::ingest_sequence_weighted(&mut graph, &signals, heavy).expect("ingest");
+        assert_eq!(
+            graph.get_edge(nodes[0], nodes[1]).expect("get"),
+            Some(EdgeWeight::new(i64::MAX))
+        );
+        assert_eq!(
+            graph.take_changes(),
+            vec![GraphChange::WeightSaturated {
+                from: nodes[0],
+                to: nodes[1]
+            }]
+        );
+    }
+
     #[test]
     fn is_duplicate_detects_existing_entity() {
         let mut graph = Graph::new();
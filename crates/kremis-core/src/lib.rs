@@ -26,13 +26,17 @@
 // MODULES
 // =============================================================================
 
+pub mod cache;
+pub mod components;
 pub mod compositor;
 pub mod confidence;
 pub mod export;
 pub mod formats;
 pub mod graph;
 pub mod grounding;
+pub mod honesty;
 pub mod ingestor;
+pub mod macros;
 pub mod mutation;
 pub mod primitives;
 pub mod query;
@@ -54,18 +58,27 @@ pub use types::{
 // RE-EXPORTS: Graph Engine
 // =============================================================================
 
+pub use cache::LruCache;
+pub use components::ComponentTracker;
 pub use compositor::Compositor;
-pub use confidence::ConfidenceScore;
+pub use confidence::{ConfidenceConfig, ConfidenceScore, ConfidenceStrategy, DefaultConfidence};
 pub use export::{
-    CanonicalGraph, CanonicalHeader, canonical_checksum, export_canonical, import_canonical,
-    verify_canonical,
+    CanonicalGraph, CanonicalHeader, Manifest, canonical_checksum, export_canonical,
+    export_msgpack, export_stable_canonical, export_with_manifest, import_canonical,
+    import_canonical_with_progress, import_msgpack, verify_canonical,
 };
-pub use graph::{Graph, GraphStore, SerializableGraph};
-pub use grounding::{GroundedResult, verify_hypothesis};
+pub use graph::{
+    EdgeDirection, Graph, GraphChange, GraphDiff, GraphStore, PathComparison, RepairReport,
+    SerializableGraph, TraverseCost,
+};
+pub use grounding::{
+    GroundedResult, verify_hypothesis, verify_hypothesis_with, verify_hypothesis_with_threshold,
+};
+pub use honesty::{Fact, HonestResponse, Inference, Unknown};
 pub use ingestor::Ingestor;
 pub use mutation::MutationEngine;
 pub use query::{Query, QueryType};
-pub use session::{Session, StorageBackend};
+pub use session::{Session, SessionError, StorageBackend};
 pub use storage::RedbGraph;
 
 // =============================================================================
@@ -0,0 +1,103 @@
+//! # Signal Macros
+//!
+//! Declarative helpers for building `Vec<Signal>` batches in tests,
+//! examples, and benchmarks without repeating `Signal::new` boilerplate.
+//!
+//! - `signals!`: explicit `(entity, attribute, value)` tuples.
+//! - `signals_seq!`: a base entity id followed by `(attribute, value)` pairs;
+//!   entity ids are assigned sequentially starting from the base.
+
+/// Build a `Vec<Signal>` from explicit `(entity, attribute, value)` tuples.
+///
+/// ```
+/// use kremis_core::{EntityId, signals};
+///
+/// let batch = signals![(1, "name", "Alice"), (2, "name", "Bob")];
+/// assert_eq!(batch.len(), 2);
+/// assert_eq!(batch[1].entity, EntityId(2));
+/// ```
+#[macro_export]
+macro_rules! signals {
+    ($(($entity:expr, $attr:expr, $value:expr)),* $(,)?) => {
+        vec![$(
+            $crate::Signal::new(
+                $crate::EntityId($entity),
+                $crate::Attribute::new($attr),
+                $crate::Value::new($value),
+            )
+        ),*]
+    };
+}
+
+/// Build a `Vec<Signal>` with sequential entity ids.
+///
+/// The first pair receives entity `base`, the second `base + 1`, and so on.
+/// Ids use saturating arithmetic, consistent with the rest of the CORE.
+///
+/// ```
+/// use kremis_core::{EntityId, signals_seq};
+///
+/// let batch = signals_seq!(10; ("name", "Alice"), ("name", "Bob"));
+/// assert_eq!(batch[0].entity, EntityId(10));
+/// assert_eq!(batch[1].entity, EntityId(11));
+/// ```
+#[macro_export]
+macro_rules! signals_seq {
+    ($base:expr; $(($attr:expr, $value:expr)),* $(,)?) => {{
+        let base: u64 = $base;
+        let pairs: Vec<($crate::Attribute, $crate::Value)> =
+            vec![$(($crate::Attribute::new($attr), $crate::Value::new($value))),*];
+        pairs
+            .into_iter()
+            .zip(0u64..)
+            .map(|((attribute, value), offset)| {
+                $crate::Signal::new($crate::EntityId(base.saturating_add(offset)), attribute, value)
+            })
+            .collect::<Vec<$crate::Signal>>()
+    }};
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use crate::{Attribute, EntityId, Signal, Value};
+
+    #[test]
+    fn signals_builds_explicit_entities() {
+        let batch: Vec<Signal> = signals![(5, "a", "x"), (9, "b", "y")];
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch[0].entity, EntityId(5));
+        assert_eq!(batch[1].entity, EntityId(9));
+        assert_eq!(batch[1].attribute, Attribute::new("b"));
+    }
+
+    #[test]
+    fn signals_seq_increments_from_base() {
+        let batch = signals_seq!(100; ("name", "Alice"), ("name", "Bob"), ("role", "admin"));
+        assert_eq!(batch.len(), 3);
+        for (offset, signal) in batch.iter().enumerate() {
+            assert_eq!(signal.entity, EntityId(100 + offset as u64));
+        }
+        assert_eq!(batch[2].value, Value::new("admin"));
+    }
+
+    #[test]
+    fn signals_seq_empty_and_trailing_comma() {
+        let empty = signals_seq!(1;);
+        assert!(empty.is_empty());
+
+        let batch = signals_seq!(7; ("k", "v"),);
+        assert_eq!(batch.len(), 1);
+        assert_eq!(batch[0].entity, EntityId(7));
+    }
+
+    #[test]
+    fn signals_seq_saturates_at_max() {
+        let batch = signals_seq!(u64::MAX; ("a", "1"), ("b", "2"));
+        assert_eq!(batch[0].entity, EntityId(u64::MAX));
+        assert_eq!(batch[1].entity, EntityId(u64::MAX));
+    }
+}
@@ -27,7 +27,7 @@ pub const MAGIC_BYTES: &[u8; 4] = b"KREM";
 /// Current serialization format version.
 ///
 /// Increment this when making breaking changes to the serialization format.
-pub const FORMAT_VERSION: u8 = 1;
+pub const FORMAT_VERSION: u8 = 2;
 
 /// Default threshold for considering an edge "stable".
 ///
@@ -50,6 +50,12 @@ pub const MAX_TRAVERSAL_DEPTH: usize = 100;
 /// unbounded computation in strongest_path and similar queries.
 pub const MAX_PATH_LENGTH: usize = 1000;
 
+/// Maximum node count for eccentricity and diameter queries.
+///
+/// Diameter runs one breadth-first search per node (quadratic), so larger
+/// graphs are refused rather than computed.
+pub const MAX_DIAMETER_NODES: usize = 10_000;
+
 // =============================================================================
 // INPUT VALIDATION LIMITS
 // =============================================================================
@@ -76,6 +82,15 @@ pub const MAX_SEQUENCE_LENGTH: usize = 10000;
 /// Limits the computational cost of intersection queries.
 pub const MAX_INTERSECT_NODES: usize = 100;
 
+/// Bit offset of the namespace inside a namespaced `EntityId`.
+///
+/// Namespaced ids are `(namespace << 48) | (entity & ENTITY_ID_MASK)`,
+/// leaving 48 bits for each source's own id space.
+pub const ENTITY_NAMESPACE_SHIFT: u32 = 48;
+
+/// Mask selecting the raw entity bits of a namespaced `EntityId`.
+pub const ENTITY_ID_MASK: u64 = (1 << ENTITY_NAMESPACE_SHIFT) - 1;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -9,7 +9,9 @@
 use crate::{EdgeWeight, EntityId, NodeId};
 
 /// Query operation types supported by the CORE.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Ordered so queries can key deterministic caches.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum QueryType {
     /// Lookup a node by entity ID.
     Lookup(EntityId),
@@ -35,7 +37,7 @@ pub enum QueryType {
 }
 
 /// A structured query with optional timeout.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Query {
     /// The type of query operation.
     pub query_type: QueryType,
@@ -13,13 +13,72 @@
 //! - `InMemory`: Uses in-memory `Graph` (fast, volatile unless explicitly saved)
 //! - `Persistent`: Uses `RedbGraph` for disk-backed ACID storage
 
-use crate::graph::{Graph, GraphStore};
+use crate::cache::LruCache;
+use crate::graph::{
+    Graph, GraphStore, strongest_path_interruptible, top_neighbors, traverse_dfs_interruptible,
+    traverse_interruptible, traverse_streaming,
+};
 use crate::ingestor::Ingestor;
+use crate::primitives::MAX_SEQUENCE_LENGTH;
 use crate::storage::RedbGraph;
+use crate::system::{GraphMetrics, Stage, StageAssessor};
 use crate::{
     Artifact, Attribute, Buffer, EdgeWeight, EntityId, KremisError, NodeId, Signal, Value,
 };
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::Path;
+use std::sync::Mutex;
+use thiserror::Error;
+
+// =============================================================================
+// SESSION ERRORS
+// =============================================================================
+
+/// Errors from session ingestion and storage setup.
+///
+/// Separates bad input from storage failures so callers can react to each
+/// without parsing messages. Converts to and from [`KremisError`], so `?`
+/// works in either direction.
+#[derive(Debug, Error)]
+pub enum SessionError {
+    /// A signal failed validation (empty or oversized attribute or value).
+    #[error("Invalid signal format")]
+    InvalidSignal,
+
+    /// A sequence is longer than `MAX_SEQUENCE_LENGTH`.
+    #[error("Sequence of {len} signals exceeds maximum {max}")]
+    SequenceTooLong { len: usize, max: usize },
+
+    /// The storage backend could not be read or written.
+    #[error("I/O error: {0}")]
+    Io(String),
+
+    /// Any other graph error.
+    #[error(transparent)]
+    Graph(KremisError),
+}
+
+impl From<KremisError> for SessionError {
+    fn from(error: KremisError) -> Self {
+        match error {
+            KremisError::InvalidSignal => Self::InvalidSignal,
+            KremisError::IoError(message) => Self::Io(message),
+            other => Self::Graph(other),
+        }
+    }
+}
+
+impl From<SessionError> for KremisError {
+    fn from(error: SessionError) -> Self {
+        match error {
+            SessionError::InvalidSignal | SessionError::SequenceTooLong { .. } => {
+                Self::InvalidSignal
+            }
+            SessionError::Io(message) => Self::IoError(message),
+            SessionError::Graph(error) => error,
+        }
+    }
+}
 
 // =============================================================================
 // ERROR LOGGING HELPERS
@@ -75,6 +134,89 @@ fn log_and_default<T: Default>(result: Result<T, KremisError>, context: &str) ->
     }
 }
 
+// =============================================================================
+// SESSION HELPERS
+// =============================================================================
+
+/// Increment the edge for every ordered pair of distinct entities.
+fn reinforce_pairs<G: GraphStore>(
+    graph: &mut G,
+    entities: &BTreeSet<EntityId>,
+) -> Result<(), KremisError> {
+    let nodes = entities
+        .iter()
+        .map(|&entity| graph.insert_node(entity))
+        .collect::<Result<Vec<NodeId>, KremisError>>()?;
+    for &from in &nodes {
+        for &to in &nodes {
+            if from != to {
+                graph.increment_edge(from, to)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Add each positive weight onto its entity edge, creating nodes and edges
+/// as needed. Returns how many edges were applied.
+fn reinforce_weighted<G: GraphStore>(
+    graph: &mut G,
+    edges: &[(EntityId, EntityId, EdgeWeight)],
+) -> Result<usize, KremisError> {
+    let mut applied = 0usize;
+    for &(from, to, weight) in edges {
+        if weight.value() <= 0 {
+            continue;
+        }
+        let (a, b) = (graph.insert_node(from)?, graph.insert_node(to)?);
+        let updated = graph.get_edge(a, b)?.map_or(weight.value(), |current| {
+            current.value().saturating_add(weight.value())
+        });
+        graph.insert_edge(a, b, EdgeWeight::new(updated))?;
+        applied = applied.saturating_add(1);
+    }
+    Ok(applied)
+}
+
+/// Heaviest weight among the outgoing and incoming edges of `node`.
+fn strongest_touching<G: GraphStore>(
+    graph: &G,
+    node: NodeId,
+) -> Result<Option<EdgeWeight>, KremisError> {
+    let outgoing = graph.neighbors(node)?;
+    let incoming = graph.in_neighbors(node)?;
+    Ok(outgoing
+        .into_iter()
+        .chain(incoming)
+        .map(|(_, weight)| weight)
+        .max())
+}
+
+/// Lock the lookup cache, recovering from poisoning (the cache holds no
+/// invariants a panicked writer could break).
+fn lock_cache(
+    cache: &Mutex<LruCache<EntityId, NodeId>>,
+) -> std::sync::MutexGuard<'_, LruCache<EntityId, NodeId>> {
+    cache
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Callback registered with [`Session::on_stage_transition`].
+struct StageObserver {
+    /// Stage assessed when the callback last ran (or was registered).
+    last: Stage,
+    callback: Box<dyn FnMut(Stage, Stage) + Send>,
+}
+
+impl std::fmt::Debug for StageObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StageObserver")
+            .field("last", &self.last)
+            .finish_non_exhaustive()
+    }
+}
+
 /// Storage backend for a Session.
 ///
 /// Supports both in-memory and persistent storage.
@@ -111,6 +253,12 @@ pub struct Session {
     backend: StorageBackend,
     /// The volatile session buffer (active context).
     buffer: Buffer,
+    /// Optional Active Context cache for entity lookups.
+    lookup_cache: Option<Mutex<LruCache<EntityId, NodeId>>>,
+    /// Edge reinforcement per attribute at ingest (default 1).
+    attribute_weights: BTreeMap<Attribute, i64>,
+    /// Called when an ingest moves the graph to another stage.
+    stage_observer: Option<Mutex<StageObserver>>,
 }
 
 impl Session {
@@ -126,6 +274,9 @@ impl Session {
         Self {
             backend: StorageBackend::InMemory(graph),
             buffer: Buffer::new(),
+            lookup_cache: None,
+            attribute_weights: BTreeMap::new(),
+            stage_observer: None,
         }
     }
 
@@ -133,11 +284,14 @@ impl Session {
     ///
     /// Opens or creates a redb database at the given path.
     /// All changes are automatically persisted to disk.
-    pub fn with_redb(path: impl AsRef<Path>) -> Result<Self, KremisError> {
+    pub fn with_redb(path: impl AsRef<Path>) -> Result<Self, SessionError> {
         let redb = RedbGraph::open(path)?;
         Ok(Self {
             backend: StorageBackend::Persistent(redb),
             buffer: Buffer::new(),
+            lookup_cache: None,
+            attribute_weights: BTreeMap::new(),
+            stage_observer: None,
         })
     }
 
@@ -147,9 +301,46 @@ impl Session {
         Self {
             backend: StorageBackend::Persistent(redb),
             buffer: Buffer::new(),
+            lookup_cache: None,
+            attribute_weights: BTreeMap::new(),
+            stage_observer: None,
         }
     }
 
+    /// Cache up to `capacity` entity lookups in front of the backend.
+    ///
+    /// Hits skip the `entity_index` (or redb) read; misses are filled on
+    /// success. Removing a node through the session invalidates its entry.
+    #[must_use]
+    pub fn with_lookup_cache(mut self, capacity: usize) -> Self {
+        self.lookup_cache = Some(Mutex::new(LruCache::new(capacity)));
+        self
+    }
+
+    /// Check whether `entity` currently has a cached lookup.
+    #[must_use]
+    pub fn is_lookup_cached(&self, entity: EntityId) -> bool {
+        self.lookup_cache
+            .as_ref()
+            .is_some_and(|cache| lock_cache(cache).contains(&entity))
+    }
+
+    /// Nodes in the lookup cache with their hit counts, hottest first.
+    ///
+    /// Ties are broken by ascending `NodeId`. Empty without a lookup cache.
+    #[must_use]
+    pub fn hot_nodes(&self) -> Vec<(NodeId, u64)> {
+        let Some(cache) = &self.lookup_cache else {
+            return Vec::new();
+        };
+        let mut hot: Vec<(NodeId, u64)> = lock_cache(cache)
+            .iter_with_hits()
+            .map(|(_, &node, hits)| (node, hits))
+            .collect();
+        hot.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        hot
+    }
+
     /// Check if using persistent storage.
     #[must_use]
     pub fn is_persistent(&self) -> bool {
@@ -233,6 +424,12 @@ impl Session {
             StorageBackend::InMemory(g) => Some(Self {
                 backend: StorageBackend::InMemory(g.clone()),
                 buffer: self.buffer.clone(),
+                lookup_cache: self
+                    .lookup_cache
+                    .as_ref()
+                    .map(|cache| Mutex::new(LruCache::new(lock_cache(cache).capacity()))),
+                attribute_weights: self.attribute_weights.clone(),
+                stage_observer: None,
             }),
             StorageBackend::Persistent(_) => None,
         }
@@ -246,6 +443,10 @@ impl Session {
     /// No unsafe blocks in Core.
     #[must_use]
     pub fn graph_mut(&mut self) -> Option<&mut Graph> {
+        // Direct mutation may renumber or drop nodes behind the cache
+        if let Some(cache) = &self.lookup_cache {
+            lock_cache(cache).clear();
+        }
         match &mut self.backend {
             StorageBackend::InMemory(g) => Some(g),
             StorageBackend::Persistent(_) => None,
@@ -269,27 +470,119 @@ impl Session {
     // =========================================================================
 
     /// Ingest a signal and add its node to the active context.
-    pub fn ingest(&mut self, signal: &Signal) -> Result<NodeId, KremisError> {
+    pub fn ingest(&mut self, signal: &Signal) -> Result<NodeId, SessionError> {
         let node_id = match &mut self.backend {
             StorageBackend::InMemory(graph) => Ingestor::ingest_signal(graph, signal)?,
             StorageBackend::Persistent(redb) => Ingestor::ingest_signal(redb, signal)?,
         };
         self.buffer.activate(node_id);
+        self.notify_stage_transition();
         Ok(node_id)
     }
 
+    /// Ingest a signal that follows `previous` in a sequence.
+    ///
+    /// Like [`Session::ingest`], then reinforces `previous -> node` by the
+    /// signal's attribute weight, as [`Session::ingest_sequence`] does for
+    /// adjacent signals. Lets callers stream a sequence one signal at a time.
+    pub fn ingest_after(
+        &mut self,
+        previous: Option<NodeId>,
+        signal: &Signal,
+    ) -> Result<NodeId, SessionError> {
+        let amount = self.attribute_weight(&signal.attribute);
+        let node_id = match &mut self.backend {
+            StorageBackend::InMemory(graph) => {
+                let node = Ingestor::ingest_signal(graph, signal)?;
+                if let Some(prev) = previous {
+                    Ingestor::reinforce(graph, prev, node, amount)?;
+                }
+                node
+            }
+            StorageBackend::Persistent(redb) => {
+                let node = Ingestor::ingest_signal(redb, signal)?;
+                if let Some(prev) = previous {
+                    Ingestor::reinforce(redb, prev, node, amount)?;
+                }
+                node
+            }
+        };
+        self.buffer.activate(node_id);
+        self.notify_stage_transition();
+        Ok(node_id)
+    }
+
+    /// Call `f(old, new)` whenever an ingest changes the assessed stage.
+    ///
+    /// Checked after `ingest`, `ingest_sequence`, and `ingest_edges`, which
+    /// then also pay for a stage assessment. Replaces any previously
+    /// registered callback; clones made with `try_clone` do not inherit it.
+    pub fn on_stage_transition(&mut self, f: impl FnMut(Stage, Stage) + Send + 'static) {
+        let last = StageAssessor::new().assess_from_metrics(&GraphMetrics::from_session(self));
+        self.stage_observer = Some(Mutex::new(StageObserver {
+            last,
+            callback: Box::new(f),
+        }));
+    }
+
+    /// Reassess the stage and run the transition callback if it changed.
+    fn notify_stage_transition(&mut self) {
+        if self.stage_observer.is_none() {
+            return;
+        }
+        let current = StageAssessor::new().assess_from_metrics(&GraphMetrics::from_session(self));
+        if let Some(observer) = self.stage_observer.as_mut() {
+            let observer = observer
+                .get_mut()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            if observer.last != current {
+                let previous = std::mem::replace(&mut observer.last, current);
+                (observer.callback)(previous, current);
+            }
+        }
+    }
+
+    /// Reinforce edges by `weight` when ingesting signals with `attribute`.
+    ///
+    /// Unconfigured attributes reinforce by 1; a weight of zero or below
+    /// stores the signal without touching its edge.
+    pub fn set_attribute_weight(&mut self, attribute: &Attribute, weight: i64) {
+        self.attribute_weights.insert(attribute.clone(), weight);
+    }
+
+    /// Edge reinforcement for signals with `attribute` (1 unless configured).
+    #[must_use]
+    pub fn attribute_weight(&self, attribute: &Attribute) -> i64 {
+        self.attribute_weights.get(attribute).copied().unwrap_or(1)
+    }
+
     /// Ingest a sequence of signals.
     ///
-    /// Creates edges between adjacent signals per ASSOCIATION_WINDOW.
+    /// Creates edges between adjacent signals per ASSOCIATION_WINDOW. Each
+    /// edge is reinforced by the weight configured for the later signal's
+    /// attribute (see [`Session::set_attribute_weight`]).
     /// All resulting nodes are added to active context.
-    pub fn ingest_sequence(&mut self, signals: &[Signal]) -> Result<Vec<NodeId>, KremisError> {
+    pub fn ingest_sequence(&mut self, signals: &[Signal]) -> Result<Vec<NodeId>, SessionError> {
+        if signals.len() > MAX_SEQUENCE_LENGTH {
+            return Err(SessionError::SequenceTooLong {
+                len: signals.len(),
+                max: MAX_SEQUENCE_LENGTH,
+            });
+        }
+        let weights = &self.attribute_weights;
+        let reinforcement = |signal: &Signal| weights.get(&signal.attribute).copied().unwrap_or(1);
         let nodes = match &mut self.backend {
-            StorageBackend::InMemory(graph) => Ingestor::ingest_sequence(graph, signals)?,
-            StorageBackend::Persistent(redb) => redb.ingest_batch(signals)?,
+            StorageBackend::InMemory(graph) => {
+                Ingestor::ingest_sequence_weighted(graph, signals, reinforcement)?
+            }
+            StorageBackend::Persistent(redb) => {
+                redb.ingest_batch_weighted(signals, reinforcement)?
+            }
         };
         for &node in &nodes {
             self.buffer.activate(node);
         }
+        self.notify_stage_transition();
         Ok(nodes)
     }
 
@@ -303,6 +596,75 @@ impl Session {
         }
     }
 
+    /// Adjust an existing edge's weight by `delta`, removing it at zero.
+    ///
+    /// Returns the new weight (`EdgeWeight(0)` if removed).
+    pub fn adjust_edge(
+        &mut self,
+        from: NodeId,
+        to: NodeId,
+        delta: i64,
+    ) -> Result<EdgeWeight, KremisError> {
+        match &mut self.backend {
+            StorageBackend::InMemory(graph) => graph.adjust_edge(from, to, delta),
+            StorageBackend::Persistent(redb) => redb.adjust_edge(from, to, delta),
+        }
+    }
+
+    /// Set the weight of the edge between two entities, creating the
+    /// entity nodes and the edge if missing.
+    ///
+    /// Returns the `(from, to)` NodeIds.
+    pub fn set_entity_edge(
+        &mut self,
+        from: EntityId,
+        to: EntityId,
+        weight: EdgeWeight,
+    ) -> Result<(NodeId, NodeId), KremisError> {
+        match &mut self.backend {
+            StorageBackend::InMemory(graph) => {
+                let (a, b) = (graph.insert_node(from)?, graph.insert_node(to)?);
+                graph.insert_edge(a, b, weight)?;
+                Ok((a, b))
+            }
+            StorageBackend::Persistent(redb) => {
+                let (a, b) = (redb.insert_node(from)?, redb.insert_node(to)?);
+                redb.insert_edge(a, b, weight)?;
+                Ok((a, b))
+            }
+        }
+    }
+
+    /// Add edges between entities directly, without going through signals.
+    ///
+    /// Each weight is added to the existing edge (or starts a new one),
+    /// creating entity nodes as needed. Non-positive weights are skipped.
+    /// Returns how many edges were applied.
+    pub fn ingest_edges(
+        &mut self,
+        edges: &[(EntityId, EntityId, EdgeWeight)],
+    ) -> Result<usize, KremisError> {
+        let applied = match &mut self.backend {
+            StorageBackend::InMemory(graph) => reinforce_weighted(graph, edges),
+            StorageBackend::Persistent(redb) => reinforce_weighted(redb, edges),
+        }?;
+        self.notify_stage_transition();
+        Ok(applied)
+    }
+
+    /// Reinforce every directed edge among entities observed together.
+    ///
+    /// Each ordered pair of distinct entities gets its edge incremented by 1,
+    /// creating nodes and edges as needed. Duplicate entities count once and
+    /// self-pairs are skipped.
+    pub fn reinforce_clique(&mut self, entities: &[EntityId]) -> Result<(), KremisError> {
+        let unique: BTreeSet<EntityId> = entities.iter().copied().collect();
+        match &mut self.backend {
+            StorageBackend::InMemory(graph) => reinforce_pairs(graph, &unique),
+            StorageBackend::Persistent(redb) => reinforce_pairs(redb, &unique),
+        }
+    }
+
     // =========================================================================
     // COMPOSITION
     // =========================================================================
@@ -396,10 +758,38 @@ impl Session {
 
     /// Lookup a node by entity ID.
     pub fn lookup_entity(&self, entity: EntityId) -> Option<NodeId> {
-        match &self.backend {
+        if let Some(cache) = &self.lookup_cache
+            && let Some(node) = lock_cache(cache).get(&entity)
+        {
+            return Some(*node);
+        }
+        let node = match &self.backend {
             StorageBackend::InMemory(graph) => graph.get_node_by_entity(entity),
             StorageBackend::Persistent(redb) => redb.get_node_by_entity(entity),
+        }?;
+        if let Some(cache) = &self.lookup_cache {
+            lock_cache(cache).insert(entity, node);
         }
+        Some(node)
+    }
+
+    /// Remove a node and every edge touching it.
+    ///
+    /// Also deactivates the node and drops its cached lookup. Only the
+    /// in-memory backend supports removal; persistent sessions return `false`.
+    pub fn remove_node(&mut self, node: NodeId) -> bool {
+        let StorageBackend::InMemory(graph) = &mut self.backend else {
+            return false;
+        };
+        let entity = graph.lookup(node).ok().flatten().map(|n| n.entity);
+        if !graph.remove_node(node) {
+            return false;
+        }
+        self.buffer.deactivate(&node);
+        if let (Some(cache), Some(entity)) = (&self.lookup_cache, entity) {
+            lock_cache(cache).remove(&entity);
+        }
+        true
     }
 
     /// Get edge weight between two nodes.
@@ -411,6 +801,40 @@ impl Session {
         log_and_convert(result, "get_edge").flatten()
     }
 
+    /// Entity of a node, if the node exists.
+    #[must_use]
+    pub fn entity_of(&self, node: NodeId) -> Option<EntityId> {
+        let result = match &self.backend {
+            StorageBackend::InMemory(graph) => graph.lookup(node),
+            StorageBackend::Persistent(redb) => redb.lookup(node),
+        };
+        log_and_convert(result, "entity_of")
+            .flatten()
+            .map(|n| n.entity)
+    }
+
+    /// The `k` heaviest outgoing edges of `node`, heaviest first (ties by NodeId).
+    #[must_use]
+    pub fn top_neighbors(&self, node: NodeId, k: usize) -> Vec<(NodeId, EdgeWeight)> {
+        let result = match &self.backend {
+            StorageBackend::InMemory(graph) => top_neighbors(graph, node, k),
+            StorageBackend::Persistent(redb) => top_neighbors(redb, node, k),
+        };
+        log_and_default(result, "top_neighbors")
+    }
+
+    /// Weight of the heaviest edge into or out of `node`.
+    ///
+    /// `None` if the node has no edges.
+    #[must_use]
+    pub fn strongest_edge_weight(&self, node: NodeId) -> Option<EdgeWeight> {
+        let result = match &self.backend {
+            StorageBackend::InMemory(graph) => strongest_touching(graph, node),
+            StorageBackend::Persistent(redb) => strongest_touching(redb, node),
+        };
+        log_and_convert(result, "strongest_edge_weight").flatten()
+    }
+
     // =========================================================================
     // METRICS (for stage assessment)
     // =========================================================================
@@ -444,6 +868,16 @@ impl Session {
         log_and_convert(result, "traverse").flatten()
     }
 
+    /// Depth-first traversal from a node.
+    pub fn traverse_dfs(&self, start: NodeId, depth: usize) -> Option<Artifact> {
+        match &self.backend {
+            StorageBackend::InMemory(graph) => graph.traverse_dfs(start, depth),
+            StorageBackend::Persistent(redb) => {
+                log_and_convert(redb.traverse_dfs(start, depth), "traverse_dfs").flatten()
+            }
+        }
+    }
+
     /// Traverse with minimum weight filter.
     pub fn traverse_filtered(
         &self,
@@ -458,6 +892,92 @@ impl Session {
         log_and_convert(result, "traverse_filtered").flatten()
     }
 
+    /// Breadth-first traversal that stops once `should_stop` returns true.
+    ///
+    /// Returns the (possibly partial) artifact and whether it was interrupted.
+    /// See [`crate::graph::traverse_interruptible`].
+    pub fn traverse_interruptible(
+        &self,
+        start: NodeId,
+        depth: usize,
+        min_weight: EdgeWeight,
+        should_stop: impl FnMut(usize) -> bool,
+    ) -> Option<(Artifact, bool)> {
+        let result = match &self.backend {
+            StorageBackend::InMemory(graph) => {
+                traverse_interruptible(graph, start, depth, min_weight, should_stop)
+            }
+            StorageBackend::Persistent(redb) => {
+                traverse_interruptible(redb, start, depth, min_weight, should_stop)
+            }
+        };
+        log_and_convert(result, "traverse_interruptible").flatten()
+    }
+
+    /// Depth-first traversal that stops once `should_stop` returns true.
+    ///
+    /// See [`crate::graph::traverse_dfs_interruptible`].
+    pub fn traverse_dfs_interruptible(
+        &self,
+        start: NodeId,
+        depth: usize,
+        should_stop: impl FnMut(usize) -> bool,
+    ) -> Option<(Artifact, bool)> {
+        let result = match &self.backend {
+            StorageBackend::InMemory(graph) => {
+                traverse_dfs_interruptible(graph, start, depth, should_stop)
+            }
+            StorageBackend::Persistent(redb) => {
+                traverse_dfs_interruptible(redb, start, depth, should_stop)
+            }
+        };
+        log_and_convert(result, "traverse_dfs_interruptible").flatten()
+    }
+
+    /// Strongest path search that stops once `should_stop` returns true.
+    ///
+    /// Returns the path (if found) and whether the search was interrupted.
+    /// See [`crate::graph::strongest_path_interruptible`].
+    pub fn strongest_path_interruptible(
+        &self,
+        start: NodeId,
+        end: NodeId,
+        should_stop: impl FnMut(usize) -> bool,
+    ) -> (Option<Vec<NodeId>>, bool) {
+        let result = match &self.backend {
+            StorageBackend::InMemory(graph) => {
+                strongest_path_interruptible(graph, start, end, should_stop)
+            }
+            StorageBackend::Persistent(redb) => {
+                strongest_path_interruptible(redb, start, end, should_stop)
+            }
+        };
+        log_and_default(result, "strongest_path_interruptible")
+    }
+
+    /// Breadth-first traversal handing each edge to `on_edge` as it is found.
+    ///
+    /// Returns `Ok(false)` if `start` does not exist. Storage errors are
+    /// returned rather than logged: edges already handed to `on_edge` cannot
+    /// be taken back, so the caller must report the failure itself.
+    /// See [`crate::graph::traverse_streaming`].
+    pub fn traverse_streaming(
+        &self,
+        start: NodeId,
+        depth: usize,
+        min_weight: EdgeWeight,
+        on_edge: impl FnMut(NodeId, NodeId, EdgeWeight) -> bool,
+    ) -> Result<bool, KremisError> {
+        match &self.backend {
+            StorageBackend::InMemory(graph) => {
+                traverse_streaming(graph, start, depth, min_weight, on_edge)
+            }
+            StorageBackend::Persistent(redb) => {
+                traverse_streaming(redb, start, depth, min_weight, on_edge)
+            }
+        }
+    }
+
     /// Find strongest path between two nodes.
     pub fn strongest_path(&self, start: NodeId, end: NodeId) -> Option<Vec<NodeId>> {
         let result = match &self.backend {
@@ -488,6 +1008,25 @@ impl Session {
         }
     }
 
+    /// Get the literal value an entity holds for `attribute`.
+    ///
+    /// Most recent value wins; see [`Graph::attribute_value`]. Returned by
+    /// value because the persistent backend has no borrowed storage.
+    pub fn attribute_value(&self, entity: EntityId, attribute: &Attribute) -> Option<Value> {
+        match &self.backend {
+            StorageBackend::InMemory(graph) => graph.attribute_value(entity, attribute).cloned(),
+            StorageBackend::Persistent(redb) => {
+                let node = redb.get_node_by_entity(entity)?;
+                let props = log_and_convert(redb.get_properties(node), "attribute_value")?;
+                props
+                    .into_iter()
+                    .rev()
+                    .find(|(attr, _)| attr == attribute)
+                    .map(|(_, value)| value)
+            }
+        }
+    }
+
     // =========================================================================
     // EXPORT SUPPORT (M3 FIX)
     // =========================================================================
@@ -538,6 +1077,46 @@ impl Session {
             }
         }
     }
+
+    /// Move an in-memory session onto a new redb database at `path`.
+    ///
+    /// Nodes are re-inserted in `NodeId` order, so ids stay the same when
+    /// they are contiguous; active nodes follow any renumbering. Edges and
+    /// properties are copied; edge labels, being in-memory only, are not.
+    /// The lookup cache is cleared. A persistent session is left unchanged.
+    pub fn spill_to_redb(&mut self, path: impl AsRef<Path>) -> Result<(), SessionError> {
+        let StorageBackend::InMemory(graph) = &self.backend else {
+            return Ok(());
+        };
+        let mut redb = RedbGraph::open(path)?;
+
+        let mut mapping = BTreeMap::new();
+        for node in graph.nodes() {
+            mapping.insert(node.id, redb.insert_node(node.entity)?);
+        }
+        for (from, to, weight) in graph.edges() {
+            if let (Some(&from), Some(&to)) = (mapping.get(&from), mapping.get(&to)) {
+                redb.insert_edge(from, to, weight)?;
+            }
+        }
+        for (&old, &new) in &mapping {
+            for (attribute, value) in graph.get_properties(old)? {
+                redb.store_property(new, attribute, value)?;
+            }
+        }
+
+        let active = std::mem::take(&mut self.buffer.active_nodes);
+        for node in active {
+            if let Some(&new) = mapping.get(&node) {
+                self.buffer.activate(new);
+            }
+        }
+        if let Some(cache) = &self.lookup_cache {
+            lock_cache(cache).clear();
+        }
+        self.backend = StorageBackend::Persistent(redb);
+        Ok(())
+    }
 }
 
 // =============================================================================
@@ -547,6 +1126,7 @@ impl Session {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::primitives::MAX_ATTRIBUTE_LENGTH;
     use crate::{Attribute, Value};
 
     fn make_signal(entity_id: u64, attr: &str, val: &str) -> Signal {
@@ -606,6 +1186,26 @@ mod tests {
         assert!(session.get_edge(nodes[0], nodes[1]).is_some());
     }
 
+    #[test]
+    fn ingest_after_matches_ingest_sequence() {
+        let signals = vec![
+            make_signal(1, "type", "word"),
+            make_signal(2, "type", "word"),
+            make_signal(1, "type", "word"),
+        ];
+        let mut sequence = Session::new();
+        sequence.ingest_sequence(&signals).expect("ingest");
+
+        let mut streamed = Session::new();
+        let mut previous = None;
+        for signal in &signals {
+            previous = Some(streamed.ingest_after(previous, signal).expect("ingest"));
+        }
+
+        let edges = |session: &Session| session.graph_opt().map(|g| g.edges().collect::<Vec<_>>());
+        assert_eq!(edges(&streamed), edges(&sequence));
+    }
+
     #[test]
     fn get_properties_via_session() {
         let mut session = Session::new();
@@ -639,4 +1239,311 @@ mod tests {
         let props2 = imported.get_properties(node2).expect("props");
         assert!(props2.contains(&(Attribute::new("role"), Value::new("admin"))));
     }
+
+    #[test]
+    fn set_entity_edge_creates_and_overwrites() {
+        let mut session = Session::new();
+        let (a, b) = session
+            .set_entity_edge(EntityId(1), EntityId(2), EdgeWeight::new(4))
+            .expect("set");
+        assert_eq!(session.get_edge(a, b), Some(EdgeWeight::new(4)));
+
+        session
+            .set_entity_edge(EntityId(1), EntityId(2), EdgeWeight::new(9))
+            .expect("set");
+        assert_eq!(session.get_edge(a, b), Some(EdgeWeight::new(9)));
+        assert_eq!(session.node_count(), 2);
+    }
+
+    #[test]
+    fn attribute_value_via_session() {
+        let mut session = Session::new();
+        session
+            .ingest(&make_signal(1, "name", "Alice"))
+            .expect("ingest");
+
+        assert_eq!(
+            session.attribute_value(EntityId(1), &Attribute::new("name")),
+            Some(Value::new("Alice"))
+        );
+        assert_eq!(
+            session.attribute_value(EntityId(1), &Attribute::new("role")),
+            None
+        );
+    }
+
+    #[test]
+    fn lookup_cache_serves_and_invalidates() {
+        let mut session = Session::new().with_lookup_cache(8);
+        let node = session
+            .ingest(&make_signal(1, "name", "Alice"))
+            .expect("ingest");
+        assert!(!session.is_lookup_cached(EntityId(1)));
+
+        assert_eq!(session.lookup_entity(EntityId(1)), Some(node));
+        assert!(session.is_lookup_cached(EntityId(1)));
+        assert_eq!(session.lookup_entity(EntityId(1)), Some(node));
+
+        // Misses are not cached
+        assert_eq!(session.lookup_entity(EntityId(2)), None);
+        assert!(!session.is_lookup_cached(EntityId(2)));
+
+        assert!(session.remove_node(node));
+        assert!(!session.is_lookup_cached(EntityId(1)));
+        assert_eq!(session.lookup_entity(EntityId(1)), None);
+        assert!(!session.is_active(&node));
+    }
+
+    #[test]
+    fn hot_nodes_reflect_skewed_lookups() {
+        let mut session = Session::new().with_lookup_cache(8);
+        let mut nodes = Vec::new();
+        for entity in 1..=3 {
+            nodes.push(
+                session
+                    .ingest(&make_signal(entity, "name", "x"))
+                    .expect("ingest"),
+            );
+        }
+        for (entity, lookups) in [(1, 2), (2, 6), (3, 4)] {
+            for _ in 0..lookups {
+                assert!(session.lookup_entity(EntityId(entity)).is_some());
+            }
+        }
+
+        // The first lookup of each entity is the miss that fills the cache
+        assert_eq!(
+            session.hot_nodes(),
+            vec![(nodes[1], 5), (nodes[2], 3), (nodes[0], 1)]
+        );
+        assert!(Session::new().hot_nodes().is_empty());
+    }
+
+    #[test]
+    fn reinforce_clique_increments_all_ordered_pairs() {
+        let mut session = Session::new();
+        let entities = [EntityId(1), EntityId(2), EntityId(3)];
+        session.reinforce_clique(&entities).expect("reinforce");
+        // Duplicates and repeated observations
+        session
+            .reinforce_clique(&[EntityId(1), EntityId(2), EntityId(3), EntityId(1)])
+            .expect("reinforce");
+
+        let nodes: Vec<NodeId> = entities
+            .iter()
+            .map(|&e| session.lookup_entity(e).expect("node"))
+            .collect();
+        for &from in &nodes {
+            for &to in &nodes {
+                let weight = session.get_edge(from, to);
+                if from == to {
+                    assert_eq!(weight, None);
+                } else {
+                    assert_eq!(weight, Some(EdgeWeight::new(2)));
+                }
+            }
+        }
+        assert_eq!(session.edge_count(), 6);
+    }
+
+    #[test]
+    fn attribute_weights_scale_edge_reinforcement() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        let redb = Session::with_redb(temp.path().join("weights.redb")).expect("open db");
+
+        for mut session in [Session::new(), redb] {
+            session.set_attribute_weight(&Attribute::new("owns"), 5);
+            session.set_attribute_weight(&Attribute::new("mentions"), 2);
+
+            let nodes = session
+                .ingest_sequence(&[
+                    make_signal(1, "name", "Alice"),
+                    make_signal(2, "owns", "car"),
+                    make_signal(3, "mentions", "Bob"),
+                    make_signal(4, "name", "Carol"),
+                ])
+                .expect("ingest");
+
+            assert_eq!(
+                session.get_edge(nodes[0], nodes[1]),
+                Some(EdgeWeight::new(5))
+            );
+            assert_eq!(
+                session.get_edge(nodes[1], nodes[2]),
+                Some(EdgeWeight::new(2))
+            );
+            // Unconfigured attributes keep the default of 1
+            assert_eq!(
+                session.get_edge(nodes[2], nodes[3]),
+                Some(EdgeWeight::new(1))
+            );
+        }
+    }
+
+    #[test]
+    fn top_neighbors_resolves_back_to_entities() {
+        let mut session = Session::new();
+        session
+            .ingest_sequence(&[make_signal(1, "a", "x"), make_signal(2, "a", "x")])
+            .expect("ingest");
+        let from = session.lookup_entity(EntityId(1)).expect("node");
+
+        let top = session.top_neighbors(from, 5);
+        assert_eq!(top.len(), 1);
+        assert_eq!(session.entity_of(top[0].0), Some(EntityId(2)));
+        assert_eq!(session.entity_of(NodeId(999)), None);
+    }
+
+    #[test]
+    fn spill_to_redb_moves_graph_to_persistent_backend() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let signals: Vec<Signal> = (1..=5).map(|i| make_signal(i, "name", "x")).collect();
+        let mut session = Session::new();
+        session.ingest_sequence(&signals).expect("ingest");
+        let before = session.export_graph_snapshot().expect("snapshot");
+
+        session
+            .spill_to_redb(dir.path().join("spill.redb"))
+            .expect("spill");
+
+        assert!(session.is_persistent());
+        assert_eq!(session.active_count(), 5);
+        let after = session.export_graph_snapshot().expect("snapshot");
+        assert_eq!(
+            after.edges().collect::<Vec<_>>(),
+            before.edges().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            session.attribute_value(EntityId(3), &Attribute::new("name")),
+            Some(Value::new("x"))
+        );
+    }
+
+    #[test]
+    fn ingest_edges_creates_and_reinforces() {
+        let mut session = Session::new();
+        let applied = session
+            .ingest_edges(&[
+                (EntityId(1), EntityId(2), EdgeWeight::new(3)),
+                (EntityId(2), EntityId(3), EdgeWeight::new(0)),
+                (EntityId(1), EntityId(2), EdgeWeight::new(4)),
+            ])
+            .expect("ingest edges");
+
+        assert_eq!(applied, 2);
+        let a = session.lookup_entity(EntityId(1)).expect("node");
+        let b = session.lookup_entity(EntityId(2)).expect("node");
+        assert_eq!(session.get_edge(a, b), Some(EdgeWeight::new(7)));
+        assert_eq!(session.lookup_entity(EntityId(3)), None);
+        assert_eq!(session.edge_count(), 1);
+    }
+
+    #[test]
+    fn weighted_ingest_paths_log_saturation() {
+        use crate::graph::GraphChange;
+
+        let mut session = Session::new();
+        let heavy = EdgeWeight::new(i64::MAX / 2 + 1);
+        let edges = [(EntityId(1), EntityId(2), heavy)];
+        session.ingest_edges(&edges).expect("ingest edges");
+        session.ingest_edges(&edges).expect("ingest edges");
+
+        let attr = Attribute::new("link");
+        session.set_attribute_weight(&attr, i64::MAX / 2 + 1);
+        let signals = [
+            Signal::new(EntityId(3), attr.clone(), Value::new("a")),
+            Signal::new(EntityId(4), attr, Value::new("b")),
+        ];
+        session.ingest_sequence(&signals).expect("ingest");
+        session.ingest_sequence(&signals).expect("ingest");
+
+        let node = |entity| session.lookup_entity(EntityId(entity)).expect("node");
+        let expected = vec![
+            GraphChange::WeightSaturated {
+                from: node(1),
+                to: node(2),
+            },
+            GraphChange::WeightSaturated {
+                from: node(3),
+                to: node(4),
+            },
+        ];
+        let graph = session.graph_mut().expect("in-memory");
+        assert_eq!(graph.take_changes(), expected);
+    }
+
+    #[test]
+    fn stage_transition_callback_fires_once_on_crossing() {
+        use crate::system::STABLE_THRESHOLD;
+        use std::sync::{Arc, Mutex};
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut session = Session::new();
+        let sink = Arc::clone(&seen);
+        session.on_stage_transition(move |old, new| {
+            sink.lock().expect("lock").push((old, new));
+        });
+
+        // 101 entities in a chain give 100 edges; each pass adds 1 to each.
+        let chain: Vec<Signal> = (0..=100).map(|i| make_signal(i, "a", "x")).collect();
+        for _ in 1..STABLE_THRESHOLD {
+            session.ingest_sequence(&chain).expect("ingest");
+        }
+        assert!(seen.lock().expect("lock").is_empty());
+
+        session.ingest_sequence(&chain).expect("ingest");
+        session.ingest(&make_signal(200, "a", "x")).expect("ingest");
+        assert_eq!(*seen.lock().expect("lock"), vec![(Stage::S0, Stage::S1)]);
+    }
+
+    #[test]
+    fn ingest_errors_are_typed() {
+        let mut session = Session::new();
+        let oversized = make_signal(1, &"a".repeat(MAX_ATTRIBUTE_LENGTH + 1), "v");
+        assert!(matches!(
+            session.ingest(&oversized),
+            Err(SessionError::InvalidSignal)
+        ));
+        assert!(matches!(
+            session.ingest_sequence(&[oversized]),
+            Err(SessionError::InvalidSignal)
+        ));
+
+        let long = vec![make_signal(1, "a", "v"); MAX_SEQUENCE_LENGTH + 1];
+        assert!(matches!(
+            session.ingest_sequence(&long),
+            Err(SessionError::SequenceTooLong { .. })
+        ));
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let missing = dir.path().join("no-such-dir").join("db.redb");
+        assert!(matches!(
+            Session::with_redb(missing),
+            Err(SessionError::Io(_))
+        ));
+    }
+    #[test]
+    fn attribute_usage_counts_entities_per_attribute() {
+        let mut session = Session::new();
+        for signal in [
+            make_signal(1, "name", "Alice"),
+            make_signal(1, "name", "Alicia"),
+            make_signal(1, "email", "alice@example.com"),
+            make_signal(2, "name", "Bob"),
+            make_signal(2, "role", "admin"),
+            make_signal(3, "name", "Carol"),
+            make_signal(3, "email", "carol@example.com"),
+        ] {
+            session.ingest(&signal).expect("ingest");
+        }
+
+        let graph = session.graph_opt().expect("in-memory graph");
+        let usage = graph.attribute_usage();
+        let usage: Vec<(&str, usize)> = usage
+            .iter()
+            .map(|(attr, count)| (attr.as_str(), *count))
+            .collect();
+        assert_eq!(usage, vec![("name", 3), ("email", 2), ("role", 1)]);
+        assert!(Graph::new().attribute_usage().is_empty());
+    }
 }
@@ -15,10 +15,11 @@
 //! storage backend for Kremis sessions. Unlike the in-memory `Graph`,
 //! `RedbGraph` persists data to disk automatically.
 
-use crate::graph::GraphStore;
+use crate::graph::{GraphStore, strongest_edge_cost, traverse_interruptible};
 use crate::{Artifact, Attribute, EdgeWeight, EntityId, KremisError, Node, NodeId, Signal, Value};
 use redb::{Database, ReadableDatabase, ReadableTable, ReadableTableMetadata, TableDefinition};
-use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap};
 use std::path::Path;
 
 /// Table for nodes: NodeId(u64) -> serialized Node bytes
@@ -179,6 +180,19 @@ impl RedbGraph {
     /// - The sequence exceeds `MAX_SEQUENCE_LENGTH`
     /// - Any signal is invalid (all signals are validated before the transaction opens)
     pub fn ingest_batch(&mut self, signals: &[Signal]) -> Result<Vec<NodeId>, KremisError> {
+        self.ingest_batch_weighted(signals, |_| 1)
+    }
+
+    /// Like [`RedbGraph::ingest_batch`], reinforcing each edge by a per-signal amount.
+    ///
+    /// Mirrors `Ingestor::ingest_sequence_weighted`: the edge into a signal's
+    /// node grows by `reinforcement(signal)`; amounts of zero or below leave
+    /// it untouched.
+    pub fn ingest_batch_weighted(
+        &mut self,
+        signals: &[Signal],
+        reinforcement: impl Fn(&Signal) -> i64,
+    ) -> Result<Vec<NodeId>, KremisError> {
         use crate::ingestor::Ingestor;
         use crate::primitives::{ASSOCIATION_WINDOW, MAX_SEQUENCE_LENGTH};
 
@@ -279,6 +293,10 @@ impl RedbGraph {
                     .copied()
                     .or_else(|| batch_entity_map.get(&current_signal.entity).copied())
                     .ok_or(KremisError::InvalidSignal)?;
+                let amount = reinforcement(current_signal);
+                if amount <= 0 {
+                    continue;
+                }
 
                 for prev_signal in window.iter().take(window.len() - 1) {
                     let prev_node = self
@@ -296,7 +314,7 @@ impl RedbGraph {
                     edges_table
                         .insert(
                             (prev_node.0, current_node.0),
-                            current_weight.saturating_add(1),
+                            current_weight.saturating_add(amount),
                         )
                         .map_err(|e| KremisError::IoError(e.to_string()))?;
                 }
@@ -321,6 +339,45 @@ impl RedbGraph {
         Ok(node_ids)
     }
 
+    /// Adjust the weight of an existing edge by `delta`.
+    ///
+    /// Mirrors `Graph::adjust_edge`: saturating, and the edge is removed
+    /// when the weight reaches zero or below.
+    pub fn adjust_edge(
+        &mut self,
+        from: NodeId,
+        to: NodeId,
+        delta: i64,
+    ) -> Result<EdgeWeight, KremisError> {
+        let current = self
+            .get_edge(from, to)?
+            .ok_or(KremisError::EdgeNotFound(from, to))?;
+        let updated = current.value().saturating_add(delta).max(0);
+
+        let write_txn = self
+            .db
+            .begin_write()
+            .map_err(|e| KremisError::IoError(e.to_string()))?;
+        {
+            let mut edges_table = write_txn
+                .open_table(EDGES)
+                .map_err(|e| KremisError::IoError(e.to_string()))?;
+            if updated > 0 {
+                edges_table
+                    .insert((from.0, to.0), updated)
+                    .map_err(|e| KremisError::IoError(e.to_string()))?;
+            } else {
+                edges_table
+                    .remove((from.0, to.0))
+                    .map_err(|e| KremisError::IoError(e.to_string()))?;
+            }
+        }
+        write_txn
+            .commit()
+            .map_err(|e| KremisError::IoError(e.to_string()))?;
+        Ok(EdgeWeight::new(updated))
+    }
+
     /// Get all edges in deterministic order.
     pub fn edges(&self) -> Result<Vec<(NodeId, NodeId, EdgeWeight)>, KremisError> {
         let read_txn = self
@@ -392,6 +449,70 @@ impl RedbGraph {
         }
         Ok(count)
     }
+
+    /// Depth-first traversal from a starting node.
+    ///
+    /// Mirrors `Graph::traverse_dfs` so both backends yield identical artifacts.
+    pub fn traverse_dfs(
+        &self,
+        start: NodeId,
+        depth: usize,
+    ) -> Result<Option<Artifact>, KremisError> {
+        let depth = depth.min(crate::primitives::MAX_TRAVERSAL_DEPTH);
+        if !self.contains_node(start)? {
+            return Ok(None);
+        }
+
+        let mut visited = BTreeSet::new();
+        let mut path = Vec::new();
+        let mut subgraph_edges = Vec::new();
+        self.dfs_recursive(
+            start,
+            0,
+            depth,
+            &mut visited,
+            &mut path,
+            &mut subgraph_edges,
+        )?;
+
+        Ok(Some(Artifact::with_subgraph(path, subgraph_edges)))
+    }
+
+    /// Recursive DFS helper.
+    fn dfs_recursive(
+        &self,
+        current: NodeId,
+        current_depth: usize,
+        max_depth: usize,
+        visited: &mut BTreeSet<NodeId>,
+        path: &mut Vec<NodeId>,
+        subgraph_edges: &mut Vec<(NodeId, NodeId, EdgeWeight)>,
+    ) -> Result<(), KremisError> {
+        if visited.contains(&current) || current_depth > max_depth {
+            return Ok(());
+        }
+
+        visited.insert(current);
+        path.push(current);
+
+        if current_depth < max_depth {
+            for (neighbor, weight) in self.neighbors(current)? {
+                subgraph_edges.push((current, neighbor, weight));
+
+                if !visited.contains(&neighbor) {
+                    self.dfs_recursive(
+                        neighbor,
+                        current_depth.saturating_add(1),
+                        max_depth,
+                        visited,
+                        path,
+                        subgraph_edges,
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 // =============================================================================
@@ -593,6 +714,30 @@ impl GraphStore for RedbGraph {
         Ok(neighbors)
     }
 
+    /// Scans the edges table (keyed by source), so cost is O(E).
+    fn in_neighbors(&self, node: NodeId) -> Result<Vec<(NodeId, EdgeWeight)>, KremisError> {
+        let read_txn = self
+            .db
+            .begin_read()
+            .map_err(|e| KremisError::IoError(e.to_string()))?;
+        let edges_table = read_txn
+            .open_table(EDGES)
+            .map_err(|e| KremisError::IoError(e.to_string()))?;
+
+        let mut sources = Vec::new();
+        for entry in edges_table
+            .iter()
+            .map_err(|e| KremisError::IoError(e.to_string()))?
+        {
+            let (key, value) = entry.map_err(|e| KremisError::IoError(e.to_string()))?;
+            let (from_id, to_id) = key.value();
+            if to_id == node.0 {
+                sources.push((NodeId(from_id), EdgeWeight::new(value.value())));
+            }
+        }
+        Ok(sources)
+    }
+
     fn contains_node(&self, id: NodeId) -> Result<bool, KremisError> {
         let read_txn = self
             .db
@@ -609,37 +754,7 @@ impl GraphStore for RedbGraph {
     }
 
     fn traverse(&self, start: NodeId, depth: usize) -> Result<Option<Artifact>, KremisError> {
-        let depth = depth.min(crate::primitives::MAX_TRAVERSAL_DEPTH);
-        if !self.contains_node(start)? {
-            return Ok(None);
-        }
-
-        let mut visited = BTreeSet::new();
-        let mut queue = VecDeque::new();
-        let mut path = Vec::new();
-        let mut subgraph_edges = Vec::new();
-
-        queue.push_back((start, 0usize));
-        visited.insert(start);
-
-        while let Some((current, current_depth)) = queue.pop_front() {
-            path.push(current);
-
-            if current_depth >= depth {
-                continue;
-            }
-
-            for (neighbor, weight) in self.neighbors(current)? {
-                subgraph_edges.push((current, neighbor, weight));
-
-                if !visited.contains(&neighbor) {
-                    visited.insert(neighbor);
-                    queue.push_back((neighbor, current_depth.saturating_add(1)));
-                }
-            }
-        }
-
-        Ok(Some(Artifact::with_subgraph(path, subgraph_edges)))
+        self.traverse_filtered(start, depth, EdgeWeight::new(i64::MIN))
     }
 
     fn traverse_filtered(
@@ -648,40 +763,10 @@ impl GraphStore for RedbGraph {
         depth: usize,
         min_weight: EdgeWeight,
     ) -> Result<Option<Artifact>, KremisError> {
-        let depth = depth.min(crate::primitives::MAX_TRAVERSAL_DEPTH);
-        if !self.contains_node(start)? {
-            return Ok(None);
-        }
-
-        let mut visited = BTreeSet::new();
-        let mut queue = VecDeque::new();
-        let mut path = Vec::new();
-        let mut subgraph_edges = Vec::new();
-
-        queue.push_back((start, 0usize));
-        visited.insert(start);
-
-        while let Some((current, current_depth)) = queue.pop_front() {
-            path.push(current);
-
-            if current_depth >= depth {
-                continue;
-            }
-
-            for (neighbor, weight) in self.neighbors(current)? {
-                // Filter by minimum weight
-                if weight.value() >= min_weight.value() {
-                    subgraph_edges.push((current, neighbor, weight));
-
-                    if !visited.contains(&neighbor) {
-                        visited.insert(neighbor);
-                        queue.push_back((neighbor, current_depth.saturating_add(1)));
-                    }
-                }
-            }
-        }
-
-        Ok(Some(Artifact::with_subgraph(path, subgraph_edges)))
+        Ok(
+            traverse_interruptible(self, start, depth, min_weight, |_| false)?
+                .map(|(artifact, _)| artifact),
+        )
     }
 
     fn intersect(&self, nodes: &[NodeId]) -> Result<Vec<NodeId>, KremisError> {
@@ -725,45 +810,36 @@ impl GraphStore for RedbGraph {
         }
 
         // Dijkstra with cost = i64::MAX - weight
-        let mut dist: BTreeMap<NodeId, i64> = BTreeMap::new();
+        let mut dist: BTreeMap<NodeId, u128> = BTreeMap::new();
         let mut prev: BTreeMap<NodeId, NodeId> = BTreeMap::new();
         let mut visited = BTreeSet::new();
 
         dist.insert(start, 0);
 
-        loop {
-            // Find unvisited node with minimum distance
-            let current = dist
-                .iter()
-                .filter(|(n, _)| !visited.contains(*n))
-                .min_by_key(|(_, d)| *d)
-                .map(|(n, _)| *n);
-
-            let Some(current) = current else {
-                break;
-            };
+        // Min-heap keyed by (distance, NodeId), matching Graph::strongest_path
+        let mut frontier = BinaryHeap::from([Reverse((0u128, start))]);
 
+        while let Some(Reverse((current_dist, current))) = frontier.pop() {
             if current == end {
                 break;
             }
 
-            visited.insert(current);
-            let current_dist = dist[&current];
+            // Stale entry: the node was already settled at a lower distance
+            if !visited.insert(current) {
+                continue;
+            }
 
             for (neighbor, weight) in self.neighbors(current)? {
                 if visited.contains(&neighbor) {
                     continue;
                 }
 
-                // Cost = i64::MAX - weight (higher weight = lower cost = preferred)
-                // Clamp negative weights to 0 to maintain Dijkstra invariant
-                let clamped_weight = weight.value().max(0);
-                let edge_cost = i64::MAX.saturating_sub(clamped_weight);
-                let new_dist = current_dist.saturating_add(edge_cost);
+                let new_dist = current_dist.saturating_add(strongest_edge_cost(weight));
 
                 if !dist.contains_key(&neighbor) || new_dist < dist[&neighbor] {
                     dist.insert(neighbor, new_dist);
                     prev.insert(neighbor, current);
+                    frontier.push(Reverse((new_dist, neighbor)));
                 }
             }
         }
@@ -899,6 +975,16 @@ impl GraphStore for RedbGraph {
 
         Ok(result)
     }
+
+    /// Edge labels are in-memory only; redb keeps none, so this is a no-op.
+    fn set_edge_label(
+        &mut self,
+        _from: NodeId,
+        _to: NodeId,
+        _label: Attribute,
+    ) -> Result<(), KremisError> {
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -979,6 +1065,29 @@ mod tests {
         assert_eq!(weight, Some(EdgeWeight::new(4)));
     }
 
+    #[test]
+    fn in_neighbors_sorted_by_source() {
+        let temp = tempdir().expect("temp dir");
+        let db_path = temp.path().join("test.redb");
+        let mut graph = RedbGraph::open(&db_path).expect("open db");
+
+        let a = graph.insert_node(EntityId(1)).expect("insert node");
+        let b = graph.insert_node(EntityId(2)).expect("insert node");
+        let c = graph.insert_node(EntityId(3)).expect("insert node");
+        graph
+            .insert_edge(c, b, EdgeWeight::new(2))
+            .expect("insert edge");
+        graph
+            .insert_edge(a, b, EdgeWeight::new(1))
+            .expect("insert edge");
+
+        assert_eq!(
+            graph.in_neighbors(b).expect("in_neighbors"),
+            vec![(a, EdgeWeight::new(1)), (c, EdgeWeight::new(2))]
+        );
+        assert!(graph.in_neighbors(a).expect("in_neighbors").is_empty());
+    }
+
     #[test]
     fn neighbors() {
         let temp = tempdir().expect("temp dir");
@@ -1872,4 +1981,50 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn adjust_edge_matches_in_memory_semantics() {
+        let temp = tempdir().expect("temp dir");
+        let mut graph = RedbGraph::open(temp.path().join("test.redb")).expect("open db");
+        let a = graph.insert_node(EntityId(1)).expect("insert node");
+        let b = graph.insert_node(EntityId(2)).expect("insert node");
+        graph
+            .insert_edge(a, b, EdgeWeight::new(2))
+            .expect("insert edge");
+
+        assert_eq!(
+            graph.adjust_edge(a, b, 3).expect("adjust"),
+            EdgeWeight::new(5)
+        );
+        assert_eq!(
+            graph.adjust_edge(a, b, -5).expect("adjust"),
+            EdgeWeight::new(0)
+        );
+        assert_eq!(graph.get_edge(a, b).expect("get"), None);
+        assert!(matches!(
+            graph.adjust_edge(a, b, 1),
+            Err(KremisError::EdgeNotFound(_, _))
+        ));
+    }
+
+    #[test]
+    fn intersect_is_sorted_regardless_of_input_order() {
+        let temp = tempdir().expect("temp dir");
+        let mut graph = RedbGraph::open(temp.path().join("test.redb")).expect("open db");
+        let a = graph.insert_node(EntityId(1)).expect("insert node");
+        let b = graph.insert_node(EntityId(2)).expect("insert node");
+        let commons: Vec<NodeId> = (10..13)
+            .map(|e| graph.insert_node(EntityId(e)).expect("insert node"))
+            .collect();
+        for &common in commons.iter().rev() {
+            graph
+                .insert_edge(b, common, EdgeWeight::new(1))
+                .expect("insert edge");
+            graph
+                .insert_edge(a, common, EdgeWeight::new(1))
+                .expect("insert edge");
+        }
+
+        assert_eq!(graph.intersect(&[b, a]).expect("intersect"), commons);
+    }
 }
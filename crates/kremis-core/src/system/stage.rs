@@ -25,7 +25,7 @@
 //! The edge counts (100, 1000, 5000) are illustrative placeholders.
 //! Real-world thresholds may be orders of magnitude higher.
 
-use crate::{Graph, GraphStore, Session, StorageBackend};
+use crate::{Graph, GraphStore, KremisError, Session, StorageBackend};
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -63,6 +63,17 @@ pub enum Stage {
 }
 
 impl Stage {
+    /// Get the short stage code ("S0".."S3"), matching the serde form.
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Stage::S0 => "S0",
+            Stage::S1 => "S1",
+            Stage::S2 => "S2",
+            Stage::S3 => "S3",
+        }
+    }
+
     /// Get the stage name.
     #[must_use]
     pub fn name(&self) -> &'static str {
@@ -120,6 +131,26 @@ impl std::fmt::Display for Stage {
     }
 }
 
+/// Parses a stage code ("S1"), case-insensitively.
+///
+/// The full `Display` form ("S1: Pattern Crystallization") is also accepted.
+impl std::str::FromStr for Stage {
+    type Err = KremisError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = s.split(':').next().unwrap_or_default().trim();
+        match code.to_ascii_uppercase().as_str() {
+            "S0" => Ok(Stage::S0),
+            "S1" => Ok(Stage::S1),
+            "S2" => Ok(Stage::S2),
+            "S3" => Ok(Stage::S3),
+            _ => Err(KremisError::DeserializationError(format!(
+                "unknown stage: {s}"
+            ))),
+        }
+    }
+}
+
 // =============================================================================
 // GRAPH METRICS
 // =============================================================================
@@ -502,4 +533,24 @@ mod tests {
         assert_eq!(format!("{}", Stage::S0), "S0: Signal Segmentation");
         assert_eq!(format!("{}", Stage::S3), "S3: Recursive Optimization");
     }
+
+    #[test]
+    fn stage_code_serde_and_parse_roundtrip() {
+        for stage in [Stage::S0, Stage::S1, Stage::S2, Stage::S3] {
+            let json = serde_json::to_string(&stage).expect("serialize");
+            assert_eq!(json, format!("\"{}\"", stage.code()));
+            let back: Stage = serde_json::from_str(&json).expect("deserialize");
+            assert_eq!(back, stage);
+            assert_eq!(stage.code().parse::<Stage>().expect("parse"), stage);
+            assert_eq!(stage.to_string().parse::<Stage>().expect("parse"), stage);
+        }
+        assert_eq!("s2".parse::<Stage>().expect("parse"), Stage::S2);
+    }
+
+    #[test]
+    fn stage_parse_unknown_fails() {
+        assert!("S9".parse::<Stage>().is_err());
+        assert!("".parse::<Stage>().is_err());
+        assert!(serde_json::from_str::<Stage>("\"S9\"").is_err());
+    }
 }
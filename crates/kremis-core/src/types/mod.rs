@@ -29,6 +29,24 @@ use thiserror::Error;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct EntityId(pub u64);
 
+impl EntityId {
+    /// Fold a source namespace into a raw entity id.
+    ///
+    /// Namespace 0 leaves ids below 2^48 unchanged, so un-namespaced
+    /// sources keep their existing ids. Returns `None` if `raw` does not fit
+    /// in [`ENTITY_ID_MASK`](crate::primitives::ENTITY_ID_MASK), since
+    /// truncating it would collide with a smaller id.
+    #[must_use]
+    pub const fn checked_namespaced(namespace: u16, raw: u64) -> Option<Self> {
+        if raw > crate::primitives::ENTITY_ID_MASK {
+            return None;
+        }
+        Some(Self(
+            ((namespace as u64) << crate::primitives::ENTITY_NAMESPACE_SHIFT) | raw,
+        ))
+    }
+}
+
 /// Unique identifier for a node in the internal graph.
 /// Nodes are the structural representation of entities within the CORE.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -152,14 +170,26 @@ impl Value {
 ///
 /// If input cannot be represented in this form,
 /// it must be discarded. No interpretation or semantic inference is allowed.
+///
+/// JSON input also accepts the field names `entity_id`/`id`, `attr` and
+/// `val`; output always uses the canonical names.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Signal {
     /// The entity this signal refers to.
+    #[serde(alias = "entity_id", alias = "id")]
     pub entity: EntityId,
     /// The attribute (relationship type) of this signal.
+    #[serde(alias = "attr")]
     pub attribute: Attribute,
     /// The value associated with the entity-attribute pair.
+    #[serde(alias = "val")]
     pub value: Value,
+    /// Source namespace already folded into `entity`, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<u16>,
+    /// Plugin or producer that emitted the signal (provenance only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
 }
 
 impl Signal {
@@ -170,8 +200,38 @@ impl Signal {
             entity,
             attribute,
             value,
+            namespace: None,
+            source: None,
         }
     }
+
+    /// Create a signal whose raw entity id is scoped to a source namespace.
+    ///
+    /// The entity is folded with [`EntityId::checked_namespaced`], so the same
+    /// raw id from two namespaces maps to two distinct entities. Returns
+    /// `None` if the raw id is outside the namespaced range.
+    #[must_use]
+    pub fn with_namespace(
+        namespace: u16,
+        entity: EntityId,
+        attribute: Attribute,
+        value: Value,
+    ) -> Option<Self> {
+        Some(Self {
+            entity: EntityId::checked_namespaced(namespace, entity.0)?,
+            attribute,
+            value,
+            namespace: Some(namespace),
+            source: None,
+        })
+    }
+
+    /// Tag the signal with the plugin or producer that emitted it.
+    #[must_use]
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
 }
 
 // =============================================================================
@@ -338,6 +398,14 @@ pub enum KremisError {
     /// An I/O error occurred.
     #[error("I/O error: {0}")]
     IoError(String),
+
+    /// A request value is outside its accepted range.
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+
+    /// The operation requires an acyclic graph; the node lies on a cycle.
+    #[error("Cycle detected at node {0:?}")]
+    CycleDetected(NodeId),
 }
 
 // =============================================================================
@@ -348,6 +416,49 @@ pub enum KremisError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn namespaced_entities_do_not_collide() {
+        let a = Signal::with_namespace(1, EntityId(5), Attribute::new("k"), Value::new("v"))
+            .expect("in range");
+        let b = Signal::with_namespace(2, EntityId(5), Attribute::new("k"), Value::new("v"))
+            .expect("in range");
+
+        assert_ne!(a.entity, b.entity);
+        assert_eq!(a.namespace, Some(1));
+        assert_eq!(EntityId::checked_namespaced(0, 5), Some(EntityId(5)));
+        assert_eq!(a.entity.0 & crate::primitives::ENTITY_ID_MASK, 5);
+    }
+
+    #[test]
+    fn namespaced_rejects_raw_ids_beyond_mask() {
+        let mask = crate::primitives::ENTITY_ID_MASK;
+        assert!(EntityId::checked_namespaced(1, mask).is_some());
+        // 2^48 + 5 would otherwise truncate onto raw id 5
+        assert_eq!(EntityId::checked_namespaced(1, mask + 6), None);
+        assert!(
+            Signal::with_namespace(1, EntityId(mask + 6), Attribute::new("k"), Value::new("v"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn signal_deserializes_from_aliased_field_names() {
+        let canonical: Signal =
+            serde_json::from_str(r#"{"entity":7,"attribute":"likes","value":"tea"}"#)
+                .expect("canonical");
+        let aliased: Signal =
+            serde_json::from_str(r#"{"id":7,"attr":"likes","val":"tea"}"#).expect("aliased");
+        let wire: Signal =
+            serde_json::from_str(r#"{"entity_id":7,"attribute":"likes","value":"tea"}"#)
+                .expect("entity_id");
+
+        assert_eq!(aliased, canonical);
+        assert_eq!(wire, canonical);
+        let json = serde_json::to_value(&aliased).expect("serialize");
+        assert_eq!(json["entity"], 7);
+        assert!(json.get("attr").is_none());
+    }
+
     #[test]
     fn edge_weight_saturating_increment() {
         let weight = EdgeWeight::new(i64::MAX);
@@ -397,4 +508,22 @@ mod tests {
         let with_path = Artifact::with_path(vec![NodeId(1)]);
         assert!(!with_path.is_empty());
     }
+
+    #[test]
+    fn signal_source_is_skipped_when_absent() {
+        let plain = Signal::new(EntityId(1), Attribute::new("name"), Value::new("Alice"));
+        let json = serde_json::to_value(&plain).expect("serialize");
+        assert!(json.get("source").is_none());
+
+        let tagged = plain.clone().with_source("crawler");
+        let json = serde_json::to_value(&tagged).expect("serialize");
+        assert_eq!(json["source"], "crawler");
+
+        let back: Signal = serde_json::from_value(json).expect("deserialize");
+        assert_eq!(back, tagged);
+        let legacy: Signal =
+            serde_json::from_str(r#"{"entity":1,"attribute":"name","value":"Alice"}"#)
+                .expect("deserialize");
+        assert_eq!(legacy, plain);
+    }
 }
@@ -4,10 +4,58 @@
 //!
 //! These tests ensure determinism and correctness invariants.
 
-use kremis_core::{EdgeWeight, EntityId, Graph, GraphStore};
+use kremis_core::graph::{generate_random, strongest_edge_cost};
+use kremis_core::{EdgeWeight, EntityId, Graph, GraphStore, NodeId};
 use proptest::collection::vec;
 use proptest::prelude::*;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
+
+// =============================================================================
+// REFERENCE IMPLEMENTATIONS
+// =============================================================================
+
+/// `strongest_path` as it was before the heap frontier: each step scans
+/// every tentative distance for the smallest unvisited one, taking the
+/// lowest NodeId on ties.
+fn strongest_path_linear_scan(graph: &Graph, start: NodeId, end: NodeId) -> Option<Vec<NodeId>> {
+    if !graph.contains_node(start).ok()? || !graph.contains_node(end).ok()? {
+        return None;
+    }
+    let mut dist: BTreeMap<NodeId, u128> = BTreeMap::from([(start, 0)]);
+    let mut prev: BTreeMap<NodeId, NodeId> = BTreeMap::new();
+    let mut visited = BTreeSet::new();
+
+    while let Some((current, current_dist)) = dist
+        .iter()
+        .filter(|(n, _)| !visited.contains(*n))
+        .min_by_key(|(_, d)| **d)
+        .map(|(n, d)| (*n, *d))
+    {
+        if current == end {
+            break;
+        }
+        visited.insert(current);
+        for (neighbor, weight) in graph.neighbors(current).ok()? {
+            if visited.contains(&neighbor) {
+                continue;
+            }
+            let new_dist = current_dist.saturating_add(strongest_edge_cost(weight));
+            if dist.get(&neighbor).is_none_or(|&d| new_dist < d) {
+                dist.insert(neighbor, new_dist);
+                prev.insert(neighbor, current);
+            }
+        }
+    }
+
+    let mut path = vec![end];
+    let mut current = end;
+    while current != start {
+        current = *prev.get(&current)?;
+        path.push(current);
+    }
+    path.reverse();
+    Some(path)
+}
 
 // =============================================================================
 // PROPERTY TESTS
@@ -126,4 +174,25 @@ proptest! {
         prop_assert!(graph.contains_edge(n1, n2));
         prop_assert_eq!(graph.get_edge(n1, n2).expect("get"), Some(weight));
     }
+
+    /// The heap-based strongest path returns exactly the linear-scan result,
+    /// ties included.
+    #[test]
+    fn strongest_path_matches_linear_scan(
+        node_count in 1usize..12,
+        edge_count in 0usize..40,
+        seed in any::<u64>()
+    ) {
+        let graph = generate_random(node_count, edge_count, seed);
+
+        for start in 0..node_count as u64 {
+            for end in 0..node_count as u64 {
+                let (start, end) = (NodeId(start), NodeId(end));
+                prop_assert_eq!(
+                    graph.strongest_path(start, end).expect("path"),
+                    strongest_path_linear_scan(&graph, start, end)
+                );
+            }
+        }
+    }
 }